@@ -0,0 +1,37 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use scru128::generator::LazyClockGenerator;
+use scru128::Scru128Generator;
+use std::hint::black_box;
+
+fn generate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("generate");
+
+    let mut g = Scru128Generator::new();
+    group.bench_function("Scru128Generator", |b| b.iter(|| black_box(g.generate())));
+
+    // reads clock at most once per 1,000 IDs or on counter_lo exhaustion instead of once per ID
+    let mut g = LazyClockGenerator::new();
+    group.bench_function("LazyClockGenerator", |b| b.iter(|| black_box(g.generate())));
+
+    // measures generator logic without cost of cryptographically strong rng
+    #[cfg(feature = "bench-util")]
+    {
+        use scru128::generator::XorShiftRng;
+
+        let mut g = Scru128Generator::with_rng(XorShiftRng::default());
+        group.bench_function("Scru128Generator with XorShiftRng", |b| {
+            b.iter(|| black_box(g.generate()))
+        });
+
+        let mut g = Scru128Generator::with_rng(XorShiftRng::default());
+        let ts = 0x0123_4567_89ab;
+        group.bench_function("generate_or_reset_core with XorShiftRng", |b| {
+            b.iter(|| black_box(g.generate_or_reset_core(black_box(ts), 10_000)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, generate);
+criterion_main!(benches);
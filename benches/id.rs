@@ -0,0 +1,38 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use scru128::{Scru128Generator, Scru128Id};
+use std::hint::black_box;
+
+fn encode(c: &mut Criterion) {
+    let mut g = Scru128Generator::new();
+    let large: Vec<Scru128Id> = (0..1024).map(|_| g.generate()).collect();
+    let small: Vec<Scru128Id> = (0..1024).map(Scru128Id::from_u128).collect();
+    let timestamp_only: Vec<Scru128Id> = (0..1024)
+        .map(|i| Scru128Id::from_timestamp_lower_bound(1_700_000_000_000 + i))
+        .collect();
+
+    let mut group = c.benchmark_group("encode");
+    for (name, ids) in [
+        ("generated", &large),
+        ("small", &small),
+        ("timestamp_only", &timestamp_only),
+    ] {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                for e in ids {
+                    black_box(black_box(e).encode());
+                }
+            })
+        });
+        group.bench_function(format!("{name}/fixed_iteration"), |b| {
+            b.iter(|| {
+                for e in ids {
+                    black_box(black_box(e).encode_fixed_iteration());
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, encode);
+criterion_main!(benches);
@@ -0,0 +1,130 @@
+//! Integration with Apache Arrow's `FixedSizeBinary(16)` arrays.
+//!
+//! SCRU128 IDs are stored in arrays as their 16-byte big-endian representation (i.e.,
+//! [`Scru128Id::as_bytes()`]), which is the layout expected by Parquet `FIXED_LEN_BYTE_ARRAY(16)`
+//! columns. Since the big-endian byte order is the same as the integer order of IDs, the values
+//! sort correctly when compared as unsigned byte strings, and thus column statistics and range
+//! predicates computed by Arrow and Parquet tools remain meaningful.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "default_rng")]
+//! # {
+//! use scru128::{arrow, Scru128Generator};
+//!
+//! let mut g = Scru128Generator::new();
+//! let ids = [Some(g.generate()), None, Some(g.generate())];
+//!
+//! let array = arrow::to_fixed_size_binary_nullable(&ids);
+//! assert_eq!(arrow::from_fixed_size_binary_nullable(&array)?, ids);
+//! # }
+//! # Ok::<(), arrow_schema::ArrowError>(())
+//! ```
+
+#![cfg(feature = "arrow")]
+#![cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+
+use crate::Scru128Id;
+use arrow_array::{builder::FixedSizeBinaryBuilder, Array, FixedSizeBinaryArray};
+use arrow_schema::ArrowError;
+
+/// The byte width of `FixedSizeBinary` arrays holding SCRU128 IDs.
+const BYTE_WIDTH: i32 = 16;
+
+/// Creates a `FixedSizeBinary(16)` array from a slice of IDs.
+pub fn to_fixed_size_binary(ids: &[Scru128Id]) -> FixedSizeBinaryArray {
+    let mut builder = FixedSizeBinaryBuilder::with_capacity(ids.len(), BYTE_WIDTH);
+    for e in ids {
+        builder.append_value(e.as_bytes()).unwrap();
+    }
+    builder.finish()
+}
+
+/// Creates a `FixedSizeBinary(16)` array from a slice of optional IDs, mapping `None` to null.
+pub fn to_fixed_size_binary_nullable(ids: &[Option<Scru128Id>]) -> FixedSizeBinaryArray {
+    let mut builder = FixedSizeBinaryBuilder::with_capacity(ids.len(), BYTE_WIDTH);
+    for e in ids {
+        match e {
+            Some(id) => builder.append_value(id.as_bytes()).unwrap(),
+            None => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+/// Creates a vector of IDs from a `FixedSizeBinary(16)` array.
+///
+/// # Errors
+///
+/// Returns an error if the byte width of the array is not 16 or if the array contains nulls.
+pub fn from_fixed_size_binary(array: &FixedSizeBinaryArray) -> Result<Vec<Scru128Id>, ArrowError> {
+    check_byte_width(array)?;
+    if array.null_count() > 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "could not convert array containing nulls to SCRU128 IDs".into(),
+        ));
+    }
+    Ok((0..array.len()).map(|i| value_at(array, i)).collect())
+}
+
+/// Creates a vector of optional IDs from a `FixedSizeBinary(16)` array, mapping null to `None`.
+///
+/// # Errors
+///
+/// Returns an error if the byte width of the array is not 16.
+pub fn from_fixed_size_binary_nullable(
+    array: &FixedSizeBinaryArray,
+) -> Result<Vec<Option<Scru128Id>>, ArrowError> {
+    check_byte_width(array)?;
+    Ok((0..array.len())
+        .map(|i| (!array.is_null(i)).then(|| value_at(array, i)))
+        .collect())
+}
+
+fn check_byte_width(array: &FixedSizeBinaryArray) -> Result<(), ArrowError> {
+    if array.value_length() == BYTE_WIDTH {
+        Ok(())
+    } else {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "could not convert FixedSizeBinary({}) array to SCRU128 IDs (expected 16)",
+            array.value_length()
+        )))
+    }
+}
+
+fn value_at(array: &FixedSizeBinaryArray, i: usize) -> Scru128Id {
+    Scru128Id::from_bytes(array.value(i).try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Scru128Generator;
+
+    /// Round-trips IDs through FixedSizeBinary array
+    #[test]
+    fn round_trips_ids_through_fixed_size_binary_array() {
+        let mut g = Scru128Generator::new();
+        let ids: Vec<Scru128Id> = (0..8).map(|_| g.generate()).collect();
+
+        let array = to_fixed_size_binary(&ids);
+        assert_eq!(array.len(), 8);
+        assert_eq!(array.value_length(), 16);
+        assert_eq!(array.value(3), ids[3].as_bytes());
+        assert_eq!(from_fixed_size_binary(&array).unwrap(), ids);
+        assert!(from_fixed_size_binary(&to_fixed_size_binary(&[]))
+            .unwrap()
+            .is_empty());
+
+        let nullable = [Some(ids[0]), None, Some(ids[1]), None];
+        let array = to_fixed_size_binary_nullable(&nullable);
+        assert_eq!(array.null_count(), 2);
+        assert_eq!(from_fixed_size_binary_nullable(&array).unwrap(), nullable);
+        assert!(from_fixed_size_binary(&array).is_err());
+
+        let array = FixedSizeBinaryArray::try_from_iter([[0u8; 8]].iter()).unwrap();
+        assert!(from_fixed_size_binary(&array).is_err());
+        assert!(from_fixed_size_binary_nullable(&array).is_err());
+    }
+}
@@ -0,0 +1,256 @@
+//! Serde helper that serializes [`Scru128Id`] as a verbose struct for human inspection.
+//!
+//! This module serializes an ID as a struct with two fields: `text`, the 25-digit canonical
+//! string representation, and `timestamp`, the 48-bit `timestamp` field as an integer. The form
+//! helps read IDs at a glance in debugging dashboards and logs, e.g., `{"text":
+//! "037arkzbgn93kdu9h3pw2ow2l", "timestamp": 1650793234504}` in JSON.
+//!
+//! The form is redundant and verbose, as the `timestamp` field merely repeats part of the `text`.
+//! Deserialization reads the ID from the `text` field only and ignores the `timestamp` field, so
+//! an edited `timestamp` value is silently discarded. Prefer the default implementation for
+//! storage and interchange.
+//!
+//! Use this module with the `#[serde(with = "...")]` field attribute.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use scru128::Scru128Id;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "scru128::as_struct")]
+//!     id: Scru128Id,
+//! }
+//! ```
+
+#![cfg(feature = "serde")]
+#![cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+
+use crate::Scru128Id;
+use core::fmt;
+use serde::{de, ser::SerializeStruct, Deserializer, Serializer};
+
+/// The name of the struct in the serialized form.
+const NAME: &str = "Scru128Id";
+
+/// The names of the fields in the serialized form.
+const FIELDS: &[&str] = &["text", "timestamp"];
+
+/// Serializes an ID as a struct with `text` and `timestamp` fields.
+pub fn serialize<S: Serializer>(id: &Scru128Id, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct(NAME, 2)?;
+    state.serialize_field("text", id.encode().as_str())?;
+    state.serialize_field("timestamp", &id.timestamp())?;
+    state.end()
+}
+
+/// Deserializes an ID from the `text` field of a struct, ignoring the `timestamp` field.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Scru128Id, D::Error> {
+    deserializer.deserialize_struct(NAME, FIELDS, StructVisitor)
+}
+
+enum Field {
+    Text,
+    Timestamp,
+}
+
+impl<'de> de::Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct FieldVisitor;
+
+impl de::Visitor<'_> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "`text` or `timestamp`")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        match value {
+            0 => Ok(Field::Text),
+            1 => Ok(Field::Timestamp),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Unsigned(value),
+                &self,
+            )),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        match value {
+            "text" => Ok(Field::Text),
+            "timestamp" => Ok(Field::Timestamp),
+            _ => Err(de::Error::unknown_field(value, FIELDS)),
+        }
+    }
+}
+
+/// Deserializes the `text` field as a string even in non-human-readable formats, where the
+/// default implementation of [`Scru128Id`] expects a byte array.
+struct Text;
+
+impl<'de> de::DeserializeSeed<'de> for Text {
+    type Value = Scru128Id;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl de::Visitor<'_> for Text {
+    type Value = Scru128Id;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a SCRU128 ID representation")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Self::Value::try_from_str(value).map_err(de::Error::custom)
+    }
+}
+
+struct StructVisitor;
+
+impl<'de> de::Visitor<'de> for StructVisitor {
+    type Value = Scru128Id;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a struct with `text` and `timestamp` fields")
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let value = seq
+            .next_element_seed(Text)?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        seq.next_element::<de::IgnoredAny>()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok(value)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut value = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Text => {
+                    if value.is_some() {
+                        return Err(de::Error::duplicate_field("text"));
+                    }
+                    value = Some(map.next_value_seed(Text)?);
+                }
+                Field::Timestamp => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        value.ok_or_else(|| de::Error::missing_field("text"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Scru128Id;
+    use serde::{Deserialize, Serialize};
+    use serde_test::{Configure, Token};
+
+    #[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Debug)]
+    struct Record {
+        #[serde(with = "super")]
+        id: Scru128Id,
+    }
+
+    /// Serializes as struct with text and timestamp fields in JSON
+    #[cfg(feature = "std")]
+    #[test]
+    fn serializes_as_struct_with_text_and_timestamp_fields_in_json() {
+        let record = Record {
+            id: "037arkzbgn93kdu9h3pw2ow2l".parse().unwrap(),
+        };
+        let json = serde_json::to_value(record).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "id": {
+                    "text": "037arkzbgn93kdu9h3pw2ow2l",
+                    "timestamp": record.id.timestamp(),
+                },
+            })
+        );
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            format!(
+                r#"{{"id":{{"text":"037arkzbgn93kdu9h3pw2ow2l","timestamp":{}}}}}"#,
+                record.id.timestamp()
+            )
+        );
+        assert_eq!(serde_json::from_value::<Record>(json).unwrap(), record);
+
+        for e in [Scru128Id::MIN, Scru128Id::MAX] {
+            let record = Record { id: e };
+            let encoded = serde_json::to_string(&record).unwrap();
+            assert_eq!(serde_json::from_str::<Record>(&encoded).unwrap(), record);
+        }
+    }
+
+    /// Deserializes from text field ignoring timestamp field
+    #[test]
+    fn deserializes_from_text_field_ignoring_timestamp_field() {
+        let expected = Record {
+            id: "037arkzbgn93kdu9h3pw2ow2l".parse().unwrap(),
+        };
+        let cases = [
+            r#"{"id":{"text":"037arkzbgn93kdu9h3pw2ow2l","timestamp":1650793234504}}"#,
+            r#"{"id":{"timestamp":0,"text":"037ARKZBGN93KDU9H3PW2OW2L"}}"#,
+            r#"{"id":{"text":"037arkzbgn93kdu9h3pw2ow2l","timestamp":"bogus"}}"#,
+            r#"{"id":{"text":"037arkzbgn93kdu9h3pw2ow2l"}}"#,
+            r#"{"id":["037arkzbgn93kdu9h3pw2ow2l",42]}"#,
+        ];
+        for e in cases {
+            assert_eq!(serde_json::from_str::<Record>(e).unwrap(), expected);
+        }
+
+        let errors = [
+            r#"{"id":{"timestamp":1650793234504}}"#,
+            r#"{"id":{"text":"037arkzbgn93kdu9h3pw2ow2"}}"#,
+            r#"{"id":{"text":"037arkzbgn93kdu9h3pw2ow2l","extra":1}}"#,
+            r#"{"id":{"text":"037arkzbgn93kdu9h3pw2ow2l","text":"037arkzbgn93kdu9h3pw2ow2l"}}"#,
+            r#"{"id":["037arkzbgn93kdu9h3pw2ow2l"]}"#,
+            r#"{"id":"037arkzbgn93kdu9h3pw2ow2l"}"#,
+        ];
+        for e in errors {
+            assert!(serde_json::from_str::<Record>(e).is_err());
+        }
+    }
+
+    /// Serializes as struct in both binary and human-readable formats
+    #[test]
+    fn serializes_as_struct_in_both_binary_and_human_readable_formats() {
+        let record = Record {
+            id: "037arkzbgn93kdu9h3pw2ow2l".parse().unwrap(),
+        };
+        let tokens = [
+            Token::Struct {
+                name: "Record",
+                len: 1,
+            },
+            Token::Str("id"),
+            Token::Struct {
+                name: "Scru128Id",
+                len: 2,
+            },
+            Token::Str("text"),
+            Token::Str("037arkzbgn93kdu9h3pw2ow2l"),
+            Token::Str("timestamp"),
+            Token::U64(record.id.timestamp()),
+            Token::StructEnd,
+            Token::StructEnd,
+        ];
+        serde_test::assert_tokens(&record.compact(), &tokens);
+        serde_test::assert_tokens(&record.readable(), &tokens);
+    }
+}
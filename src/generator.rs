@@ -3,6 +3,7 @@
 //! This module is also exported as `scru128::gen` for backward compatibility.
 
 use crate::{Scru128Id, MAX_COUNTER_HI, MAX_COUNTER_LO, MAX_TIMESTAMP};
+use core::{cell::Cell, convert::Infallible, fmt, iter};
 
 /// A trait that defines the minimum random number generator interface for [`Scru128Generator`].
 pub trait Scru128Rng {
@@ -10,11 +11,103 @@ pub trait Scru128Rng {
     fn next_u32(&mut self) -> u32;
 }
 
+/// A trait that defines the time source interface for [`Scru128Generator`].
+///
+/// Use [`Scru128Generator::with_rand_and_time_sources()`] to create a generator with a custom
+/// time source. The generator methods that read the current `timestamp`, such as
+/// [`generate()`](Scru128Generator::generate), are available with any time source, including
+/// under `no_std` environments.
+pub trait TimeSource {
+    /// Returns the current Unix timestamp in milliseconds.
+    fn unix_ts_ms(&mut self) -> u64;
+}
+
+/// The strategy to initialize `counter_lo` at each new `timestamp`, which is configured through
+/// [`Scru128Generator::with_counter_mode()`].
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub enum CounterMode {
+    /// Initializes `counter_lo` to a random number, as the SCRU128 specification recommends. This
+    /// is the default.
+    #[default]
+    Random,
+
+    /// Initializes `counter_lo` to zero, which makes the low-order bits of `counter_lo` denser and
+    /// more predictable and thus helps compress stored IDs. The IDs generated in this mode leak the
+    /// number of IDs generated in the same millisecond and are easier to guess.
+    Zero,
+}
+
+/// An error returned by [`Scru128Generator::generate_checked_core()`] upon significant
+/// `timestamp` rollback.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub struct ClockRollback {
+    /// The amount in milliseconds by which the `timestamp` passed went backwards from that of the
+    /// immediately preceding ID, which is greater than the `rollback_allowance`.
+    pub by_ms: u64,
+}
+
+impl fmt::Display for ClockRollback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "clock went backwards by {} ms", self.by_ms)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ClockRollback {}
+
 pub mod with_rand08;
 
 mod default_rng;
 pub use default_rng::DefaultRng;
 
+mod time_source;
+#[cfg(feature = "std")]
+pub use time_source::MonotonicTimeSource;
+pub use time_source::{FnTimeSource, StdSystemTime};
+
+mod lazy_clock;
+#[cfg(feature = "std")]
+pub use lazy_clock::LazyClockGenerator;
+
+mod governed;
+#[cfg(feature = "governor")]
+pub use governed::GovernedGenerator;
+
+mod rate_limit;
+#[cfg(feature = "std")]
+pub use rate_limit::RateLimitedGenerator;
+
+mod watermark;
+#[cfg(feature = "std")]
+pub use watermark::Watermark;
+
+mod stats;
+pub use stats::GeneratorStats;
+
+mod decision_log;
+pub use decision_log::{Decision, DECISION_LOG_CAPACITY};
+
+mod packed_state;
+
+mod fallible;
+pub use fallible::{RngError, TryRandSource};
+
+mod spread;
+#[cfg(feature = "default_rng")]
+pub use spread::generate_spread;
+
+mod replay;
+#[cfg(feature = "test-util")]
+pub use replay::{ReplayRandSource, ReplayTimeSource};
+
+mod seeded;
+
+mod xorshift;
+#[cfg(feature = "bench-util")]
+pub use xorshift::XorShiftRng;
+
 /// Represents a SCRU128 ID generator that encapsulates the monotonic counters and other internal
 /// states.
 ///
@@ -71,20 +164,25 @@ pub use default_rng::DefaultRng;
 ///
 /// All of the four return a monotonically increasing ID by reusing the previous `timestamp` even
 /// if the one provided is smaller than the immediately preceding ID's. However, when such a clock
-/// rollback is considered significant (by default, more than ten seconds):
+/// rollback is considered significant (by default, more than ten seconds; see
+/// [`set_rollback_allowance()`](Scru128Generator::set_rollback_allowance)):
 ///
 /// 1.  `generate` (or_reset) methods reset the generator and return a new ID based on the given
 ///     `timestamp`, breaking the increasing order of IDs.
 /// 2.  `or_abort` variants abort and return `None` immediately.
 ///
+/// [`generate_checked_core`] is another variant of [`generate_or_abort_core`] that returns a
+/// [`ClockRollback`] error carrying the magnitude of the rollback instead of `None`.
+///
 /// The `core` functions offer low-level primitives to customize the behavior.
 ///
 /// [`generate`]: Scru128Generator::generate
 /// [`generate_or_abort`]: Scru128Generator::generate_or_abort
 /// [`generate_or_reset_core`]: Scru128Generator::generate_or_reset_core
 /// [`generate_or_abort_core`]: Scru128Generator::generate_or_abort_core
-#[derive(Clone, Eq, PartialEq, Debug, Default)]
-pub struct Scru128Generator<R = DefaultRng> {
+/// [`generate_checked_core`]: Scru128Generator::generate_checked_core
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Scru128Generator<R = DefaultRng, T = StdSystemTime> {
     timestamp: u64,
     counter_hi: u32,
     counter_lo: u32,
@@ -92,11 +190,81 @@ pub struct Scru128Generator<R = DefaultRng> {
     /// The timestamp at the last renewal of `counter_hi` field.
     ts_counter_hi: u64,
 
+    /// The number of low-order bits of the 48-bit counter used for counting.
+    counter_bits: u8,
+
+    /// The amount of `timestamp` rollback tolerated by the methods that read the current time.
+    rollback_allowance: u64,
+
+    /// Whether to consume a fixed number of random words per ID.
+    constant_time_rng: bool,
+
+    /// The strategy to initialize `counter_lo` at each new `timestamp`.
+    counter_mode: CounterMode,
+
+    /// Whether to check if the `entropy` field repeats within the same `timestamp`.
+    entropy_repeat_check: bool,
+
+    /// The `entropy` field of the last generated ID, tracked only if `entropy_repeat_check` is on.
+    last_entropy: u32,
+
+    /// The `timestamp` passed at the last call, or zero if never.
+    ts_last_passed: u64,
+
+    /// Whether the `timestamp` passed at the last call was smaller than the one before.
+    last_clock_regressed: bool,
+
+    /// The maximum number of IDs issued per `timestamp`.
+    per_ms_cap: u64,
+
+    /// The number of IDs generated with the current `timestamp`.
+    ids_in_ms: u64,
+
     /// The random number generator used by the generator.
     rng: R,
+
+    /// The time source used by the generator.
+    time_source: T,
+
+    /// The statistics collected by the generator.
+    stats: GeneratorStats,
+
+    /// The callback registered through `set_stats_callback()`.
+    #[cfg(feature = "std")]
+    stats_callback: Option<stats::StatsCallback>,
+
+    /// The most recent decisions recorded if enabled by `set_record_decisions()`.
+    decision_log: decision_log::DecisionLog,
+}
+
+impl<R: Default, T: Default> Default for Scru128Generator<R, T> {
+    fn default() -> Self {
+        Self {
+            timestamp: 0,
+            counter_hi: 0,
+            counter_lo: 0,
+            ts_counter_hi: 0,
+            counter_bits: 48,
+            rollback_allowance: DEFAULT_ROLLBACK_ALLOWANCE,
+            constant_time_rng: false,
+            counter_mode: CounterMode::Random,
+            entropy_repeat_check: false,
+            last_entropy: 0,
+            ts_last_passed: 0,
+            last_clock_regressed: false,
+            per_ms_cap: u64::MAX,
+            ids_in_ms: 0,
+            rng: R::default(),
+            time_source: T::default(),
+            stats: GeneratorStats::new(),
+            #[cfg(feature = "std")]
+            stats_callback: None,
+            decision_log: decision_log::DecisionLog::new(),
+        }
+    }
 }
 
-impl<R: Scru128Rng> Scru128Generator<R> {
+impl<R> Scru128Generator<R> {
     /// Creates a generator object with a specified random number generator. The specified random
     /// number generator should be cryptographically strong and securely seeded.
     ///
@@ -104,157 +272,225 @@ impl<R: Scru128Rng> Scru128Generator<R> {
     /// generators from `rand` crate. Although this constructor accepts [`rand::RngCore`] types for
     /// historical reasons, such behavior is deprecated and will be removed in the future.
     pub const fn with_rng(rng: R) -> Self {
+        Self::with_rand_and_time_sources(rng, StdSystemTime::new())
+    }
+}
+
+impl<R, T> Scru128Generator<R, T> {
+    /// Creates a generator object with a specified random number generator and time source. The
+    /// specified random number generator should be cryptographically strong and securely seeded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::generator::{Scru128Rng, TimeSource};
+    /// use scru128::Scru128Generator;
+    ///
+    /// struct HardwareRng;
+    ///
+    /// impl Scru128Rng for HardwareRng {
+    ///     fn next_u32(&mut self) -> u32 {
+    ///         4 // read a hardware random number generator
+    ///     }
+    /// }
+    ///
+    /// struct RealTimeClock;
+    ///
+    /// impl TimeSource for RealTimeClock {
+    ///     fn unix_ts_ms(&mut self) -> u64 {
+    ///         0x0123_4567_89ab // read a real-time clock
+    ///     }
+    /// }
+    ///
+    /// let mut g = Scru128Generator::with_rand_and_time_sources(HardwareRng, RealTimeClock);
+    /// let x = g.generate();
+    /// let y = g.generate();
+    /// assert!(x < y);
+    /// assert_eq!(x.timestamp(), 0x0123_4567_89ab);
+    /// ```
+    pub const fn with_rand_and_time_sources(rng: R, time_source: T) -> Self {
         Self {
             timestamp: 0,
             counter_hi: 0,
             counter_lo: 0,
             ts_counter_hi: 0,
+            counter_bits: 48,
+            rollback_allowance: DEFAULT_ROLLBACK_ALLOWANCE,
+            constant_time_rng: false,
+            counter_mode: CounterMode::Random,
+            entropy_repeat_check: false,
+            last_entropy: 0,
+            ts_last_passed: 0,
+            last_clock_regressed: false,
+            per_ms_cap: u64::MAX,
+            ids_in_ms: 0,
             rng,
+            time_source,
+            stats: GeneratorStats::new(),
+            #[cfg(feature = "std")]
+            stats_callback: None,
+            decision_log: decision_log::DecisionLog::new(),
         }
     }
 
-    /// Generates a new SCRU128 ID object from the `timestamp` passed, or resets the generator upon
-    /// significant timestamp rollback.
+    /// Returns the amount of `timestamp` rollback in milliseconds that the generator tolerates
+    /// when it reads the current time. The default is `10_000` (ten seconds).
     ///
-    /// See the [`Scru128Generator`] type documentation for the description.
+    /// See [`set_rollback_allowance()`](Self::set_rollback_allowance) for details.
+    pub const fn rollback_allowance(&self) -> u64 {
+        self.rollback_allowance
+    }
+
+    /// Sets the amount of `timestamp` rollback in milliseconds that is considered significant by
+    /// the methods that read the current time, such as [`generate()`](Self::generate) and
+    /// [`generate_or_abort()`](Self::generate_or_abort), as well as by
+    /// [`generate_at()`](Self::generate_at). The default is `10_000` (ten seconds).
     ///
-    /// The `rollback_allowance` parameter specifies the amount of `timestamp` rollback that is
-    /// considered significant. A suggested value is `10_000` (milliseconds).
+    /// A larger value helps environments where the system clock may be adjusted backwards
+    /// considerably (e.g., virtual machines resumed from suspension), at the cost of the
+    /// generator reusing the previous `timestamp` for a longer period after such an adjustment.
+    /// The `core` methods that take the `rollback_allowance` parameter, such as
+    /// [`generate_or_reset_core()`](Self::generate_or_reset_core), are not affected by this
+    /// setting.
     ///
     /// # Panics
     ///
-    /// Panics if `timestamp` is not a 48-bit positive integer.
-    pub fn generate_or_reset_core(&mut self, timestamp: u64, rollback_allowance: u64) -> Scru128Id {
-        if let Some(value) = self.generate_or_abort_core(timestamp, rollback_allowance) {
-            value
-        } else {
-            // reset state and resume
-            self.timestamp = 0;
-            self.ts_counter_hi = 0;
-            self.generate_or_abort_core(timestamp, rollback_allowance)
-                .unwrap()
-        }
-    }
-
-    /// Generates a new SCRU128 ID object from the `timestamp` passed, or returns `None` upon
-    /// significant timestamp rollback.
+    /// Panics if `ms` is greater than the maximum 48-bit `timestamp` value.
     ///
-    /// See the [`Scru128Generator`] type documentation for the description.
+    /// # Examples
     ///
-    /// The `rollback_allowance` parameter specifies the amount of `timestamp` rollback that is
-    /// considered significant. A suggested value is `10_000` (milliseconds).
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
     ///
-    /// # Panics
+    /// let mut g = Scru128Generator::new();
+    /// assert_eq!(g.rollback_allowance(), 10_000);
     ///
-    /// Panics if `timestamp` is not a 48-bit positive integer.
-    pub fn generate_or_abort_core(
-        &mut self,
-        timestamp: u64,
-        rollback_allowance: u64,
-    ) -> Option<Scru128Id> {
-        if timestamp == 0 || timestamp > MAX_TIMESTAMP {
-            panic!("`timestamp` must be a 48-bit positive integer");
-        } else if rollback_allowance > MAX_TIMESTAMP {
+    /// g.set_rollback_allowance(30_000);
+    /// assert_eq!(g.rollback_allowance(), 30_000);
+    /// # }
+    /// ```
+    pub fn set_rollback_allowance(&mut self, ms: u64) {
+        if ms > MAX_TIMESTAMP {
             panic!("`rollback_allowance` out of reasonable range");
         }
-
-        if timestamp > self.timestamp {
-            self.timestamp = timestamp;
-            self.counter_lo = self.rng.next_u32() & MAX_COUNTER_LO;
-        } else if timestamp + rollback_allowance >= self.timestamp {
-            // go on with previous timestamp if new one is not much smaller
-            self.counter_lo += 1;
-            if self.counter_lo > MAX_COUNTER_LO {
-                self.counter_lo = 0;
-                self.counter_hi += 1;
-                if self.counter_hi > MAX_COUNTER_HI {
-                    self.counter_hi = 0;
-                    // increment timestamp at counter overflow
-                    self.timestamp += 1;
-                    self.counter_lo = self.rng.next_u32() & MAX_COUNTER_LO;
-                }
-            }
-        } else {
-            // abort if clock went backwards to unbearable extent
-            return None;
-        }
-
-        if self.timestamp - self.ts_counter_hi >= 1_000 || self.ts_counter_hi == 0 {
-            self.ts_counter_hi = self.timestamp;
-            self.counter_hi = self.rng.next_u32() & MAX_COUNTER_HI;
-        }
-
-        Some(Scru128Id::from_fields(
-            self.timestamp,
-            self.counter_hi,
-            self.counter_lo,
-            self.rng.next_u32(),
-        ))
+        self.rollback_allowance = ms;
     }
-}
 
-#[cfg(any(feature = "default_rng", test))]
-#[cfg_attr(docsrs, doc(cfg(feature = "default_rng")))]
-impl Scru128Generator {
-    /// Creates a generator object with the default random number generator.
-    pub fn new() -> Self {
-        Default::default()
+    /// Returns `true` if the `timestamp` passed at the last generation call went backwards from
+    /// the one passed at the call before.
+    ///
+    /// When the clock steps back within the rollback allowance, the generator silently goes on
+    /// with the previous `timestamp` and produces IDs that are still monotonically ordered, so
+    /// the ID output alone does not reveal the clock instability. This flag helps monitoring
+    /// surface such events (e.g., NTP adjustments) without changing the generated IDs. It is also
+    /// set when a significant rollback makes the generator reset or abort.
+    ///
+    /// The flag describes the most recent call only and is overwritten by every call that
+    /// generates or attempts to generate an ID, so check it right after the call in question.
+    /// It compares the `timestamp` values passed to the generator, not the `timestamp` fields of
+    /// the generated IDs, so the generator running ahead of the clock after a counter overflow
+    /// does not set the flag by itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new();
+    /// let x = g.generate_or_reset_core(0x0123_4567_89ab, 10_000);
+    /// assert!(!g.last_clock_regressed());
+    ///
+    /// let y = g.generate_or_reset_core(0x0123_4567_89ab - 1_000, 10_000);
+    /// assert!(g.last_clock_regressed());
+    /// assert!(x < y);
+    ///
+    /// g.generate_or_reset_core(0x0123_4567_89ab - 1_000, 10_000);
+    /// assert!(!g.last_clock_regressed());
+    /// # }
+    /// ```
+    pub const fn last_clock_regressed(&self) -> bool {
+        self.last_clock_regressed
     }
-}
-
-#[cfg(feature = "std")]
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-mod with_std {
-    use super::{Scru128Generator, Scru128Id, Scru128Rng};
-    use std::{iter, time};
-
-    /// The default timestamp rollback allowance.
-    const DEFAULT_ROLLBACK_ALLOWANCE: u64 = 10_000; // 10 seconds
 
-    /// Returns the current Unix timestamp in milliseconds.
-    fn unix_ts_ms() -> u64 {
-        time::SystemTime::now()
-            .duration_since(time::UNIX_EPOCH)
-            .expect("clock may have gone backwards")
-            .as_millis() as u64
+    /// Returns the number of low-order bits of the 48-bit counter (`counter_hi` and `counter_lo`
+    /// combined) that the generator uses for counting.
+    pub const fn counter_bits(&self) -> u8 {
+        self.counter_bits
     }
 
-    impl<R: Scru128Rng> Scru128Generator<R> {
-        /// Generates a new SCRU128 ID object from the current `timestamp`, or resets the generator
-        /// upon significant timestamp rollback.
-        ///
-        /// See the [`Scru128Generator`] type documentation for the description.
-        pub fn generate(&mut self) -> Scru128Id {
-            self.generate_or_reset_core(unix_ts_ms(), DEFAULT_ROLLBACK_ALLOWANCE)
+    /// Limits the number of low-order bits of the 48-bit counter (`counter_hi` and `counter_lo`
+    /// combined) that the generator uses for counting. The default is `48`.
+    ///
+    /// The remaining high-order bits of the counter are always set to zero, which applications
+    /// can use to embed metadata (e.g., by setting a constant tag) without breaking the order of
+    /// IDs. The trade-off is the reduced capacity: the counter is initialized to a random number
+    /// within the narrowed range at each new `timestamp` and increments up to `2^bits - 1` only,
+    /// after which the generator increments `timestamp` to continue generating monotonically
+    /// increasing IDs. Therefore, a small `bits` value may let `timestamp` run ahead of the
+    /// system clock quickly under a heavy load.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is greater than `48`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new();
+    /// g.set_counter_bits(16);
+    ///
+    /// let x = g.generate_or_reset_core(0x0123_4567_89ab, 10_000);
+    /// assert_eq!(x.counter_hi(), 0);
+    /// assert!(x.counter_lo() < 1 << 16);
+    /// # }
+    /// ```
+    pub fn set_counter_bits(&mut self, bits: u8) {
+        if bits > 48 {
+            panic!("`bits` must be at most 48");
         }
+        self.counter_bits = bits;
+    }
 
-        /// Generates a new SCRU128 ID object from the current `timestamp`, or returns `None` upon
-        /// significant timestamp rollback.
-        ///
-        /// See the [`Scru128Generator`] type documentation for the description.
-        ///
-        /// # Examples
-        ///
-        /// ```rust
-        /// # #[cfg(feature = "default_rng")]
-        /// # {
-        /// use scru128::Scru128Generator;
-        ///
-        /// let mut g = Scru128Generator::new();
-        /// let x = g.generate_or_abort().unwrap();
-        /// let y = g
-        ///     .generate_or_abort()
-        ///     .expect("The clock went backwards by ten seconds!");
-        /// assert!(x < y);
-        /// # }
-        /// ```
-        pub fn generate_or_abort(&mut self) -> Option<Scru128Id> {
-            self.generate_or_abort_core(unix_ts_ms(), DEFAULT_ROLLBACK_ALLOWANCE)
-        }
+    /// Returns the maximum number of IDs that the generator issues per `timestamp`. The default is
+    /// [`u64::MAX`], which imposes no limit.
+    ///
+    /// See [`set_per_ms_cap()`](Self::set_per_ms_cap) for details.
+    pub const fn per_ms_cap(&self) -> u64 {
+        self.per_ms_cap
     }
 
-    /// `Scru128Generator` behaves as an infinite iterator that produces a new ID for each call of
-    /// `next()`.
+    /// Sets the maximum number of IDs that the generator issues per `timestamp`, which helps
+    /// protect downstream systems from bursts.
+    ///
+    /// Once `cap` IDs have been generated with the same `timestamp`, the generator handles the
+    /// next call in either of the following ways depending on the method:
+    ///
+    /// - [`generate_or_abort()`](Self::generate_or_abort) and
+    ///   [`generate_or_abort_core()`](Self::generate_or_abort_core) return `None` without
+    ///   advancing the counters until the `timestamp` passed exceeds that of the last ID, while
+    ///   still updating [`last_clock_regressed()`](Self::last_clock_regressed), so `None` works as a backpressure signal that tells callers to retry in the next
+    ///   millisecond. [`generate_burst()`](Self::generate_burst) likewise returns `None` if the
+    ///   cap leaves no room for the whole burst.
+    /// - The other methods, which always return an ID, such as [`generate()`](Self::generate),
+    ///   move on to the next `timestamp` as they do upon counter overflow, so the `timestamp` of
+    ///   the generated IDs may run ahead of the real time under a sustained burst.
+    ///
+    /// The cap is counted per `timestamp` of the generated IDs, not per `timestamp` passed. If the
+    /// counters overflow before the cap is reached, the generator moves on to the next
+    /// `timestamp` as usual and starts counting from one again, so a cap greater than the counter
+    /// capacity under the current [`counter_bits()`](Self::counter_bits) has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cap` is zero.
     ///
     /// # Examples
     ///
@@ -263,87 +499,2180 @@ mod with_std {
     /// # {
     /// use scru128::Scru128Generator;
     ///
-    /// let g = Scru128Generator::new();
-    /// for (i, e) in g.take(8).enumerate() {
-    ///     println!("[{}] {}", i, e);
-    /// }
+    /// let mut g = Scru128Generator::new();
+    /// g.set_per_ms_cap(2);
+    ///
+    /// let ts = 0x0123_4567_89ab;
+    /// assert!(g.generate_or_abort_core(ts, 10_000).is_some());
+    /// assert!(g.generate_or_abort_core(ts, 10_000).is_some());
+    /// assert!(g.generate_or_abort_core(ts, 10_000).is_none());
+    /// assert!(g.generate_or_abort_core(ts + 1, 10_000).is_some());
     /// # }
     /// ```
-    impl<R: Scru128Rng> Iterator for Scru128Generator<R> {
-        type Item = Scru128Id;
-
-        fn next(&mut self) -> Option<Self::Item> {
-            Some(self.generate())
-        }
-
-        fn size_hint(&self) -> (usize, Option<usize>) {
-            (usize::MAX, None)
+    pub fn set_per_ms_cap(&mut self, cap: u64) {
+        if cap == 0 {
+            panic!("`cap` must be a positive integer");
         }
+        self.per_ms_cap = cap;
     }
 
-    impl<R: Scru128Rng> iter::FusedIterator for Scru128Generator<R> {}
+    /// Makes the generator consume a fixed number of random words (three `u32` values) to
+    /// generate every ID, regardless of whether it starts a new `timestamp` or increments the
+    /// counters. The default is `false`.
+    ///
+    /// Without this option, the generator draws one to three random words per ID depending on
+    /// the branch taken, so the time spent on the random number generator (or the number of
+    /// requests to an external random number source) may reveal whether an ID begins a new
+    /// millisecond or a new `counter_hi` period. This option closes such a timing side channel in
+    /// sensitive contexts by drawing and discarding the unused words, at the cost of a few more
+    /// random number generator calls per ID. Aborted calls that return `None` do not consume any
+    /// random word either way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new();
+    /// g.set_constant_time_rng(true);
+    /// let x = g.generate();
+    /// let y = g.generate();
+    /// assert!(x < y);
+    /// # }
+    /// ```
+    pub fn set_constant_time_rng(&mut self, enabled: bool) {
+        self.constant_time_rng = enabled;
+    }
 
-    #[cfg(test)]
-    mod tests {
-        /// Is iterable with for-in loop
-        #[test]
-        fn is_iterable_with_for_in_loop() {
-            use super::Scru128Generator;
+    /// Returns the generator configured to initialize `counter_lo` at each new `timestamp` in the
+    /// specified manner. The default is [`CounterMode::Random`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::generator::CounterMode;
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new().with_counter_mode(CounterMode::Zero);
+    /// let x = g.generate_or_abort_core(0x0123_4567_89ab, 10_000).unwrap();
+    /// let y = g.generate_or_abort_core(0x0123_4567_89ab, 10_000).unwrap();
+    /// assert_eq!(x.counter_lo(), 0);
+    /// assert_eq!(y.counter_lo(), 1);
+    /// # }
+    /// ```
+    pub fn with_counter_mode(mut self, mode: CounterMode) -> Self {
+        self.counter_mode = mode;
+        self
+    }
 
-            let mut i = 0;
-            for e in Scru128Generator::new() {
-                assert!(e.timestamp() > 0);
-                i += 1;
-                if i > 100 {
-                    break;
-                }
-            }
-            assert_eq!(i, 101);
-        }
+    /// Enables or disables the diagnostic check for a malfunctioning random number generator. This
+    /// option is disabled by default.
+    ///
+    /// When enabled, the generator compares the `entropy` field of each ID with that of the
+    /// previous one generated within the same `timestamp`, and if they are identical, it counts
+    /// the event in [`GeneratorStats::entropy_repeats`] and immediately invokes the callback
+    /// registered through `set_stats_callback()`, if any, regardless of its interval. A broken
+    /// random number generator that keeps returning the same value would otherwise go unnoticed
+    /// while producing IDs of reduced uniqueness.
+    ///
+    /// This check is merely a heuristic: a healthy random number generator also repeats a value by
+    /// chance with a probability of 2<sup>-32</sup> per ID, while a flawed one that does not
+    /// repeat consecutive values passes the check. Treat a report as a sign to investigate rather
+    /// than as proof of failure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::{generator::Scru128Rng, Scru128Generator};
+    ///
+    /// struct StuckRng;
+    ///
+    /// impl Scru128Rng for StuckRng {
+    ///     fn next_u32(&mut self) -> u32 {
+    ///         42
+    ///     }
+    /// }
+    ///
+    /// let mut g = Scru128Generator::with_rng(StuckRng);
+    /// g.set_entropy_repeat_check(true);
+    /// for _ in 0..4 {
+    ///     g.generate_or_reset_core(0x0123_4567_89ab, 10_000);
+    /// }
+    /// assert_eq!(g.stats().entropy_repeats, 3);
+    /// ```
+    pub fn set_entropy_repeat_check(&mut self, enabled: bool) {
+        self.entropy_repeat_check = enabled;
     }
-}
 
-#[cfg(test)]
+    /// Returns the most recently generated ID reconstructed from the internal states, or `None`
+    /// if the generator has not generated any ID yet.
+    ///
+    /// The generator does not store the `entropy` field of the generated IDs, so the returned ID
+    /// carries the `timestamp`, `counter_hi`, and `counter_lo` of the last ID but always has
+    /// `entropy` of `0`. Therefore, the returned ID is less than or equal to the actual last ID and
+    /// greater than any ID generated before it, which makes it suitable as a high-water mark.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new();
+    /// assert_eq!(g.last_id(), None);
+    ///
+    /// let x = g.generate();
+    /// let y = g.last_id().unwrap();
+    /// assert_eq!(y.timestamp(), x.timestamp());
+    /// assert_eq!(y.counter_hi(), x.counter_hi());
+    /// assert_eq!(y.counter_lo(), x.counter_lo());
+    /// assert_eq!(y.entropy(), 0);
+    /// # }
+    /// ```
+    pub const fn last_id(&self) -> Option<Scru128Id> {
+        if self.timestamp == 0 {
+            None
+        } else {
+            Some(Scru128Id::from_fields(
+                self.timestamp,
+                self.counter_hi,
+                self.counter_lo,
+                0,
+            ))
+        }
+    }
+
+    /// Returns the logical `timestamp` stored in the generator, which is the `timestamp` of the
+    /// last generated ID, or `0` if the generator has not generated any ID yet.
+    ///
+    /// The logical `timestamp` may run ahead of the clock after a counter overflow and may stay
+    /// behind it within the rollback allowance, so comparing this value with the current time
+    /// tells how far the generator has drifted from real time. This method does not change the
+    /// state of the generator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new();
+    /// assert_eq!(g.peek_timestamp(), 0);
+    ///
+    /// g.generate_or_reset_core(0x0123_4567_89ab, 10_000);
+    /// assert_eq!(g.peek_timestamp(), 0x0123_4567_89ab);
+    ///
+    /// g.generate_or_reset_core(0x0123_4567_89ab - 1_000, 10_000);
+    /// assert_eq!(g.peek_timestamp(), 0x0123_4567_89ab);
+    /// # }
+    /// ```
+    pub const fn peek_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+}
+
+impl<R: Scru128Rng, T> Scru128Generator<R, T> {
+    /// Generates a new SCRU128 ID object pinned to the `timestamp_ms` passed instead of the
+    /// current time, or resets the generator upon significant timestamp rollback.
+    ///
+    /// This method is a shorthand for [`generate_or_reset_core()`](Self::generate_or_reset_core)
+    /// with the [`rollback_allowance()`](Self::rollback_allowance) configured for the generator,
+    /// which is handy to backfill historical records with IDs that reflect their original
+    /// creation time. Repeated calls with the same or a decreasing `timestamp_ms` still yield
+    /// monotonically increasing IDs as long as the `timestamp_ms` does not go backwards beyond
+    /// the `rollback_allowance`; the generator carries on with the greatest `timestamp` seen and
+    /// increments the counters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp_ms` is not a 48-bit positive integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new();
+    /// let x = g.generate_at(1_577_836_800_000);
+    /// let y = g.generate_at(1_577_836_800_000);
+    /// assert!(x < y);
+    /// assert_eq!(x.timestamp(), 1_577_836_800_000);
+    /// assert_eq!(y.timestamp(), 1_577_836_800_000);
+    /// # }
+    /// ```
+    pub fn generate_at(&mut self, timestamp_ms: u64) -> Scru128Id {
+        self.generate_or_reset_core(timestamp_ms, self.rollback_allowance)
+    }
+
+    /// Generates a new SCRU128 ID object from the `timestamp` passed, or resets the generator upon
+    /// significant timestamp rollback.
+    ///
+    /// See the [`Scru128Generator`] type documentation for the description.
+    ///
+    /// The `rollback_allowance` parameter specifies the amount of `timestamp` rollback that is
+    /// considered significant. A suggested value is `10_000` (milliseconds).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp` is not a 48-bit positive integer.
+    pub fn generate_or_reset_core(&mut self, timestamp: u64, rollback_allowance: u64) -> Scru128Id {
+        let value = self.generate_or_reset_unrecorded(timestamp, rollback_allowance);
+        self.record_generated();
+        value
+    }
+
+    /// Implements `generate_or_reset_core()` without recording the generated ID in the
+    /// statistics.
+    fn generate_or_reset_unrecorded(
+        &mut self,
+        timestamp: u64,
+        rollback_allowance: u64,
+    ) -> Scru128Id {
+        if let Some(value) = self.generate_core(timestamp, rollback_allowance) {
+            value
+        } else {
+            // reset state and resume
+            self.timestamp = 0;
+            self.ts_counter_hi = 0;
+            self.stats.resets += 1;
+            let value = self.generate_core(timestamp, rollback_allowance).unwrap();
+            self.decision_log.replace_last(Decision::Reset);
+            self.last_clock_regressed = true;
+            value
+        }
+    }
+
+    /// Generates a new SCRU128 ID object from the `timestamp` passed, or returns `None` upon
+    /// significant timestamp rollback.
+    ///
+    /// See the [`Scru128Generator`] type documentation for the description.
+    ///
+    /// The `rollback_allowance` parameter specifies the amount of `timestamp` rollback that is
+    /// considered significant. A suggested value is `10_000` (milliseconds).
+    ///
+    /// This method also returns `None` if the generator has issued as many IDs as the
+    /// [per-millisecond cap](Self::set_per_ms_cap) with the current `timestamp`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp` is not a 48-bit positive integer.
+    pub fn generate_or_abort_core(
+        &mut self,
+        timestamp: u64,
+        rollback_allowance: u64,
+    ) -> Option<Scru128Id> {
+        if self.ids_in_ms >= self.per_ms_cap
+            && timestamp <= self.timestamp
+            && timestamp + rollback_allowance >= self.timestamp
+        {
+            self.last_clock_regressed = timestamp < self.ts_last_passed;
+            self.ts_last_passed = timestamp;
+            self.decision_log.push(Decision::CapReached);
+            return None;
+        }
+        self.generate_checked_core(timestamp, rollback_allowance)
+            .ok()
+    }
+
+    /// Generates a new SCRU128 ID object from the `timestamp` passed, or returns an error upon
+    /// significant timestamp rollback.
+    ///
+    /// This method behaves the same as
+    /// [`generate_or_abort_core()`](Self::generate_or_abort_core), except that it returns a
+    /// [`ClockRollback`] error that tells how far the `timestamp` went backwards, which helps
+    /// log the severity of the clock issue or decide whether to raise an alert, and that it moves
+    /// on to the next `timestamp` once the [per-millisecond cap](Self::set_per_ms_cap) is reached
+    /// instead of returning no ID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp` is not a 48-bit positive integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new();
+    /// let ts = 0x0123_4567_89ab;
+    /// assert!(g.generate_checked_core(ts, 10_000).is_ok());
+    /// assert!(g.generate_checked_core(ts - 10_000, 10_000).is_ok());
+    ///
+    /// let err = g.generate_checked_core(ts - 60_000, 10_000).unwrap_err();
+    /// assert_eq!(err.by_ms, 60_000);
+    /// # }
+    /// ```
+    pub fn generate_checked_core(
+        &mut self,
+        timestamp: u64,
+        rollback_allowance: u64,
+    ) -> Result<Scru128Id, ClockRollback> {
+        let prev_timestamp = self.timestamp;
+        if let Some(value) = self.generate_core(timestamp, rollback_allowance) {
+            self.record_generated();
+            Ok(value)
+        } else {
+            self.stats.aborts += 1;
+            self.decision_log.push(Decision::Abort);
+            Err(ClockRollback {
+                by_ms: prev_timestamp - timestamp,
+            })
+        }
+    }
+
+    /// Generates a new SCRU128 ID object from the `timestamp` passed, or extends the previous
+    /// `timestamp` however far the `timestamp` passed goes backwards.
+    ///
+    /// Unlike [`generate_or_reset_core()`](Self::generate_or_reset_core) and
+    /// [`generate_or_abort_core()`](Self::generate_or_abort_core), this method treats any
+    /// `timestamp` rollback as if it were within the `rollback_allowance`: it keeps the greater
+    /// `timestamp` of the previous ID and increments the counters, so every ID returned is
+    /// strictly greater than the preceding one. The trade-off is that the `timestamp` of the
+    /// generated IDs may run ahead of the real time indefinitely after the clock goes backwards,
+    /// because it only resumes following the `timestamp` passed once the latter catches up; until
+    /// then, the `timestamp` advances only when the counters overflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp` is not a 48-bit positive integer, or if the counters overflow while
+    /// the `timestamp` of the previous ID is the maximum 48-bit value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new();
+    /// let ts = 0x0123_4567_89ab;
+    /// let x = g.generate_or_extend_core(ts);
+    /// let y = g.generate_or_extend_core(ts - 3_600_000);
+    /// assert!(x < y);
+    /// assert_eq!(y.timestamp(), ts);
+    /// # }
+    /// ```
+    pub fn generate_or_extend_core(&mut self, timestamp: u64) -> Scru128Id {
+        // allowance of MAX_TIMESTAMP accepts any rollback of 48-bit timestamps
+        let value = self.generate_core(timestamp, MAX_TIMESTAMP).unwrap();
+        self.record_generated();
+        value
+    }
+
+    /// Generates an ID by advancing the counters under the current `timestamp` of the generator,
+    /// without treating that `timestamp` as a clock reading or recording the ID in the
+    /// statistics.
+    #[cfg(feature = "std")]
+    fn advance_counters(&mut self) -> Scru128Id {
+        let (ts_last_passed, last_clock_regressed) =
+            (self.ts_last_passed, self.last_clock_regressed);
+        let value = self
+            .generate_core(self.timestamp, self.rollback_allowance)
+            .unwrap();
+        (self.ts_last_passed, self.last_clock_regressed) = (ts_last_passed, last_clock_regressed);
+        value
+    }
+
+    /// Implements the common part of `generate_or_reset_core()` and `generate_or_abort_core()`
+    /// without updating the statistics of generated IDs and aborts.
+    fn generate_core(&mut self, timestamp: u64, rollback_allowance: u64) -> Option<Scru128Id> {
+        let result = self.generate_core_with(timestamp, rollback_allowance, |rng| {
+            Ok::<_, Infallible>(rng.next_u32())
+        });
+        match result {
+            Ok(value) => value,
+            Err(e) => match e {},
+        }
+    }
+}
+
+/// The internal states that a generation call updates, saved to roll back a call that does not
+/// produce an ID in the end.
+#[derive(Clone)]
+struct SavedState {
+    timestamp: u64,
+    counter_hi: u32,
+    counter_lo: u32,
+    ts_counter_hi: u64,
+    last_entropy: u32,
+    ts_last_passed: u64,
+    last_clock_regressed: bool,
+    ids_in_ms: u64,
+    stats: GeneratorStats,
+    decision_log: decision_log::DecisionLog,
+}
+
+impl<R, T> Scru128Generator<R, T> {
+    /// Saves the internal states that a generation call updates, except for the state of the
+    /// random number generator.
+    fn save_state(&self) -> SavedState {
+        SavedState {
+            timestamp: self.timestamp,
+            counter_hi: self.counter_hi,
+            counter_lo: self.counter_lo,
+            ts_counter_hi: self.ts_counter_hi,
+            last_entropy: self.last_entropy,
+            ts_last_passed: self.ts_last_passed,
+            last_clock_regressed: self.last_clock_regressed,
+            ids_in_ms: self.ids_in_ms,
+            stats: self.stats,
+            decision_log: self.decision_log.clone(),
+        }
+    }
+
+    /// Restores the internal states saved by `save_state()`.
+    fn restore_state(&mut self, saved: SavedState) {
+        self.timestamp = saved.timestamp;
+        self.counter_hi = saved.counter_hi;
+        self.counter_lo = saved.counter_lo;
+        self.ts_counter_hi = saved.ts_counter_hi;
+        self.last_entropy = saved.last_entropy;
+        self.ts_last_passed = saved.ts_last_passed;
+        self.last_clock_regressed = saved.last_clock_regressed;
+        self.ids_in_ms = saved.ids_in_ms;
+        self.stats = saved.stats;
+        self.decision_log = saved.decision_log;
+    }
+
+    /// Returns the maximum values of `counter_hi` and `counter_lo` under the current
+    /// `counter_bits` configuration.
+    const fn max_counters(&self) -> (u32, u32) {
+        if self.counter_bits >= 24 {
+            (MAX_COUNTER_HI >> (48 - self.counter_bits), MAX_COUNTER_LO)
+        } else {
+            (0, MAX_COUNTER_LO >> (24 - self.counter_bits))
+        }
+    }
+
+    /// Implements the generation algorithm, drawing random numbers through `next_u32` so that
+    /// the infallible and fallible random number sources share the same logic.
+    ///
+    /// If `next_u32` fails, the generator may be left in an intermediate state; the caller is
+    /// responsible for restoring the state as needed.
+    fn generate_core_with<E>(
+        &mut self,
+        timestamp: u64,
+        rollback_allowance: u64,
+        mut draw_u32: impl FnMut(&mut R) -> Result<u32, E>,
+    ) -> Result<Option<Scru128Id>, E> {
+        if timestamp == 0 || timestamp > MAX_TIMESTAMP {
+            panic!("`timestamp` must be a 48-bit positive integer");
+        } else if rollback_allowance > MAX_TIMESTAMP {
+            panic!("`rollback_allowance` out of reasonable range");
+        }
+
+        self.last_clock_regressed = timestamp < self.ts_last_passed;
+        self.ts_last_passed = timestamp;
+
+        let n_draws = Cell::new(0u8);
+        let mut next_u32 = |rng: &mut R| {
+            n_draws.set(n_draws.get() + 1);
+            draw_u32(rng)
+        };
+
+        let (max_counter_hi, max_counter_lo) = self.max_counters();
+        let mut same_timestamp = false;
+        let mut decision;
+        if timestamp > self.timestamp {
+            decision = Decision::NewTimestamp;
+            self.timestamp = timestamp;
+            self.counter_lo = self.init_counter_lo(&mut next_u32)? & max_counter_lo;
+        } else if timestamp + rollback_allowance >= self.timestamp {
+            // go on with previous timestamp if new one is not much smaller
+            same_timestamp = true;
+            decision = Decision::CounterIncrement;
+            if self.ids_in_ms >= self.per_ms_cap {
+                // increment timestamp once per-millisecond cap is reached
+                self.counter_hi = 0;
+                self.timestamp += 1;
+                same_timestamp = false;
+                decision = Decision::CapReached;
+                self.counter_lo = self.init_counter_lo(&mut next_u32)? & max_counter_lo;
+            } else {
+                self.counter_lo += 1;
+                if self.counter_lo > max_counter_lo {
+                    self.counter_lo = 0;
+                    self.counter_hi += 1;
+                    if self.counter_hi > max_counter_hi {
+                        self.counter_hi = 0;
+                        // increment timestamp at counter overflow
+                        self.timestamp += 1;
+                        same_timestamp = false;
+                        decision = Decision::CounterOverflow;
+                        self.stats.counter_overflows += 1;
+                        self.counter_lo = self.init_counter_lo(&mut next_u32)? & max_counter_lo;
+                    }
+                }
+            }
+        } else {
+            // abort if clock went backwards to unbearable extent
+            return Ok(None);
+        }
+
+        if self.timestamp - self.ts_counter_hi >= 1_000 || self.ts_counter_hi == 0 {
+            self.ts_counter_hi = self.timestamp;
+            self.counter_hi = next_u32(&mut self.rng)? & max_counter_hi;
+        }
+
+        let entropy = next_u32(&mut self.rng)?;
+        if self.constant_time_rng {
+            // discard extra words so that every ID consumes as many words as the longest path
+            while n_draws.get() < RNG_WORDS_PER_ID {
+                next_u32(&mut self.rng)?;
+            }
+        }
+
+        if self.entropy_repeat_check {
+            if same_timestamp && entropy == self.last_entropy {
+                self.record_entropy_repeat();
+            }
+            self.last_entropy = entropy;
+        }
+
+        self.ids_in_ms = match decision {
+            Decision::CounterIncrement => self.ids_in_ms + 1,
+            _ => 1,
+        };
+        self.decision_log.push(decision);
+        Ok(Some(Scru128Id::from_fields(
+            self.timestamp,
+            self.counter_hi,
+            self.counter_lo,
+            entropy,
+        )))
+    }
+
+    /// Returns the initial value of `counter_lo` for a new `timestamp` according to the counter
+    /// mode.
+    fn init_counter_lo<E>(
+        &mut self,
+        next_u32: &mut impl FnMut(&mut R) -> Result<u32, E>,
+    ) -> Result<u32, E> {
+        match self.counter_mode {
+            CounterMode::Random => next_u32(&mut self.rng),
+            CounterMode::Zero => Ok(0),
+        }
+    }
+}
+
+#[cfg(any(feature = "default_rng", test))]
+#[cfg_attr(docsrs, doc(cfg(feature = "default_rng")))]
+impl Scru128Generator {
+    /// Creates a generator object with the default random number generator.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns a factory function that creates a new generator with the default random number
+    /// generator each time it is called.
+    ///
+    /// The factory can be cloned and sent to other threads, which is handy to configure a thread
+    /// pool where each worker owns its generator rather than sharing one through a lock. Every
+    /// generator created by the factory is independent: it has its own counters and its own
+    /// random number generator seeded separately from the operating system. Therefore, the IDs
+    /// generated by different generators are not monotonically ordered with each other, even
+    /// though each generator produces monotonically increasing IDs. Use a shared generator or the
+    /// global generator (e.g., [`scru128::new()`](crate::new)) if the monotonic order across
+    /// threads matters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
+    ///
+    /// let factory = Scru128Generator::factory();
+    /// std::thread::scope(|s| {
+    ///     for _ in 0..4 {
+    ///         let factory = factory.clone();
+    ///         s.spawn(move || {
+    ///             let mut g = factory();
+    ///             let x = g.generate();
+    ///             let y = g.generate();
+    ///             assert!(x < y);
+    ///         });
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn factory() -> impl Fn() -> Self + Send + Sync + Clone {
+        Self::new
+    }
+}
+
+#[cfg(any(feature = "default_rng", test))]
+#[cfg_attr(docsrs, doc(cfg(feature = "default_rng")))]
+impl<T: Clone> Scru128Generator<DefaultRng, T> {
+    /// Returns a new generator that shares the configuration of `self` but has a freshly seeded
+    /// default random number generator and no monotonic state.
+    ///
+    /// [`Clone`] duplicates the entire state of the generator, including the `timestamp` and
+    /// counters of the last ID, so a clone and the original used concurrently produce IDs whose
+    /// `timestamp`, `counter_hi`, and `counter_lo` fields are identical, leaving only the
+    /// `entropy` field to distinguish them. Moreover, whether a cloned random number generator
+    /// diverges from the original depends on its `Clone` implementation (`DefaultRng` happens to
+    /// reseed a clone on first use, but a generic one may not). This method is safer to fork a
+    /// workload across threads or tasks: the returned generator is seeded independently from the
+    /// operating system and starts over from the initial state as a newly created generator does,
+    /// while it keeps the configured rollback allowance, counter bits, counter mode, constant-time
+    /// option, entropy repeat check, per-millisecond cap, decision recording option, and a clone
+    /// of the time source.
+    /// The statistics, the recorded decisions, and the stats callback are not carried over.
+    ///
+    /// As with generators created independently, the IDs produced by the original and the
+    /// returned generators are not monotonically ordered with each other.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::generator::CounterMode;
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new().with_counter_mode(CounterMode::Zero);
+    /// g.set_rollback_allowance(30_000);
+    ///
+    /// let mut h = g.clone_with_fresh_rng();
+    /// assert_eq!(h.rollback_allowance(), 30_000);
+    /// assert_eq!(h.generate().counter_lo(), 0);
+    /// # }
+    /// ```
+    pub fn clone_with_fresh_rng(&self) -> Self {
+        let mut g =
+            Self::with_rand_and_time_sources(DefaultRng::default(), self.time_source.clone());
+        g.counter_bits = self.counter_bits;
+        g.rollback_allowance = self.rollback_allowance;
+        g.constant_time_rng = self.constant_time_rng;
+        g.counter_mode = self.counter_mode;
+        g.entropy_repeat_check = self.entropy_repeat_check;
+        g.per_ms_cap = self.per_ms_cap;
+        g.set_record_decisions(self.decision_log.enabled());
+        g
+    }
+}
+
+impl<R: Scru128Rng, T: TimeSource> Scru128Generator<R, T> {
+    /// Generates a new SCRU128 ID object from the current `timestamp`, or resets the generator
+    /// upon significant timestamp rollback.
+    ///
+    /// See the [`Scru128Generator`] type documentation for the description.
+    pub fn generate(&mut self) -> Scru128Id {
+        let timestamp = self.time_source.unix_ts_ms();
+        self.generate_or_reset_core(timestamp, self.rollback_allowance)
+    }
+
+    /// Generates a new SCRU128 ID object from the current `timestamp`, or returns `None` upon
+    /// significant timestamp rollback.
+    ///
+    /// See the [`Scru128Generator`] type documentation for the description.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new();
+    /// let x = g.generate_or_abort().unwrap();
+    /// let y = g
+    ///     .generate_or_abort()
+    ///     .expect("The clock went backwards by ten seconds!");
+    /// assert!(x < y);
+    /// # }
+    /// ```
+    pub fn generate_or_abort(&mut self) -> Option<Scru128Id> {
+        let timestamp = self.time_source.unix_ts_ms();
+        self.generate_or_abort_core(timestamp, self.rollback_allowance)
+    }
+
+    /// Returns an iterator that borrows the generator and yields exactly `n` new IDs generated by
+    /// [`generate()`](Self::generate).
+    ///
+    /// The generator itself is an infinite [`Iterator`], so `for x in &mut g` never ends unless
+    /// the loop breaks explicitly. This method bounds the iteration while leaving the generator
+    /// usable afterwards, and it is equivalent to `g.by_ref().take(n)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new();
+    /// let mut prev = g.generate();
+    /// for curr in g.take_ids(100) {
+    ///     assert!(prev < curr);
+    ///     prev = curr;
+    /// }
+    /// assert!(prev < g.generate());
+    /// # }
+    /// ```
+    pub fn take_ids(&mut self, n: usize) -> iter::Take<&mut Self> {
+        self.take(n)
+    }
+
+    /// Generates a new SCRU128 ID object from the current `timestamp` and returns it together
+    /// with the 32-bit `entropy` field value embedded in it, for audit logging purposes.
+    ///
+    /// This method behaves exactly like [`generate`](Self::generate). Only the random number
+    /// drawn for the `entropy` field is exposed; the random numbers used to initialize the
+    /// `counter_hi` and `counter_lo` fields are not.
+    pub fn generate_with_entropy_audit(&mut self) -> (Scru128Id, u32) {
+        let value = self.generate();
+        (value, value.entropy())
+    }
+
+    /// Generates a new SCRU128 ID object from the current `timestamp` and returns it together
+    /// with the generation epoch, i.e., the number of times the generator has been reset upon
+    /// significant timestamp rollback.
+    ///
+    /// This method behaves exactly like [`generate`](Self::generate). The epoch starts at zero and
+    /// increments whenever the generator resets, which is the only occasion on which the
+    /// generator returns an ID smaller than the preceding one. Downstream consumers can therefore
+    /// compare IDs only within the same epoch and treat an epoch change as a discontinuity of the
+    /// monotonic order. The epoch is process-local metadata that is not embedded in the ID and
+    /// equals [`stats().resets`](GeneratorStats::resets) after the call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new();
+    /// let (x, epoch_x) = g.generate_with_epoch();
+    /// let (y, epoch_y) = g.generate_with_epoch();
+    /// if epoch_x == epoch_y {
+    ///     assert!(x < y);
+    /// }
+    /// # }
+    /// ```
+    pub fn generate_with_epoch(&mut self) -> (Scru128Id, u64) {
+        let value = self.generate();
+        (value, self.stats.resets)
+    }
+
+    /// Generates a new SCRU128 ID object that the `seen` predicate does not report as already
+    /// used, or returns `None` if all the IDs tried are reported as used.
+    ///
+    /// This method calls [`generate()`](Self::generate) and passes the result to `seen`, repeating
+    /// up to 16 times in total until `seen` returns `false`. It helps idempotent retries that must
+    /// not reuse an ID already persisted elsewhere, although a properly functioning generator is
+    /// not expected to reproduce an existing ID in the first place. Every ID tried advances the
+    /// generator state, so the IDs rejected by `seen` are consumed and never returned later, and
+    /// the returned ID is still greater than the preceding ones unless the generator is reset.
+    ///
+    /// The cost of this method is dominated by `seen`, which is called at least once per ID;
+    /// keep it cheap (e.g., a lookup in an in-memory set or a Bloom filter) rather than a
+    /// round trip to a database. `None` after the retry cap indicates that `seen` rejects IDs
+    /// indiscriminately or that the generator is malfunctioning, and retrying immediately is
+    /// unlikely to help.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
+    /// use std::collections::HashSet;
+    ///
+    /// let mut g = Scru128Generator::new();
+    /// let persisted: HashSet<_> = (0..100).map(|_| g.generate()).collect();
+    ///
+    /// let x = g.generate_avoiding(|id| persisted.contains(id)).unwrap();
+    /// assert!(!persisted.contains(&x));
+    /// # }
+    /// ```
+    pub fn generate_avoiding(
+        &mut self,
+        mut seen: impl FnMut(&Scru128Id) -> bool,
+    ) -> Option<Scru128Id> {
+        for _ in 0..MAX_AVOIDING_ATTEMPTS {
+            let value = self.generate();
+            if !seen(&value) {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+/// `Scru128Generator` behaves as an infinite iterator that produces a new ID for each call of
+/// `next()`.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "default_rng")]
+/// # {
+/// use scru128::Scru128Generator;
+///
+/// let g = Scru128Generator::new();
+/// for (i, e) in g.take(8).enumerate() {
+///     println!("[{}] {}", i, e);
+/// }
+/// # }
+/// ```
+impl<R: Scru128Rng, T: TimeSource> Iterator for Scru128Generator<R, T> {
+    type Item = Scru128Id;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.generate())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<R: Scru128Rng, T: TimeSource> iter::FusedIterator for Scru128Generator<R, T> {}
+
+/// The default timestamp rollback allowance.
+pub(crate) const DEFAULT_ROLLBACK_ALLOWANCE: u64 = 10_000; // 10 seconds
+
+/// The maximum number of random words consumed to generate an ID: `counter_lo`, `counter_hi`, and
+/// `entropy`.
+const RNG_WORDS_PER_ID: u8 = 3;
+
+/// The maximum number of IDs that `generate_avoiding()` generates before giving up.
+const MAX_AVOIDING_ATTEMPTS: usize = 16;
+
+#[cfg(feature = "std")]
+pub(crate) use with_std::unix_ts_ms;
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod with_std {
+    use super::{Scru128Generator, Scru128Id, Scru128Rng, TimeSource};
+    use std::time;
+
+    /// Returns the current Unix timestamp in milliseconds.
+    pub(crate) fn unix_ts_ms() -> u64 {
+        time::SystemTime::now()
+            .duration_since(time::UNIX_EPOCH)
+            .expect("clock may have gone backwards")
+            .as_millis() as u64
+    }
+
+    impl<R: Scru128Rng, T: TimeSource> Scru128Generator<R, T> {
+        /// Generates `n` SCRU128 ID objects that share the same `timestamp` and are strictly
+        /// ordered by the counters, or returns `None` if the counters do not have enough capacity
+        /// left for `n` IDs.
+        ///
+        /// The first ID is generated in the same manner as [`generate`](Self::generate), and the
+        /// remaining IDs reuse its `timestamp` by incrementing `counter_lo` (and `counter_hi` when
+        /// `counter_lo` overflows). Because the counters start at random values, the capacity
+        /// left for a burst varies from call to call: it is at most 2^48 but typically around
+        /// 2^47 IDs. The capacity is further limited by the
+        /// [per-millisecond cap](Self::set_per_ms_cap), if any, minus the number of IDs already
+        /// generated with the same `timestamp`. When the capacity is insufficient, this method
+        /// returns `None` without changing the internal states of the generator except for that
+        /// of the random number generator.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # #[cfg(feature = "default_rng")]
+        /// # {
+        /// use scru128::Scru128Generator;
+        ///
+        /// let mut g = Scru128Generator::new();
+        /// let burst = g.generate_burst(4).unwrap();
+        /// assert!(burst.iter().all(|e| e.timestamp() == burst[0].timestamp()));
+        /// assert!(burst.windows(2).all(|w| w[0] < w[1]));
+        /// # }
+        /// ```
+        pub fn generate_burst(&mut self, n: usize) -> Option<Vec<Scru128Id>> {
+            if n == 0 {
+                return Some(Vec::new());
+            }
+
+            let saved_state = self.save_state();
+            let timestamp = self.time_source.unix_ts_ms();
+            let first = self.generate_or_reset_unrecorded(timestamp, self.rollback_allowance);
+
+            let (max_counter_hi, max_counter_lo) = self.max_counters();
+            let capacity = (((max_counter_hi - self.counter_hi) as u64) << 24)
+                | (max_counter_lo - self.counter_lo) as u64;
+            let capacity = capacity.min(self.per_ms_cap - self.ids_in_ms);
+            if n as u64 - 1 > capacity {
+                self.restore_state(saved_state);
+                return None;
+            }
+
+            let mut burst = Vec::with_capacity(n);
+            self.record_generated();
+            burst.push(first);
+            for _ in 1..n {
+                burst.push(self.advance_counters());
+                self.record_generated();
+            }
+            Some(burst)
+        }
+
+        /// Generates `n` SCRU128 ID objects at once, reading the current `timestamp` only once
+        /// for the whole batch unless the counters overflow.
+        ///
+        /// This method behaves as if [`generate`](Self::generate) were called `n` times in a
+        /// row, except that it reuses the `timestamp` read at the beginning of the call and
+        /// advances the counters for the following IDs. The clock is read again only when the
+        /// counters are about to overflow or the [per-millisecond cap](Self::set_per_ms_cap) is
+        /// reached, so the returned IDs are strictly ordered within the batch and across calls.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # #[cfg(feature = "default_rng")]
+        /// # {
+        /// use scru128::Scru128Generator;
+        ///
+        /// let mut g = Scru128Generator::new();
+        /// let batch = g.generate_n(1_000);
+        /// assert_eq!(batch.len(), 1_000);
+        /// assert!(batch.windows(2).all(|w| w[0] < w[1]));
+        /// # }
+        /// ```
+        pub fn generate_n(&mut self, n: usize) -> Vec<Scru128Id> {
+            let mut buf = Vec::with_capacity(n);
+            self.generate_n_into(n, &mut buf);
+            buf
+        }
+
+        /// Appends `n` SCRU128 ID objects to `buf` in the same manner as
+        /// [`generate_n`](Self::generate_n), which is useful to reuse an allocation across
+        /// batches.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # #[cfg(feature = "default_rng")]
+        /// # {
+        /// use scru128::Scru128Generator;
+        ///
+        /// let mut g = Scru128Generator::new();
+        /// let mut buf = Vec::with_capacity(1_000);
+        /// for _ in 0..4 {
+        ///     buf.clear();
+        ///     g.generate_n_into(1_000, &mut buf);
+        ///     assert_eq!(buf.len(), 1_000);
+        /// }
+        /// # }
+        /// ```
+        pub fn generate_n_into(&mut self, n: usize, buf: &mut Vec<Scru128Id>) {
+            buf.reserve(n);
+            let (max_counter_hi, max_counter_lo) = self.max_counters();
+            let mut timestamp = self.time_source.unix_ts_ms();
+            for i in 0..n {
+                if i > 0
+                    && (self.counter_hi == max_counter_hi && self.counter_lo == max_counter_lo
+                        || self.ids_in_ms >= self.per_ms_cap)
+                {
+                    // re-read clock only when counters would overflow or cap is reached
+                    timestamp = self.time_source.unix_ts_ms();
+                }
+                buf.push(self.generate_or_reset_core(timestamp, self.rollback_allowance));
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        /// Is iterable with for-in loop
+        #[test]
+        fn is_iterable_with_for_in_loop() {
+            use super::Scru128Generator;
+
+            let mut i = 0;
+            for e in Scru128Generator::new() {
+                assert!(e.timestamp() > 0);
+                i += 1;
+                if i > 100 {
+                    break;
+                }
+            }
+            assert_eq!(i, 101);
+        }
+
+        /// Returns entropy embedded in generated ID for audit
+        #[test]
+        fn returns_entropy_embedded_in_generated_id_for_audit() {
+            use super::Scru128Generator;
+
+            let mut g = Scru128Generator::new();
+            let mut prev = g.generate();
+            for _ in 0..1_000 {
+                let (curr, entropy) = g.generate_with_entropy_audit();
+                assert_eq!(entropy, curr.entropy());
+                assert!(prev < curr);
+                prev = curr;
+            }
+        }
+
+        /// Generates burst of IDs sharing timestamp or returns None if counters are short
+        #[test]
+        fn generates_burst_of_ids_sharing_timestamp_or_returns_none_if_counters_are_short() {
+            use super::{unix_ts_ms, Scru128Generator};
+            use crate::{MAX_COUNTER_HI, MAX_COUNTER_LO};
+
+            let mut g = Scru128Generator::new();
+            let burst = g.generate_burst(10_000).unwrap();
+            assert_eq!(burst.len(), 10_000);
+            for i in 1..burst.len() {
+                assert_eq!(burst[i].timestamp(), burst[0].timestamp());
+                assert!(burst[i - 1] < burst[i]);
+            }
+            assert!(g.generate_burst(0).unwrap().is_empty());
+
+            // set up state so that only four more IDs fit in the current timestamp
+            g.timestamp = unix_ts_ms() + 5_000;
+            g.ts_counter_hi = g.timestamp;
+            g.counter_hi = MAX_COUNTER_HI;
+            g.counter_lo = MAX_COUNTER_LO - 5;
+            let saved = g.clone();
+
+            assert!(g.generate_burst(6).is_none());
+            assert_eq!(
+                (g.timestamp, g.counter_hi, g.counter_lo, g.ts_counter_hi),
+                (
+                    saved.timestamp,
+                    saved.counter_hi,
+                    saved.counter_lo,
+                    saved.ts_counter_hi
+                )
+            );
+            assert_eq!(
+                (g.ts_last_passed, g.last_clock_regressed, g.ids_in_ms),
+                (
+                    saved.ts_last_passed,
+                    saved.last_clock_regressed,
+                    saved.ids_in_ms
+                )
+            );
+            assert_eq!(g.stats(), saved.stats());
+
+            let burst = g.generate_burst(5).unwrap();
+            assert_eq!(burst[0].timestamp(), saved.timestamp);
+            assert_eq!(burst[4].counter_lo(), MAX_COUNTER_LO);
+            for i in 1..burst.len() {
+                assert_eq!(burst[i].timestamp(), saved.timestamp);
+                assert!(burst[i - 1] < burst[i]);
+            }
+        }
+
+        /// Limits burst to room left under per-millisecond cap
+        #[test]
+        fn limits_burst_to_room_left_under_per_ms_cap() {
+            use super::{Scru128Generator, TimeSource};
+            use crate::generator::{Decision, DefaultRng};
+
+            #[derive(Clone)]
+            struct FixedClock;
+            impl TimeSource for FixedClock {
+                fn unix_ts_ms(&mut self) -> u64 {
+                    0x0123_4567_89ab
+                }
+            }
+
+            let mut g =
+                Scru128Generator::with_rand_and_time_sources(DefaultRng::default(), FixedClock);
+            g.set_record_decisions(true);
+            g.set_per_ms_cap(2);
+            assert!(g.generate_burst(5).is_none());
+            assert!(g.generate_burst(3).is_none());
+            assert_eq!(g.stats().generated, 0);
+            assert!(g.recent_decisions().is_empty());
+            assert_eq!(g.last_id(), None);
+
+            let burst = g.generate_burst(2).unwrap();
+            assert_eq!(burst[0].timestamp(), 0x0123_4567_89ab);
+            assert_eq!(burst[1].timestamp(), 0x0123_4567_89ab);
+            assert!(burst[0] < burst[1]);
+            assert_eq!(g.stats().generated, 2);
+
+            // no room is left in the current timestamp, so a burst starts from the next one
+            let saved = g.clone();
+            assert!(g.generate_burst(3).is_none());
+            assert_eq!(g.stats(), saved.stats());
+            assert_eq!(g.recent_decisions(), saved.recent_decisions());
+            assert_eq!(g.last_id(), saved.last_id());
+
+            let burst = g.generate_burst(2).unwrap();
+            assert_eq!(burst[0].timestamp(), 0x0123_4567_89ac);
+            assert_eq!(burst[1].timestamp(), 0x0123_4567_89ac);
+            assert_eq!(
+                g.recent_decisions().last(),
+                Some(&Decision::CounterIncrement)
+            );
+        }
+
+        /// Does not flag clock regression after burst following step back
+        #[test]
+        fn does_not_flag_clock_regression_after_burst_following_step_back() {
+            use super::Scru128Generator;
+            use crate::generator::{DefaultRng, FnTimeSource};
+            use std::cell::Cell;
+
+            let ts = 0x0123_4567_89abu64;
+            let clock = Cell::new(ts);
+            let mut g = Scru128Generator::with_rand_and_time_sources(
+                DefaultRng::default(),
+                FnTimeSource(|| clock.get()),
+            );
+            g.generate();
+
+            clock.set(ts - 5);
+            g.generate();
+            assert!(g.last_clock_regressed());
+
+            clock.set(ts - 4);
+            let burst = g.generate_burst(10).unwrap();
+            assert!(!g.last_clock_regressed());
+            assert_eq!(burst[9].timestamp(), ts);
+
+            clock.set(ts - 3);
+            g.generate();
+            assert!(!g.last_clock_regressed());
+        }
+
+        /// Generates batch of unique IDs in strictly increasing order
+        #[test]
+        fn generates_batch_of_unique_ids_in_strictly_increasing_order() {
+            use super::Scru128Generator;
+            use std::collections::HashSet;
+
+            let mut g = Scru128Generator::new();
+            let mut prev = g.generate();
+            let batch = g.generate_n(1_000_000);
+            assert_eq!(batch.len(), 1_000_000);
+            assert_eq!(batch.iter().collect::<HashSet<_>>().len(), 1_000_000);
+            for e in &batch {
+                assert!(prev < *e);
+                prev = *e;
+            }
+
+            let mut buf = Vec::new();
+            for n in [0, 1, 10_000] {
+                buf.clear();
+                g.generate_n_into(n, &mut buf);
+                assert_eq!(buf.len(), n);
+                for e in &buf {
+                    assert!(prev < *e);
+                    prev = *e;
+                }
+            }
+            assert!(prev < g.generate());
+        }
+
+        /// Keeps batch in order across counter overflows
+        #[test]
+        fn keeps_batch_in_order_across_counter_overflows() {
+            use super::Scru128Generator;
+
+            let mut g = Scru128Generator::new();
+            g.set_counter_bits(4);
+            let batch = g.generate_n(1_000);
+            for i in 1..batch.len() {
+                assert!(batch[i - 1] < batch[i]);
+            }
+            assert!(g.stats().counter_overflows > 0);
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+mod with_heapless {
+    use super::{Scru128Generator, Scru128Id, Scru128Rng, TimeSource};
+
+    impl<R: Scru128Rng, T: TimeSource> Scru128Generator<R, T> {
+        /// Fills the remaining capacity of a fixed-capacity [`heapless::Vec`] with new SCRU128 ID
+        /// objects generated by [`generate()`](Self::generate), returning the number of IDs
+        /// pushed.
+        ///
+        /// This method is available under `no_std` environments and reads the current
+        /// `timestamp` from the configured time source; create the generator with
+        /// [`with_rand_and_time_sources()`](Self::with_rand_and_time_sources) and a time source
+        /// that works without `std`, such as [`FnTimeSource`](super::FnTimeSource).
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use scru128::generator::{FnTimeSource, Scru128Rng};
+        /// use scru128::{Scru128Generator, Scru128Id};
+        ///
+        /// # struct HardwareRng;
+        /// # impl Scru128Rng for HardwareRng {
+        /// #     fn next_u32(&mut self) -> u32 {
+        /// #         4
+        /// #     }
+        /// # }
+        /// # fn read_rtc_unix_ms() -> u64 {
+        /// #     0x0123_4567_89ab
+        /// # }
+        /// let mut g =
+        ///     Scru128Generator::with_rand_and_time_sources(HardwareRng, FnTimeSource(read_rtc_unix_ms));
+        /// let mut buf = heapless::Vec::<Scru128Id, 4>::new();
+        /// assert_eq!(g.generate_into_heapless(&mut buf), 4);
+        /// assert!(buf.windows(2).all(|w| w[0] < w[1]));
+        /// assert_eq!(g.generate_into_heapless(&mut buf), 0);
+        /// ```
+        pub fn generate_into_heapless<const N: usize>(
+            &mut self,
+            buf: &mut heapless::Vec<Scru128Id, N>,
+        ) -> usize {
+            let len = buf.len();
+            while !buf.is_full() {
+                let _ = buf.push(self.generate());
+            }
+            buf.len() - len
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{Scru128Generator, Scru128Id, Scru128Rng};
+        use crate::generator::FnTimeSource;
+
+        struct MockRng(u32);
+
+        impl Scru128Rng for MockRng {
+            fn next_u32(&mut self) -> u32 {
+                self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                self.0
+            }
+        }
+
+        /// Fills heapless Vec with monotonically increasing IDs
+        #[test]
+        fn fills_heapless_vec_with_monotonically_increasing_ids() {
+            let mut clock = 0x0123_4567_89abu64;
+            let mut g = Scru128Generator::with_rand_and_time_sources(
+                MockRng(42),
+                FnTimeSource(|| {
+                    clock += 1;
+                    clock / 3
+                }),
+            );
+
+            let mut buf = heapless::Vec::<Scru128Id, 8>::new();
+            assert_eq!(g.generate_into_heapless(&mut buf), 8);
+            assert!(buf.is_full());
+            for i in 1..buf.len() {
+                assert!(buf[i - 1] < buf[i]);
+            }
+            assert_eq!(g.generate_into_heapless(&mut buf), 0);
+
+            let last = buf[7];
+            buf.truncate(5);
+            assert_eq!(g.generate_into_heapless(&mut buf), 3);
+            assert!(last < buf[5]);
+            for i in 6..buf.len() {
+                assert!(buf[i - 1] < buf[i]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
 mod tests_generate_or_reset {
     use super::Scru128Generator;
 
-    /// Generates increasing IDs even with decreasing or constant timestamp
+    /// Generates increasing IDs even with decreasing or constant timestamp
+    #[test]
+    fn generates_increasing_ids_even_with_decreasing_or_constant_timestamp() {
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::new();
+
+        let mut prev = g.generate_or_reset_core(ts, 10_000);
+        assert_eq!(prev.timestamp(), ts);
+
+        for i in 0..100_000u64 {
+            let curr = g.generate_or_reset_core(ts - i.min(9_999), 10_000);
+            assert!(prev < curr);
+            prev = curr;
+        }
+        assert!(prev.timestamp() >= ts);
+    }
+
+    /// Breaks increasing order of IDs if timestamp goes backwards a lot
+    #[test]
+    fn breaks_increasing_order_of_ids_if_timestamp_goes_backwards_a_lot() {
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::new();
+
+        let mut prev = g.generate_or_reset_core(ts, 10_000);
+        assert_eq!(prev.timestamp(), ts);
+
+        let mut curr = g.generate_or_reset_core(ts - 10_000, 10_000);
+        assert!(prev < curr);
+
+        prev = curr;
+        curr = g.generate_or_reset_core(ts - 10_001, 10_000);
+        assert!(prev > curr);
+        assert_eq!(curr.timestamp(), ts - 10_001);
+
+        prev = curr;
+        curr = g.generate_or_reset_core(ts - 10_002, 10_000);
+        assert!(prev < curr);
+    }
+}
+
+#[cfg(test)]
+mod tests_counter_bits {
+    use super::Scru128Generator;
+
+    /// Increments timestamp sooner with narrowed counter
+    #[test]
+    fn increments_timestamp_sooner_with_narrowed_counter() {
+        let ts = 0x0123_4567_89abu64;
+        for bits in [0, 4, 23, 24, 25] {
+            let mut g = Scru128Generator::new();
+            g.set_counter_bits(bits);
+            assert_eq!(g.counter_bits(), bits);
+
+            let mut prev = g.generate_or_abort_core(ts, 10_000).unwrap();
+            for _ in 0..1_000 {
+                let curr = g.generate_or_abort_core(ts, 10_000).unwrap();
+                assert!(prev < curr);
+                assert_eq!((curr.counter_hi() as u64) << 24 >> bits, 0);
+                assert_eq!((curr.counter_lo() as u64) >> bits, 0);
+                prev = curr;
+            }
+
+            // 1001 IDs require at least (1001 / 2^bits - 1) timestamp increments
+            let min_increments = (1001u64 >> bits).saturating_sub(1);
+            assert!(prev.timestamp() - ts >= min_increments);
+        }
+
+        // default configuration never overflows 1001 IDs
+        let mut g = Scru128Generator::new();
+        assert_eq!(g.counter_bits(), 48);
+        g.generate_or_abort_core(ts, 10_000).unwrap();
+        g.counter_lo = 0;
+        for _ in 0..1_000 {
+            assert_eq!(
+                g.generate_or_abort_core(ts, 10_000).unwrap().timestamp(),
+                ts
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_constant_time_rng {
+    use super::{Scru128Generator, Scru128Rng};
+
+    /// Counts the number of calls.
+    #[derive(Default)]
+    struct CountingRng(u64);
+
+    impl Scru128Rng for CountingRng {
+        fn next_u32(&mut self) -> u32 {
+            self.0 += 1;
+            self.0 as u32
+        }
+    }
+
+    /// Consumes fixed number of rng words per ID
+    #[test]
+    fn consumes_fixed_number_of_rng_words_per_id() {
+        let ts = 0x0123_4567_89abu64;
+        let cases = [
+            ts,          // new timestamp and counter_hi
+            ts,          // same timestamp
+            ts + 1,      // new timestamp
+            ts - 1,      // rollback within allowance
+            ts + 1_000,  // new timestamp and counter_hi
+            ts + 1_000,  // same timestamp
+            ts - 20_000, // reset
+        ];
+
+        let mut g = Scru128Generator::with_rng(CountingRng::default());
+        let mut n_calls = [0; 7];
+        for (i, ts) in cases.into_iter().enumerate() {
+            let before = g.rng.0;
+            g.generate_or_reset_core(ts, 10_000);
+            n_calls[i] = g.rng.0 - before;
+        }
+        assert_eq!(n_calls, [3, 1, 2, 1, 3, 1, 3]);
+
+        let mut g = Scru128Generator::with_rng(CountingRng::default());
+        g.set_constant_time_rng(true);
+        for ts in cases {
+            let before = g.rng.0;
+            g.generate_or_reset_core(ts, 10_000);
+            assert_eq!(g.rng.0 - before, 3);
+        }
+
+        // counter overflow
+        g.set_counter_bits(0);
+        for _ in 0..10 {
+            let before = g.rng.0;
+            g.generate_or_reset_core(ts, 10_000);
+            assert_eq!(g.rng.0 - before, 3);
+        }
+        assert!(g.stats().counter_overflows > 0);
+    }
+}
+
+#[cfg(test)]
+mod tests_entropy_repeat_check {
+    use super::{DefaultRng, Scru128Generator, Scru128Rng};
+
+    /// Returns the same value forever.
+    struct ConstantRng;
+
+    impl Scru128Rng for ConstantRng {
+        fn next_u32(&mut self) -> u32 {
+            0xdead_beef
+        }
+    }
+
+    /// Reports repeated entropy from constant rng only if enabled
+    #[test]
+    fn reports_repeated_entropy_from_constant_rng_only_if_enabled() {
+        let ts = 0x0123_4567_89abu64;
+
+        let mut g = Scru128Generator::with_rng(ConstantRng);
+        for _ in 0..10 {
+            g.generate_or_reset_core(ts, 10_000);
+        }
+        assert_eq!(g.stats().entropy_repeats, 0);
+
+        let mut g = Scru128Generator::with_rng(ConstantRng);
+        g.set_entropy_repeat_check(true);
+        for _ in 0..10 {
+            g.generate_or_reset_core(ts, 10_000);
+        }
+        assert_eq!(g.stats().entropy_repeats, 9);
+
+        // does not compare across timestamps
+        g.generate_or_reset_core(ts + 1, 10_000);
+        assert_eq!(g.stats().entropy_repeats, 9);
+        g.generate_or_reset_core(ts + 1, 10_000);
+        assert_eq!(g.stats().entropy_repeats, 10);
+    }
+
+    /// Reports no repeated entropy from normal rng
+    #[test]
+    fn reports_no_repeated_entropy_from_normal_rng() {
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::<DefaultRng>::default();
+        g.set_entropy_repeat_check(true);
+        for i in 0..10_000 {
+            g.generate_or_reset_core(ts + i / 100, 10_000);
+        }
+        assert_eq!(g.stats().generated, 10_000);
+        assert_eq!(g.stats().entropy_repeats, 0);
+    }
+
+    /// Invokes stats callback immediately upon repeated entropy
+    #[cfg(feature = "std")]
+    #[test]
+    fn invokes_stats_callback_immediately_upon_repeated_entropy() {
+        use std::sync::{Arc, Mutex};
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let mut g = Scru128Generator::with_rng(ConstantRng);
+        g.set_entropy_repeat_check(true);
+        let cloned = Arc::clone(&reports);
+        g.set_stats_callback(1_000, move |stats| {
+            cloned.lock().unwrap().push(stats.entropy_repeats)
+        });
+
+        for _ in 0..3 {
+            g.generate_or_reset_core(0x0123_4567_89ab, 10_000);
+        }
+        assert_eq!(*reports.lock().unwrap(), [1, 2]);
+    }
+}
+
+#[cfg(all(test, feature = "default_rng"))]
+mod tests_factory {
+    use super::Scru128Generator;
+
+    /// Creates generators with independent entropy streams
+    #[test]
+    fn creates_generators_with_independent_entropy_streams() {
+        fn assert_send_sync_clone<T: Send + Sync + Clone>(_: &T) {}
+
+        let factory = Scru128Generator::factory();
+        assert_send_sync_clone(&factory);
+        let (mut g1, mut g2) = (factory(), factory.clone()());
+
+        let ts = 0x0123_4567_89abu64;
+        let mut n_equal = 0;
+        for _ in 0..100 {
+            let (x, y) = (
+                g1.generate_or_reset_core(ts, 10_000),
+                g2.generate_or_reset_core(ts, 10_000),
+            );
+            assert_eq!(x.timestamp(), y.timestamp());
+            if x.entropy() == y.entropy() {
+                n_equal += 1;
+            }
+        }
+        assert!(n_equal < 2);
+        assert_ne!(
+            g1.last_id().unwrap().counter_lo(),
+            g2.last_id().unwrap().counter_lo()
+        );
+    }
+}
+
+#[cfg(all(test, feature = "default_rng"))]
+mod tests_clone_with_fresh_rng {
+    use super::{CounterMode, Scru128Generator};
+
+    /// Draws independent entropy while keeping configuration
+    #[test]
+    fn draws_independent_entropy_while_keeping_configuration() {
+        let mut g = Scru128Generator::new().with_counter_mode(CounterMode::Zero);
+        g.set_rollback_allowance(30_000);
+        g.set_constant_time_rng(true);
+        g.set_entropy_repeat_check(true);
+        g.set_record_decisions(true);
+
+        let ts = 0x0123_4567_89ab;
+        g.generate_or_abort_core(ts, 10_000).unwrap();
+
+        let mut h1 = g.clone_with_fresh_rng();
+        let mut h2 = g.clone_with_fresh_rng();
+        for h in [&h1, &h2] {
+            assert_eq!(h.rollback_allowance(), 30_000);
+            assert_eq!(h.counter_mode, CounterMode::Zero);
+            assert!(h.constant_time_rng);
+            assert!(h.entropy_repeat_check);
+            assert!(h.decision_log.enabled());
+            assert!(h.recent_decisions().is_empty());
+            assert_eq!(h.last_id(), None);
+            assert_eq!(h.stats().generated, 0);
+        }
+
+        // unlike clones, which inherit the original's counters
+        let mut g = Scru128Generator::new();
+        g.generate_or_abort_core(ts, 10_000).unwrap();
+        let mut cloned = g.clone();
+        let mut fresh = g.clone_with_fresh_rng();
+        let x = g.generate_or_abort_core(ts, 10_000).unwrap();
+        let y = cloned.generate_or_abort_core(ts, 10_000).unwrap();
+        let z = fresh.generate_or_abort_core(ts, 10_000).unwrap();
+        assert_eq!(x.to_u128() >> 32, y.to_u128() >> 32);
+        assert_ne!(x.to_u128() >> 32, z.to_u128() >> 32);
+
+        let mut n_equal = 0;
+        for _ in 0..1_000 {
+            let x = h1.generate_or_abort_core(ts, 10_000).unwrap();
+            let y = h2.generate_or_abort_core(ts, 10_000).unwrap();
+            assert_eq!(x.timestamp(), y.timestamp());
+            assert_eq!(x.counter_lo(), y.counter_lo());
+            if x.entropy() == y.entropy() {
+                n_equal += 1;
+            }
+        }
+        assert!(n_equal < 2);
+        assert_ne!(h1.last_id(), h2.last_id());
+    }
+}
+
+#[cfg(test)]
+mod tests_rollback_allowance {
+    use super::{DefaultRng, FnTimeSource, Scru128Generator};
+    use core::cell::Cell;
+
+    /// Honors configured rollback allowance in generate methods
+    #[test]
+    fn honors_configured_rollback_allowance_in_generate_methods() {
+        let ts = 0x0123_4567_89abu64;
+        let clock = Cell::new(ts);
+        let mut g = Scru128Generator::with_rand_and_time_sources(
+            DefaultRng::default(),
+            FnTimeSource(|| clock.get()),
+        );
+        assert_eq!(g.rollback_allowance(), 10_000);
+        g.set_rollback_allowance(30_000);
+        assert_eq!(g.rollback_allowance(), 30_000);
+
+        let mut prev = g.generate();
+        assert_eq!(prev.timestamp(), ts);
+
+        // tolerates rollback up to configured value
+        for rollback in [10_001, 20_000, 30_000] {
+            clock.set(ts - rollback);
+            let curr = g.generate();
+            assert!(prev < curr);
+            assert_eq!(curr.timestamp(), ts);
+            prev = curr;
+
+            let curr = g.generate_or_abort().unwrap();
+            assert!(prev < curr);
+            prev = curr;
+        }
+
+        // aborts or resets beyond configured value
+        clock.set(ts - 30_001);
+        assert!(g.generate_or_abort().is_none());
+        let curr = g.generate();
+        assert!(curr < prev);
+        assert_eq!(curr.timestamp(), ts - 30_001);
+        assert_eq!(g.stats().resets, 1);
+
+        // does not affect core methods
+        assert!(g.generate_or_abort_core(ts - 60_002, 10_000).is_none());
+        assert!(g.generate_or_abort_core(ts - 40_001, 10_000).is_some());
+    }
+
+    /// Rejects rollback allowance out of range
+    #[cfg(feature = "std")]
+    #[test]
+    fn rejects_rollback_allowance_out_of_range() {
+        let mut g = Scru128Generator::<DefaultRng>::default();
+        g.set_rollback_allowance(0xffff_ffff_ffff);
+        assert_eq!(g.rollback_allowance(), 0xffff_ffff_ffff);
+        assert!(std::panic::catch_unwind(move || g.set_rollback_allowance(1 << 48)).is_err());
+    }
+}
+
+#[cfg(test)]
+mod tests_counter_mode {
+    use super::{CounterMode, Scru128Generator};
+
+    /// Initializes counter_lo to zero at new timestamp in zero mode
+    #[test]
+    fn initializes_counter_lo_to_zero_at_new_timestamp_in_zero_mode() {
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::new().with_counter_mode(CounterMode::Zero);
+
+        let mut prev = g.generate_or_abort_core(ts, 10_000).unwrap();
+        assert_eq!(prev.counter_lo(), 0);
+        for i in 1..1_000 {
+            let curr = g.generate_or_abort_core(ts + i / 100, 10_000).unwrap();
+            assert_eq!(curr.counter_lo(), (i % 100) as u32);
+            assert!(prev < curr);
+            prev = curr;
+        }
+
+        // counter overflow
+        g.set_counter_bits(4);
+        for _ in 0..100 {
+            let curr = g.generate_or_abort_core(ts, 10_000).unwrap();
+            assert!(prev < curr);
+            if curr.timestamp() > prev.timestamp() {
+                assert_eq!(curr.counter_lo(), 0);
+            }
+            prev = curr;
+        }
+
+        // default mode draws random counter_lo
+        let mut g = Scru128Generator::new();
+        let n_zeros = (0..1_000)
+            .filter(|i| {
+                g.generate_or_abort_core(ts + i, 10_000)
+                    .unwrap()
+                    .counter_lo()
+                    == 0
+            })
+            .count();
+        assert!(n_zeros < 10);
+    }
+}
+
+#[cfg(test)]
+mod tests_last_id {
+    use super::Scru128Generator;
+
+    /// Reconstructs last generated ID without entropy
     #[test]
-    fn generates_increasing_ids_even_with_decreasing_or_constant_timestamp() {
+    fn reconstructs_last_generated_id_without_entropy() {
         let ts = 0x0123_4567_89abu64;
         let mut g = Scru128Generator::new();
+        assert_eq!(g.last_id(), None);
 
         let mut prev = g.generate_or_reset_core(ts, 10_000);
-        assert_eq!(prev.timestamp(), ts);
+        for i in 0..1_000 {
+            let curr = g.generate_or_reset_core(ts + i / 100, 10_000);
+            let last = g.last_id().unwrap();
+            assert_eq!(last.timestamp(), curr.timestamp());
+            assert_eq!(last.counter_hi(), curr.counter_hi());
+            assert_eq!(last.counter_lo(), curr.counter_lo());
+            assert_eq!(last.entropy(), 0);
+            assert!(prev < last && last <= curr);
+            prev = curr;
+        }
 
-        for i in 0..100_000u64 {
-            let curr = g.generate_or_reset_core(ts - i.min(9_999), 10_000);
+        // abort does not change last ID
+        let last = g.last_id();
+        assert!(g.generate_or_abort_core(ts - 20_000, 10_000).is_none());
+        assert_eq!(g.last_id(), last);
+    }
+}
+
+#[cfg(test)]
+mod tests_take_ids {
+    use super::{DefaultRng, FnTimeSource, Scru128Generator};
+    use core::cell::Cell;
+
+    /// Yields exactly n increasing IDs and leaves generator usable
+    #[test]
+    fn yields_exactly_n_increasing_ids_and_leaves_generator_usable() {
+        let ts = 0x0123_4567_89abu64;
+        let clock = Cell::new(ts);
+        let mut g = Scru128Generator::with_rand_and_time_sources(
+            DefaultRng::default(),
+            FnTimeSource(|| clock.get()),
+        );
+
+        let mut total = 0;
+        for n in [0, 1, 2, 100, 10_000] {
+            let mut prev = g.generate();
+            let mut count = 0;
+            let iter = g.take_ids(n);
+            assert_eq!(iter.size_hint(), (n, Some(n)));
+            for curr in iter {
+                assert!(prev < curr);
+                prev = curr;
+                count += 1;
+                clock.set(ts + count as u64 / 10);
+            }
+            assert_eq!(count, n);
+
+            let curr = g.generate();
+            assert!(prev < curr);
+            total += n as u64 + 2;
+        }
+        assert_eq!(g.stats().generated, total);
+    }
+}
+
+#[cfg(test)]
+mod tests_per_ms_cap {
+    use super::{DefaultRng, FnTimeSource, Scru128Generator};
+    use core::cell::Cell;
+
+    /// Returns None once cap is reached until clock advances
+    #[test]
+    fn returns_none_once_cap_is_reached_until_clock_advances() {
+        const CAP: u64 = 100;
+        let ts = 0x0123_4567_89abu64;
+        let clock = Cell::new(ts);
+        let mut g = Scru128Generator::with_rand_and_time_sources(
+            DefaultRng::default(),
+            FnTimeSource(|| clock.get()),
+        );
+        assert_eq!(g.per_ms_cap(), u64::MAX);
+        g.set_per_ms_cap(CAP);
+        assert_eq!(g.per_ms_cap(), CAP);
+
+        let mut prev = g.generate_or_abort().unwrap();
+        for _ in 1..CAP {
+            let curr = g.generate_or_abort().unwrap();
             assert!(prev < curr);
             prev = curr;
         }
-        assert!(prev.timestamp() >= ts);
+        assert_eq!(g.generate_or_abort(), None);
+        assert_eq!(g.generate_or_abort_core(ts - 1_000, 10_000), None);
+        assert_eq!(g.generate_or_abort_core(ts - 20_000, 10_000), None);
+        assert_eq!(g.stats().generated, CAP);
+        assert_eq!(g.stats().aborts, 1);
+
+        clock.set(ts + 1);
+        for _ in 0..CAP {
+            let curr = g.generate_or_abort().unwrap();
+            assert!(prev < curr);
+            assert_eq!(curr.timestamp(), ts + 1);
+            prev = curr;
+        }
+        assert_eq!(g.generate_or_abort(), None);
     }
 
-    /// Breaks increasing order of IDs if timestamp goes backwards a lot
+    /// Moves on to next timestamp in other methods once cap is reached
     #[test]
-    fn breaks_increasing_order_of_ids_if_timestamp_goes_backwards_a_lot() {
+    fn moves_on_to_next_timestamp_in_other_methods_once_cap_is_reached() {
+        use super::Decision;
+
+        const CAP: u64 = 3;
         let ts = 0x0123_4567_89abu64;
-        let mut g = Scru128Generator::new();
+        let mut g = Scru128Generator::with_rng(DefaultRng::default());
+        g.set_record_decisions(true);
+        g.set_per_ms_cap(CAP);
 
         let mut prev = g.generate_or_reset_core(ts, 10_000);
-        assert_eq!(prev.timestamp(), ts);
+        for i in 1..=CAP * 10 {
+            let curr = match i % 3 {
+                0 => g.generate_or_reset_core(ts, 10_000),
+                1 => g.generate_checked_core(ts, 10_000).unwrap(),
+                _ => g.generate_or_extend_core(ts),
+            };
+            assert!(prev < curr);
+            assert_eq!(curr.timestamp(), ts + i / CAP);
+            prev = curr;
+        }
+        assert_eq!(g.stats().counter_overflows, 0);
+        assert_eq!(g.recent_decisions().last(), Some(&Decision::CapReached));
 
-        let mut curr = g.generate_or_reset_core(ts - 10_000, 10_000);
+        // batch re-reads clock instead of running ahead once cap is reached
+        #[cfg(feature = "std")]
+        {
+            let clock = Cell::new(ts);
+            let mut g = Scru128Generator::with_rand_and_time_sources(
+                DefaultRng::default(),
+                FnTimeSource(|| {
+                    clock.set(clock.get() + 1);
+                    clock.get()
+                }),
+            );
+            g.set_per_ms_cap(CAP);
+            let batch = g.generate_n(CAP as usize * 10);
+            for (i, e) in batch.iter().enumerate() {
+                assert_eq!(e.timestamp(), ts + 1 + i as u64 / CAP);
+            }
+        }
+    }
+
+    /// Restarts count after counter overflow
+    #[test]
+    fn restarts_count_after_counter_overflow() {
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::with_rng(DefaultRng::default());
+        g.set_counter_bits(2);
+        g.set_per_ms_cap(10);
+
+        // at most 4 IDs per timestamp with 2 counter bits
+        let mut prev = g.generate_or_abort_core(ts, 10_000).unwrap();
+        for _ in 0..100 {
+            let curr = g.generate_or_abort_core(ts, 10_000).unwrap();
+            assert!(prev < curr);
+            prev = curr;
+        }
+        assert!(g.stats().counter_overflows > 0);
+    }
+
+    /// Records cap reached decision
+    #[test]
+    fn records_cap_reached_decision() {
+        use super::Decision;
+
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::with_rng(DefaultRng::default());
+        g.set_record_decisions(true);
+        g.set_per_ms_cap(1);
+        assert!(g.generate_or_abort_core(ts, 10_000).is_some());
+        assert!(g.generate_or_abort_core(ts, 10_000).is_none());
+        assert_eq!(g.recent_decisions().last(), Some(&Decision::CapReached));
+    }
+
+    /// Tracks clock regression even when cap is reached
+    #[test]
+    fn tracks_clock_regression_even_when_cap_is_reached() {
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::with_rng(DefaultRng::default());
+        g.set_per_ms_cap(1);
+        assert!(g.generate_or_abort_core(ts, 10_000).is_some());
+
+        assert!(g.generate_or_abort_core(ts - 5, 10_000).is_none());
+        assert!(g.last_clock_regressed());
+        assert!(g.generate_or_abort_core(ts, 10_000).is_none());
+        assert!(!g.last_clock_regressed());
+        assert!(g.generate_or_abort_core(ts - 1, 10_000).is_none());
+        assert!(g.last_clock_regressed());
+        assert_eq!(g.peek_timestamp(), ts);
+    }
+}
+
+#[cfg(test)]
+mod tests_peek_timestamp {
+    use super::{DefaultRng, Scru128Generator};
+
+    /// Peeks logical timestamp running ahead after counter overflow
+    #[test]
+    fn peeks_logical_timestamp_running_ahead_after_counter_overflow() {
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::with_rng(DefaultRng::default());
+        g.set_counter_bits(4);
+        assert_eq!(g.peek_timestamp(), 0);
+
+        let mut prev = g.generate_or_abort_core(ts, 10_000).unwrap();
+        assert_eq!(g.peek_timestamp(), ts);
+        for _ in 0..1_000 {
+            let before = g.peek_timestamp();
+            let curr = g.generate_or_abort_core(ts, 10_000).unwrap();
+            assert!(prev < curr);
+            assert_eq!(g.peek_timestamp(), curr.timestamp());
+            assert!(g.peek_timestamp() - before <= 1);
+            prev = curr;
+        }
+
+        // 16 IDs per millisecond with 4 counter bits
+        assert_eq!(g.stats().counter_overflows, g.peek_timestamp() - ts);
+        assert!(g.peek_timestamp() >= ts + 1_000 / 16);
+
+        // abort does not change logical timestamp
+        let peeked = g.peek_timestamp();
+        assert!(g.generate_or_abort_core(ts - 20_000, 10_000).is_none());
+        assert_eq!(g.peek_timestamp(), peeked);
+    }
+}
+
+#[cfg(test)]
+mod tests_last_clock_regressed {
+    use super::{DefaultRng, FnTimeSource, Scru128Generator};
+    use core::cell::Cell;
+
+    /// Flags clock regression within allowance while keeping IDs monotonic
+    #[test]
+    fn flags_clock_regression_within_allowance_while_keeping_ids_monotonic() {
+        let ts = 0x0123_4567_89abu64;
+        let clock = Cell::new(ts);
+        let mut g = Scru128Generator::with_rand_and_time_sources(
+            DefaultRng::default(),
+            FnTimeSource(|| clock.get()),
+        );
+
+        let mut prev = g.generate();
+        assert!(!g.last_clock_regressed());
+
+        // step back within allowance
+        clock.set(ts - 5_000);
+        let curr = g.generate();
+        assert!(g.last_clock_regressed());
         assert!(prev < curr);
+        assert_eq!(curr.timestamp(), ts);
+        prev = curr;
 
+        // flag lasts for one call only
+        let curr = g.generate();
+        assert!(!g.last_clock_regressed());
+        assert!(prev < curr);
         prev = curr;
-        curr = g.generate_or_reset_core(ts - 10_001, 10_000);
-        assert!(prev > curr);
-        assert_eq!(curr.timestamp(), ts - 10_001);
 
+        clock.set(ts - 4_999);
+        let curr = g.generate();
+        assert!(!g.last_clock_regressed());
+        assert!(prev < curr);
         prev = curr;
-        curr = g.generate_or_reset_core(ts - 10_002, 10_000);
+
+        clock.set(ts - 6_000);
+        let curr = g.generate();
+        assert!(g.last_clock_regressed());
+        assert!(prev < curr);
+        prev = curr;
+
+        clock.set(ts + 1);
+        let curr = g.generate();
+        assert!(!g.last_clock_regressed());
+        assert!(prev < curr);
+        assert_eq!(g.stats().resets, 0);
+
+        // significant rollback also sets flag
+        clock.set(ts - 20_000);
+        g.generate();
+        assert!(g.last_clock_regressed());
+        assert_eq!(g.stats().resets, 1);
+
+        assert!(g.generate_or_abort_core(ts - 40_000, 10_000).is_none());
+        assert!(g.last_clock_regressed());
+    }
+
+    /// Does not flag timestamp running ahead after counter overflow
+    #[test]
+    fn does_not_flag_timestamp_running_ahead_after_counter_overflow() {
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::with_rng(DefaultRng::default());
+        g.set_counter_bits(1);
+        let mut prev = g.generate_or_reset_core(ts, 10_000);
+        for _ in 0..100 {
+            let curr = g.generate_or_reset_core(ts, 10_000);
+            assert!(!g.last_clock_regressed());
+            assert!(prev < curr);
+            prev = curr;
+        }
+        assert!(prev.timestamp() > ts);
+    }
+}
+
+#[cfg(test)]
+mod tests_generate_with_epoch {
+    use super::{DefaultRng, FnTimeSource, Scru128Generator};
+    use core::cell::Cell;
+
+    /// Increments epoch exactly once per reset
+    #[test]
+    fn increments_epoch_exactly_once_per_reset() {
+        let ts = 0x0123_4567_89abu64;
+        let clock = Cell::new(ts);
+        let mut g = Scru128Generator::with_rand_and_time_sources(
+            DefaultRng::default(),
+            FnTimeSource(|| clock.get()),
+        );
+
+        let (mut prev, epoch) = g.generate_with_epoch();
+        assert_eq!(epoch, 0);
+        for i in 0..1_000 {
+            // tolerable rollback does not change epoch
+            clock.set(ts - i % 10_001);
+            let (curr, epoch) = g.generate_with_epoch();
+            assert!(prev < curr);
+            assert_eq!(epoch, 0);
+            prev = curr;
+        }
+
+        clock.set(ts - 10_001);
+        let (curr, epoch) = g.generate_with_epoch();
+        assert!(curr < prev);
+        assert_eq!(epoch, 1);
+        prev = curr;
+
+        for _ in 0..1_000 {
+            let (curr, epoch) = g.generate_with_epoch();
+            assert!(prev < curr);
+            assert_eq!(epoch, 1);
+            prev = curr;
+        }
+        assert_eq!(g.stats().resets, 1);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests_generate_avoiding {
+    use super::{DefaultRng, Scru128Generator, MAX_AVOIDING_ATTEMPTS};
+
+    /// Skips IDs reported as seen
+    #[test]
+    fn skips_ids_reported_as_seen() {
+        let mut g = Scru128Generator::<DefaultRng>::default();
+        let mut first = None;
+        let x = g
+            .generate_avoiding(|id| {
+                if first.is_none() {
+                    first = Some(*id);
+                    true
+                } else {
+                    false
+                }
+            })
+            .unwrap();
+        let first = first.unwrap();
+        assert_ne!(x, first);
+        assert!(first < x);
+        assert_eq!(g.stats().generated, 2);
+
+        let mut n_calls = 0;
+        let y = g
+            .generate_avoiding(|_| {
+                n_calls += 1;
+                false
+            })
+            .unwrap();
+        assert!(x < y);
+        assert_eq!(n_calls, 1);
+    }
+
+    /// Gives up after retry cap
+    #[test]
+    fn gives_up_after_retry_cap() {
+        let mut g = Scru128Generator::<DefaultRng>::default();
+        let mut n_calls = 0;
+        let result = g.generate_avoiding(|_| {
+            n_calls += 1;
+            true
+        });
+        assert_eq!(result, None);
+        assert_eq!(n_calls, MAX_AVOIDING_ATTEMPTS);
+        assert_eq!(g.stats().generated, MAX_AVOIDING_ATTEMPTS as u64);
+
+        // continues with increasing IDs afterwards
+        let prev = g.last_id().unwrap();
+        assert!(prev < g.generate_avoiding(|_| false).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod tests_generate_at {
+    use super::Scru128Generator;
+
+    /// Generates increasing IDs at fixed timestamp
+    #[test]
+    fn generates_increasing_ids_at_fixed_timestamp() {
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::new();
+
+        let mut prev = g.generate_at(ts);
+        assert_eq!(prev.timestamp(), ts);
+        for _ in 0..100_000 {
+            let curr = g.generate_at(ts);
+            assert!(prev < curr);
+            prev = curr;
+        }
+        assert!(prev.timestamp() >= ts);
+        assert_eq!(g.stats().resets, 0);
+    }
+
+    /// Generates increasing IDs with decreasing timestamp within allowance
+    #[test]
+    fn generates_increasing_ids_with_decreasing_timestamp_within_allowance() {
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::new();
+        g.set_rollback_allowance(30_000);
+
+        let mut prev = g.generate_at(ts);
+        for i in 0..30_000u64 {
+            let curr = g.generate_at(ts - i);
+            assert!(prev < curr);
+            assert_eq!(curr.timestamp(), ts);
+            prev = curr;
+        }
+        assert_eq!(g.stats().resets, 0);
+
+        // resets beyond configured allowance
+        let curr = g.generate_at(ts - 30_001);
+        assert!(curr < prev);
+        assert_eq!(curr.timestamp(), ts - 30_001);
+        assert_eq!(g.stats().resets, 1);
+    }
+}
+
+#[cfg(test)]
+mod tests_generate_or_extend {
+    use super::Scru128Generator;
+
+    /// Generates increasing IDs with steadily decreasing timestamp
+    #[test]
+    fn generates_increasing_ids_with_steadily_decreasing_timestamp() {
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::new();
+
+        let mut prev = g.generate_or_extend_core(ts);
+        assert_eq!(prev.timestamp(), ts);
+
+        for i in 0..100_000u64 {
+            let curr = g.generate_or_extend_core(ts - i * 1_000);
+            assert!(prev < curr);
+            prev = curr;
+        }
+        assert!(prev.timestamp() >= ts);
+        assert_eq!(g.stats().resets, 0);
+        assert_eq!(g.stats().aborts, 0);
+        assert_eq!(g.stats().generated, 100_001);
+
+        // resume following timestamp once it catches up
+        let curr = g.generate_or_extend_core(prev.timestamp() + 1);
         assert!(prev < curr);
+        assert_eq!(curr.timestamp(), prev.timestamp() + 1);
     }
 }
 
@@ -386,4 +2715,40 @@ mod tests_generate_or_abort {
         curr = g.generate_or_abort_core(ts - 10_002, 10_000);
         assert!(curr.is_none());
     }
+
+    /// Reports magnitude of significant timestamp rollback
+    #[test]
+    fn reports_magnitude_of_significant_timestamp_rollback() {
+        use super::ClockRollback;
+
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::new();
+
+        let prev = g.generate_checked_core(ts, 10_000).unwrap();
+        assert_eq!(prev.timestamp(), ts);
+
+        let curr = g.generate_checked_core(ts - 10_000, 10_000).unwrap();
+        assert!(prev < curr);
+
+        let err = g.generate_checked_core(ts - 10_001, 10_000).unwrap_err();
+        assert_eq!(err, ClockRollback { by_ms: 10_001 });
+
+        let err = g.generate_checked_core(ts - 10_002, 10_000).unwrap_err();
+        assert_eq!(err.by_ms, 10_002);
+
+        let err = g.generate_checked_core(1, 0).unwrap_err();
+        assert_eq!(err.by_ms, ts - 1);
+        assert_eq!(g.stats().aborts, 3);
+
+        // counts from timestamp advanced by counter overflow
+        let mut g = Scru128Generator::new();
+        g.set_counter_bits(0);
+        for _ in 0..5 {
+            g.generate_checked_core(ts, 10_000).unwrap();
+        }
+        assert_eq!(g.last_id().unwrap().timestamp(), ts + 4);
+        let err = g.generate_checked_core(ts, 3).unwrap_err();
+        assert_eq!(err.by_ms, 4);
+        assert_eq!(g.generate_checked_core(ts, 4).unwrap().timestamp(), ts + 5);
+    }
 }
@@ -0,0 +1,206 @@
+use super::Scru128Generator;
+
+/// The way the generator handled the `timestamp` in a generation call, as recorded by
+/// [`Scru128Generator::recent_decisions()`].
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub enum Decision {
+    /// The `timestamp` passed was greater than the previous one, and the generator adopted it.
+    NewTimestamp,
+
+    /// The `timestamp` passed was not greater than the previous one (but within the rollback
+    /// allowance), and the generator incremented the counters under the previous `timestamp`.
+    CounterIncrement,
+
+    /// The counters overflowed while reusing the previous `timestamp`, and the generator
+    /// incremented the `timestamp` by one to continue.
+    CounterOverflow,
+
+    /// The `timestamp` went backwards significantly, and the generator reset its internal states
+    /// to generate an ID from the `timestamp` passed.
+    Reset,
+
+    /// The `timestamp` went backwards significantly, and the generator returned no ID.
+    Abort,
+
+    /// The generator had issued as many IDs as the per-millisecond cap with the current
+    /// `timestamp`, and it either returned no ID or incremented the `timestamp` by one to
+    /// continue, depending on the method called.
+    CapReached,
+}
+
+/// The maximum number of decisions retained by [`Scru128Generator::recent_decisions()`].
+pub const DECISION_LOG_CAPACITY: usize = 32;
+
+/// A fixed-capacity log of the most recent decisions, kept in chronological order.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub(super) struct DecisionLog {
+    enabled: bool,
+    len: u8,
+    buf: [Decision; DECISION_LOG_CAPACITY],
+}
+
+impl DecisionLog {
+    pub(super) const fn new() -> Self {
+        Self {
+            enabled: false,
+            len: 0,
+            buf: [Decision::NewTimestamp; DECISION_LOG_CAPACITY],
+        }
+    }
+
+    /// Returns whether the recording is enabled.
+    #[cfg(any(feature = "default_rng", test))]
+    pub(super) const fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Appends a decision, discarding the oldest one if the log is full.
+    pub(super) fn push(&mut self, decision: Decision) {
+        if !self.enabled {
+            return;
+        }
+        let len = self.len as usize;
+        if len < DECISION_LOG_CAPACITY {
+            self.buf[len] = decision;
+            self.len += 1;
+        } else {
+            self.buf.copy_within(1.., 0);
+            self.buf[len - 1] = decision;
+        }
+    }
+
+    /// Overwrites the last decision, which is used to record a reset as a single decision even
+    /// though the generator internally retries after the reset.
+    pub(super) fn replace_last(&mut self, decision: Decision) {
+        if self.enabled && self.len > 0 {
+            self.buf[self.len as usize - 1] = decision;
+        }
+    }
+}
+
+impl<R, T> Scru128Generator<R, T> {
+    /// Enables or disables the recording of recent decisions retrievable through
+    /// [`recent_decisions()`](Self::recent_decisions). This option is disabled by default.
+    ///
+    /// Disabling the recording also clears the decisions recorded so far.
+    pub fn set_record_decisions(&mut self, enabled: bool) {
+        self.decision_log.enabled = enabled;
+        if !enabled {
+            self.decision_log.len = 0;
+        }
+    }
+
+    /// Returns the decisions made by the most recent generation calls, from the oldest to the
+    /// newest, if enabled by [`set_record_decisions()`](Self::set_record_decisions).
+    ///
+    /// Each call of a generation method that produces an ID or aborts records one [`Decision`]
+    /// describing how the generator handled the `timestamp`, which helps monitor clock behavior
+    /// (e.g., on a dashboard polling the generator periodically) without registering a callback
+    /// invoked for each call. The log retains at most [`DECISION_LOG_CAPACITY`] decisions in a
+    /// fixed-size buffer embedded in the generator, so recording does not allocate memory and
+    /// the oldest decisions are discarded as new ones arrive. The log belongs to each generator
+    /// instance; a cloned generator carries a copy of the log and records its own decisions
+    /// afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::generator::Decision;
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new();
+    /// g.set_record_decisions(true);
+    ///
+    /// let ts = 0x0123_4567_89ab;
+    /// g.generate_or_reset_core(ts, 10_000);
+    /// g.generate_or_reset_core(ts - 1, 10_000);
+    /// g.generate_or_abort_core(ts - 60_000, 10_000);
+    /// g.generate_or_reset_core(ts - 60_000, 10_000);
+    /// assert_eq!(
+    ///     g.recent_decisions(),
+    ///     [
+    ///         Decision::NewTimestamp,
+    ///         Decision::CounterIncrement,
+    ///         Decision::Abort,
+    ///         Decision::Reset,
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    pub fn recent_decisions(&self) -> &[Decision] {
+        &self.decision_log.buf[..self.decision_log.len as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decision, DECISION_LOG_CAPACITY};
+    use crate::generator::{DefaultRng, Scru128Generator};
+
+    /// Records decisions upon varied clock movements
+    #[test]
+    fn records_decisions_upon_varied_clock_movements() {
+        use Decision::*;
+
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::<DefaultRng>::default();
+        g.generate_or_reset_core(ts, 10_000);
+        assert!(g.recent_decisions().is_empty());
+
+        g.set_record_decisions(true);
+        g.generate_or_reset_core(ts + 1, 10_000);
+        g.generate_or_reset_core(ts + 1, 10_000);
+        g.generate_or_reset_core(ts - 5_000, 10_000);
+        g.generate_or_reset_core(ts + 2, 10_000);
+        assert!(g.generate_or_abort_core(ts - 20_000, 10_000).is_none());
+        assert!(g.generate_checked_core(ts - 20_000, 10_000).is_err());
+        g.generate_or_reset_core(ts - 20_000, 10_000);
+        g.generate_or_reset_core(ts - 20_000, 10_000);
+        assert_eq!(
+            g.recent_decisions(),
+            [
+                NewTimestamp,
+                CounterIncrement,
+                CounterIncrement,
+                NewTimestamp,
+                Abort,
+                Abort,
+                Reset,
+                CounterIncrement,
+            ]
+        );
+
+        g.set_counter_bits(0);
+        g.generate_or_reset_core(ts - 20_000, 10_000);
+        assert_eq!(g.recent_decisions().last(), Some(&CounterOverflow));
+
+        g.set_record_decisions(false);
+        assert!(g.recent_decisions().is_empty());
+        g.generate_or_reset_core(ts, 10_000);
+        assert!(g.recent_decisions().is_empty());
+    }
+
+    /// Retains most recent decisions up to capacity
+    #[test]
+    fn retains_most_recent_decisions_up_to_capacity() {
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::<DefaultRng>::default();
+        g.set_record_decisions(true);
+
+        for i in 0..DECISION_LOG_CAPACITY as u64 * 2 {
+            g.generate_or_reset_core(ts + i, 10_000);
+            assert!(g.recent_decisions().len() <= DECISION_LOG_CAPACITY);
+        }
+        assert!(g.generate_or_abort_core(1, 0).is_none());
+
+        let decisions = g.recent_decisions();
+        assert_eq!(decisions.len(), DECISION_LOG_CAPACITY);
+        assert_eq!(decisions[DECISION_LOG_CAPACITY - 1], Decision::Abort);
+        for e in &decisions[..DECISION_LOG_CAPACITY - 1] {
+            assert_eq!(*e, Decision::NewTimestamp);
+        }
+    }
+}
@@ -1,7 +1,9 @@
 #![cfg_attr(docsrs, doc(cfg(feature = "default_rng")))]
 
 #[cfg(feature = "default_rng")]
-use rand::{rngs::adapter::ReseedingRng, rngs::OsRng, SeedableRng as _};
+use rand::{rngs::adapter::ReseedingRng, rngs::OsRng, RngCore, SeedableRng as _};
+#[cfg(feature = "default_rng")]
+use std::sync::{atomic, Arc, Mutex, PoisonError};
 
 #[cfg(all(test, not(feature = "default_rng")))]
 use rand::{rngs::StdRng, SeedableRng as _};
@@ -13,10 +15,23 @@ use rand::{rngs::StdRng, SeedableRng as _};
 /// same strategy as that employed by [`ThreadRng`]; see the docs of `rand` crate for a detailed
 /// discussion on the strategy.
 ///
+/// If `OsRng` fails to supply a new seed at a reseeding point, `DefaultRng` does not panic but
+/// reseeds itself from a fallback ChaCha12 stream that was seeded by `OsRng` upon creation, and it
+/// records the failure so that [`reseed_failed()`](Self::reseed_failed) and
+/// [`Scru128Generator::reseed_failed()`] report it. Applications that cannot tolerate running
+/// without fresh system entropy should check the indicator and act on it, e.g., by raising an
+/// alert or replacing the generator.
+///
+/// Cloning a `DefaultRng` does not draw a new seed immediately; the clone reseeds itself upon its
+/// first use as `ReseedingRng` does, and thus cloning never panics. The clones share the reseed
+/// failure indicator and the fallback stream with the original, so a failure detected through any
+/// of them is reported by all of them.
+///
 /// This structure does exist without the `default_rng` feature flag but is not able to be
 /// instantiated or used as a random number generator.
 ///
 /// [`Scru128Generator`]: super::Scru128Generator
+/// [`Scru128Generator::reseed_failed()`]: super::Scru128Generator::reseed_failed
 /// [`ChaCha12Core`]: rand_chacha::ChaCha12Core
 /// [`OsRng`]: rand::rngs::OsRng
 /// [`ReseedingRng`]: rand::rngs::adapter::ReseedingRng
@@ -26,7 +41,11 @@ pub struct DefaultRng {
     _private: (),
 
     #[cfg(feature = "default_rng")]
-    inner: ReseedingRng<rand_chacha::ChaCha12Core, OsRng>,
+    inner: ReseedingRng<rand_chacha::ChaCha12Core, Reseeder>,
+
+    /// Whether reseeding has failed, shared with the reseeder and clones.
+    #[cfg(feature = "default_rng")]
+    reseed_failed: Arc<atomic::AtomicBool>,
 
     #[cfg(all(test, not(feature = "default_rng")))]
     inner: StdRng,
@@ -42,17 +61,12 @@ impl super::Scru128Rng for DefaultRng {
 #[cfg(any(feature = "default_rng", test))]
 impl Default for DefaultRng {
     fn default() -> Self {
+        #[cfg(feature = "default_rng")]
+        return Self::with_reseed_source(|dest| OsRng.try_fill_bytes(dest));
+
+        #[cfg(all(test, not(feature = "default_rng")))]
         Self {
             _private: (),
-
-            #[cfg(feature = "default_rng")]
-            inner: {
-                let rng = rand_chacha::ChaCha12Core::from_rng(OsRng)
-                    .expect("could not initialize DefaultRng");
-                ReseedingRng::new(rng, 1024 * 64, OsRng)
-            },
-
-            #[cfg(all(test, not(feature = "default_rng")))]
             inner: {
                 let local_var = 0u32;
                 let addr_as_seed = (&local_var as *const u32) as u64;
@@ -62,6 +76,91 @@ impl Default for DefaultRng {
     }
 }
 
+#[cfg(feature = "default_rng")]
+impl DefaultRng {
+    /// Creates an instance seeded by `OsRng` that obtains new seeds from `reseed_source`.
+    fn with_reseed_source(reseed_source: ReseedSource) -> Self {
+        let rng =
+            rand_chacha::ChaCha12Core::from_rng(OsRng).expect("could not initialize DefaultRng");
+        let reseed_failed = Arc::new(atomic::AtomicBool::new(false));
+        let reseeder = Reseeder {
+            source: reseed_source,
+            fallback: Arc::new(Mutex::new(
+                rand_chacha::ChaCha12Rng::from_rng(OsRng).expect("could not initialize DefaultRng"),
+            )),
+            failed: Arc::clone(&reseed_failed),
+        };
+        Self {
+            _private: (),
+            inner: ReseedingRng::new(rng, 1024 * 64, reseeder),
+            reseed_failed,
+        }
+    }
+
+    /// Returns `true` if reseeding from `OsRng` has failed at least once in this instance or any
+    /// instance that shares the reseed failure indicator with it through cloning.
+    pub fn reseed_failed(&self) -> bool {
+        self.reseed_failed.load(atomic::Ordering::Relaxed)
+    }
+}
+
+#[cfg(feature = "default_rng")]
+impl<T> super::Scru128Generator<DefaultRng, T> {
+    /// Returns `true` if the [`DefaultRng`] of the generator has failed to reseed from `OsRng` at
+    /// least once.
+    ///
+    /// See the [`DefaultRng`] type documentation for details.
+    pub fn reseed_failed(&self) -> bool {
+        self.rng.reseed_failed()
+    }
+}
+
+/// A function that fills a buffer with new seed material.
+#[cfg(feature = "default_rng")]
+type ReseedSource = fn(&mut [u8]) -> Result<(), rand::Error>;
+
+/// The reseeder of [`DefaultRng`] that falls back to a ChaCha12 stream and records the failure
+/// if the primary source fails.
+///
+/// The fallback stream is shared among clones so that they do not reseed from identical output.
+#[cfg(feature = "default_rng")]
+#[derive(Clone, Debug)]
+struct Reseeder {
+    source: ReseedSource,
+    fallback: Arc<Mutex<rand_chacha::ChaCha12Rng>>,
+    failed: Arc<atomic::AtomicBool>,
+}
+
+#[cfg(feature = "default_rng")]
+impl RngCore for Reseeder {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if (self.source)(dest).is_err() {
+            self.failed.store(true, atomic::Ordering::Relaxed);
+            let mut fallback = self.fallback.lock().unwrap_or_else(PoisonError::into_inner);
+            // keep forked processes sharing the same fallback state from reseeding identically
+            fallback.set_stream(std::process::id() as u64);
+            fallback.fill_bytes(dest);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{super::Scru128Rng, DefaultRng};
@@ -107,4 +206,53 @@ mod tests {
             .iter()
             .all(|e| (*e as f64 / N_LOOPS as f64 - 0.5).abs() < margin));
     }
+
+    /// Reports reseed failure and keeps generating unique IDs
+    #[cfg(feature = "default_rng")]
+    #[test]
+    fn reports_reseed_failure_and_keeps_generating_unique_ids() {
+        use crate::Scru128Generator;
+        use std::collections::HashSet;
+
+        // mock an unavailable OsRng at reseeding points
+        let rng = DefaultRng::with_reseed_source(|_| {
+            Err(rand::Error::new(std::io::Error::other("mock failure")))
+        });
+        let mut g = Scru128Generator::with_rng(rng);
+        assert!(!g.reseed_failed());
+
+        // consume the first 64 kiB, which do not require reseeding
+        let mut s = HashSet::new();
+        for _ in 0..1024 * 64 / 4 {
+            s.insert(g.rng.next_u32());
+        }
+        assert!(!g.reseed_failed());
+
+        let ts = 0x0123_4567_89abu64;
+        let mut prev = g.generate_or_abort_core(ts, 10_000).unwrap();
+        assert!(g.reseed_failed());
+        for i in 0..100_000 {
+            let curr = g.generate_or_abort_core(ts + i / 1_000, 10_000).unwrap();
+            assert!(prev < curr);
+            prev = curr;
+        }
+        assert!(g.clone().reseed_failed());
+
+        // reseeding from the fallback stream yields numbers different from those drawn before,
+        // including by clones that reseed upon their first use
+        let mut clone = g.rng.clone();
+        let n_before = s.len();
+        for _ in 0..1024 {
+            s.insert(g.rng.next_u32());
+            s.insert(clone.next_u32());
+        }
+        assert!(s.len() > n_before + 2_000);
+
+        let mut g = Scru128Generator::with_rng(DefaultRng::default());
+        for _ in 0..1024 * 64 / 4 + 1 {
+            g.rng.next_u32();
+        }
+        assert!(!g.reseed_failed());
+        assert!(!g.clone().reseed_failed());
+    }
 }
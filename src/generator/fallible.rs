@@ -0,0 +1,200 @@
+use super::{Decision, Scru128Generator, TimeSource};
+use crate::Scru128Id;
+use core::fmt;
+
+/// A trait that defines a fallible random number generator interface for [`Scru128Generator`],
+/// which is suitable for random number sources that may fail at run time (e.g., a hardware
+/// security module).
+///
+/// Use [`Scru128Generator::try_generate()`] to generate IDs with a generator built on this trait.
+///
+/// [`Scru128Generator`]: super::Scru128Generator
+/// [`Scru128Generator::try_generate()`]: super::Scru128Generator::try_generate
+pub trait TryRandSource {
+    /// Returns the next random `u32` or an error if the random number source fails.
+    fn try_next_u32(&mut self) -> Result<u32, RngError>;
+}
+
+/// An error reported by a [`TryRandSource`] implementation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RngError {
+    message: &'static str,
+}
+
+impl RngError {
+    /// Creates an error object with a message describing the failure.
+    pub const fn new(message: &'static str) -> Self {
+        Self { message }
+    }
+
+    /// Returns the message describing the failure.
+    pub const fn message(&self) -> &'static str {
+        self.message
+    }
+}
+
+impl fmt::Display for RngError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "random number generator failed: {}", self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RngError {}
+
+impl<R: TryRandSource, T: TimeSource> Scru128Generator<R, T> {
+    /// Generates a new SCRU128 ID object from the current `timestamp`, or returns an error if
+    /// the random number source fails.
+    ///
+    /// This method is the fallible counterpart of
+    /// [`generate()`](Scru128Generator::generate) and resets the generator upon significant
+    /// timestamp rollback in the same manner. When the random number source fails, the
+    /// generator restores the internal states before the call, so the generator remains
+    /// usable and keeps producing monotonically increasing IDs once the source recovers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")]
+    /// # {
+    /// use scru128::generator::{RngError, TryRandSource};
+    /// use scru128::Scru128Generator;
+    ///
+    /// struct Hsm(u32);
+    ///
+    /// impl TryRandSource for Hsm {
+    ///     fn try_next_u32(&mut self) -> Result<u32, RngError> {
+    ///         // communicate with a hardware security module
+    ///         self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+    ///         Ok(self.0)
+    ///     }
+    /// }
+    ///
+    /// let mut g = Scru128Generator::with_rng(Hsm(42));
+    /// let x = g.try_generate()?;
+    /// let y = g.try_generate()?;
+    /// assert!(x < y);
+    /// # }
+    /// # Ok::<(), scru128::generator::RngError>(())
+    /// ```
+    pub fn try_generate(&mut self) -> Result<Scru128Id, RngError> {
+        let timestamp = self.time_source.unix_ts_ms();
+        let rollback_allowance = self.rollback_allowance;
+        let saved_state = self.save_state();
+
+        let mut next_u32 = |rng: &mut R| rng.try_next_u32();
+        let result = match self.generate_core_with(timestamp, rollback_allowance, &mut next_u32) {
+            Ok(None) => {
+                // reset state and resume
+                self.timestamp = 0;
+                self.ts_counter_hi = 0;
+                self.stats.resets += 1;
+                self.generate_core_with(timestamp, rollback_allowance, &mut next_u32)
+                    .map(|value| {
+                        self.decision_log.replace_last(Decision::Reset);
+                        self.last_clock_regressed = true;
+                        value.unwrap()
+                    })
+            }
+            result => result.map(Option::unwrap),
+        };
+
+        match result {
+            Ok(value) => {
+                self.record_generated();
+                Ok(value)
+            }
+            Err(err) => {
+                self.restore_state(saved_state);
+                Err(err)
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{RngError, TryRandSource};
+    use crate::Scru128Generator;
+
+    /// Fails on the `fail_at`-th call (counted from one) and succeeds otherwise.
+    struct MockRng {
+        n_calls: u32,
+        fail_at: u32,
+    }
+
+    impl TryRandSource for MockRng {
+        fn try_next_u32(&mut self) -> Result<u32, RngError> {
+            self.n_calls += 1;
+            if self.n_calls == self.fail_at {
+                Err(RngError::new("mock failure"))
+            } else {
+                Ok(self.n_calls.wrapping_mul(0x9e37_79b9))
+            }
+        }
+    }
+
+    /// Propagates rng error and recovers without breaking monotonicity
+    #[test]
+    fn propagates_rng_error_and_recovers_without_breaking_monotonicity() {
+        // the first call draws counter_lo, counter_hi, and entropy; the following calls draw
+        // entropy only unless timestamp or counter_hi is renewed
+        for fail_at in [1, 2, 3, 5] {
+            let mut g = Scru128Generator::with_rng(MockRng {
+                n_calls: 0,
+                fail_at,
+            });
+
+            let mut prev = None;
+            let mut n_errors = 0;
+            for _ in 0..8 {
+                match g.try_generate() {
+                    Ok(curr) => {
+                        assert!(prev < Some(curr));
+                        prev = Some(curr);
+                    }
+                    Err(err) => {
+                        assert_eq!(err, RngError::new("mock failure"));
+                        assert_eq!(
+                            err.to_string(),
+                            "random number generator failed: mock failure"
+                        );
+                        n_errors += 1;
+                    }
+                }
+            }
+            assert_eq!(n_errors, 1);
+            assert_eq!(g.stats().generated, 7);
+        }
+    }
+
+    /// Leaves clock regression tracking untouched upon rng error
+    #[test]
+    fn leaves_clock_regression_tracking_untouched_upon_rng_error() {
+        use crate::generator::FnTimeSource;
+        use std::cell::Cell;
+
+        let ts = 0x0123_4567_89abu64;
+        let clock = Cell::new(ts);
+        let mut g = Scru128Generator::with_rand_and_time_sources(
+            MockRng {
+                n_calls: 0,
+                fail_at: 4,
+            },
+            FnTimeSource(|| clock.get()),
+        );
+
+        // the first call draws counter_lo, counter_hi, and entropy
+        let x = g.try_generate().unwrap();
+        assert!(!g.last_clock_regressed());
+
+        clock.set(ts - 1);
+        assert!(g.try_generate().is_err());
+        assert!(!g.last_clock_regressed());
+
+        // regression is still detected against the timestamp passed before the failed call
+        let y = g.try_generate().unwrap();
+        assert!(g.last_clock_regressed());
+        assert!(x < y);
+    }
+}
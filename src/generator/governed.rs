@@ -0,0 +1,133 @@
+#![cfg(feature = "governor")]
+#![cfg_attr(docsrs, doc(cfg(feature = "governor")))]
+
+use super::{DefaultRng, Scru128Generator, Scru128Id, Scru128Rng};
+use governor::DefaultDirectRateLimiter;
+use std::sync::{Arc, Mutex};
+
+/// A wrapper of [`Scru128Generator`] that gates ID generation through a rate limiter of the
+/// `governor` crate.
+///
+/// [`generate()`](Self::generate) is an `async` method that waits until the rate limiter grants a
+/// permit and then generates an ID, so it must be awaited in an asynchronous runtime (e.g.,
+/// Tokio). The rate limiter is held through [`Arc`] and can be shared with other generators or
+/// with other parts of the application to enforce a single quota on all of them.
+///
+/// The inner generator is guarded by a mutex held only while generating an ID, not while waiting
+/// for a permit, so this type can be shared among tasks by reference or through [`Arc`]. The IDs
+/// are monotonically ordered in the order in which the tasks acquire the mutex.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "default_rng")]
+/// # {
+/// use governor::{Quota, RateLimiter};
+/// use scru128::generator::GovernedGenerator;
+/// use scru128::Scru128Generator;
+/// use std::num::NonZeroU32;
+///
+/// let quota = Quota::per_second(NonZeroU32::new(100).unwrap());
+/// let g = GovernedGenerator::new(Scru128Generator::new(), RateLimiter::direct(quota));
+///
+/// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+/// let x = g.generate().await;
+/// let y = g.generate().await;
+/// assert!(x < y);
+/// # });
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct GovernedGenerator<R = DefaultRng> {
+    inner: Mutex<Scru128Generator<R>>,
+    limiter: Arc<DefaultDirectRateLimiter>,
+}
+
+impl<R: Scru128Rng> GovernedGenerator<R> {
+    /// Creates a generator object that wraps `generator` and gates it through `limiter`, which is
+    /// either a rate limiter owned exclusively or an [`Arc`] shared with others.
+    pub fn new(
+        generator: Scru128Generator<R>,
+        limiter: impl Into<Arc<DefaultDirectRateLimiter>>,
+    ) -> Self {
+        Self {
+            inner: Mutex::new(generator),
+            limiter: limiter.into(),
+        }
+    }
+
+    /// Waits until the rate limiter grants a permit and then generates a new SCRU128 ID object.
+    ///
+    /// Like [`Scru128Generator::generate()`], this method resets the generator upon significant
+    /// timestamp rollback.
+    pub async fn generate(&self) -> Scru128Id {
+        self.limiter.until_ready().await;
+        self.inner
+            .lock()
+            .expect("scru128: could not lock generator")
+            .generate()
+    }
+
+    /// Returns a reference to the rate limiter.
+    pub fn limiter(&self) -> &Arc<DefaultDirectRateLimiter> {
+        &self.limiter
+    }
+
+    /// Consumes the wrapper and returns the inner generator.
+    pub fn into_inner(self) -> Scru128Generator<R> {
+        self.inner
+            .into_inner()
+            .expect("scru128: could not lock generator")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DefaultRng, GovernedGenerator, Scru128Generator};
+    use governor::{Quota, RateLimiter};
+    use std::{sync::Arc, time::Duration, time::Instant};
+
+    /// Generates IDs at configured rate
+    #[tokio::test]
+    async fn generates_ids_at_configured_rate() {
+        let quota = Quota::with_period(Duration::from_millis(20)).unwrap();
+        let g = GovernedGenerator::new(
+            Scru128Generator::<DefaultRng>::default(),
+            RateLimiter::direct(quota),
+        );
+
+        // first permit is granted immediately and each following one in 20 ms
+        let start = Instant::now();
+        let mut prev = g.generate().await;
+        for _ in 0..10 {
+            let curr = g.generate().await;
+            assert!(prev < curr);
+            prev = curr;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(190));
+        assert_eq!(g.into_inner().stats().generated, 11);
+    }
+
+    /// Shares rate limiter among generators
+    #[tokio::test]
+    async fn shares_rate_limiter_among_generators() {
+        let quota = Quota::with_period(Duration::from_millis(20)).unwrap();
+        let limiter = Arc::new(RateLimiter::direct(quota));
+        let g1 = GovernedGenerator::new(
+            Scru128Generator::<DefaultRng>::default(),
+            Arc::clone(&limiter),
+        );
+        let g2 = GovernedGenerator::new(
+            Scru128Generator::<DefaultRng>::default(),
+            Arc::clone(&limiter),
+        );
+        assert!(Arc::ptr_eq(g1.limiter(), g2.limiter()));
+
+        let start = Instant::now();
+        for _ in 0..5 {
+            g1.generate().await;
+            g2.generate().await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(170));
+    }
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "std")]
+#![cfg_attr(docsrs, doc(cfg(feature = "std")))]
+
+use super::{unix_ts_ms, DefaultRng, Scru128Generator, Scru128Id, Scru128Rng};
+
+/// A wrapper of [`Scru128Generator`] that reads the system clock only on the first call, when the
+/// `counter_lo` field is exhausted, and once every 1,000 calls.
+///
+/// This generator is intended for ultra-high-throughput scenarios where the cost of reading the
+/// system clock for every ID is not negligible. Between clock reads, it relies on the counters to
+/// produce monotonically increasing IDs under the `timestamp` obtained at the last clock read.
+///
+/// Consequently, the `timestamp` of generated IDs may lag behind real time. The lag is bounded
+/// because the generator reads the clock again after 1,000 calls at the latest: it stays well
+/// within a millisecond while the generator is producing IDs at a very high rate, but it may
+/// extend to the interval of 1,000 calls if the generator is called infrequently. Use
+/// [`Scru128Generator`] if the `timestamp` must reflect the generation time.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "default_rng")]
+/// # {
+/// use scru128::generator::LazyClockGenerator;
+///
+/// let mut g = LazyClockGenerator::new();
+/// let x = g.generate();
+/// let y = g.generate();
+/// assert!(x < y);
+/// # }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct LazyClockGenerator<R = DefaultRng> {
+    inner: Scru128Generator<R>,
+
+    /// The `timestamp` obtained at the last clock read.
+    ts_clock: u64,
+
+    /// The number of calls since the last clock read.
+    n_calls_since_read: u32,
+}
+
+/// The maximum number of calls that `LazyClockGenerator` serves with the `timestamp` obtained at
+/// a single clock read.
+const MAX_CALLS_PER_CLOCK_READ: u32 = 1_000;
+
+impl<R: Scru128Rng> LazyClockGenerator<R> {
+    /// Creates a generator object with a specified random number generator. The specified random
+    /// number generator should be cryptographically strong and securely seeded.
+    pub const fn with_rng(rng: R) -> Self {
+        Self {
+            inner: Scru128Generator::with_rng(rng),
+            ts_clock: 0,
+            n_calls_since_read: 0,
+        }
+    }
+
+    /// Generates a new SCRU128 ID object, reading the system clock only if this is the first call,
+    /// `counter_lo` is exhausted, or the last clock read has served 1,000 calls.
+    ///
+    /// Like [`Scru128Generator::generate()`], this method resets the generator upon significant
+    /// timestamp rollback.
+    pub fn generate(&mut self) -> Scru128Id {
+        if self.ts_clock == 0
+            || self.n_calls_since_read >= MAX_CALLS_PER_CLOCK_READ
+            || self.inner.counter_lo == self.inner.max_counters().1
+        {
+            self.ts_clock = unix_ts_ms();
+            self.n_calls_since_read = 0;
+        }
+        self.n_calls_since_read += 1;
+        self.inner
+            .generate_or_reset_core(self.ts_clock, self.inner.rollback_allowance)
+    }
+}
+
+#[cfg(feature = "default_rng")]
+#[cfg_attr(docsrs, doc(cfg(feature = "default_rng")))]
+impl LazyClockGenerator {
+    /// Creates a generator object with the default random number generator.
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DefaultRng, LazyClockGenerator, MAX_CALLS_PER_CLOCK_READ};
+    use crate::MAX_COUNTER_LO;
+
+    /// Generates unique increasing IDs with lazily read timestamp
+    #[test]
+    fn generates_unique_increasing_ids_with_lazily_read_timestamp() {
+        let mut g = LazyClockGenerator::<DefaultRng>::default();
+        let mut prev = g.generate();
+        let ts_first_read = g.ts_clock;
+        for _ in 0..100_000 {
+            let curr = g.generate();
+            assert!(prev < curr);
+            prev = curr;
+        }
+
+        // reads clock again when counter_lo is exhausted
+        g.inner.counter_lo = MAX_COUNTER_LO;
+        g.n_calls_since_read = 1;
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let curr = g.generate();
+        assert!(prev < curr);
+        assert!(g.ts_clock > ts_first_read);
+    }
+
+    /// Bounds staleness of timestamp by number of calls
+    #[test]
+    fn bounds_staleness_of_timestamp_by_number_of_calls() {
+        let mut g = LazyClockGenerator::<DefaultRng>::default();
+        g.generate();
+
+        // pretend the last clock read is five seconds old
+        let ts_stale = g.ts_clock - 5_000;
+        g.ts_clock = ts_stale;
+        g.n_calls_since_read = 1;
+        g.inner.counter_lo = 0;
+
+        let mut prev = g.generate();
+        for _ in 2..MAX_CALLS_PER_CLOCK_READ {
+            let curr = g.generate();
+            assert!(prev < curr);
+            assert_eq!(g.ts_clock, ts_stale);
+            prev = curr;
+        }
+
+        let curr = g.generate();
+        assert!(prev < curr);
+        assert!(g.ts_clock >= ts_stale + 5_000);
+        assert_eq!(g.n_calls_since_read, 1);
+    }
+}
@@ -0,0 +1,179 @@
+use super::{Scru128Generator, StdSystemTime, MAX_TIMESTAMP};
+
+impl<R, T> Scru128Generator<R, T> {
+    /// Returns the monotonic state of the generator packed into a single 128-bit integer, which
+    /// helps build a custom lock-free coordinator that stores the state in a 128-bit atomic
+    /// integer (e.g., `AtomicU128` of the `portable-atomic` crate, as Rust does not provide a
+    /// stable one).
+    ///
+    /// The packed value consists of the following fields, from the most significant bit:
+    ///
+    /// | Bits     | Width | Field                                                       |
+    /// | -------- | ----- | ----------------------------------------------------------- |
+    /// | 127 - 80 | 48    | `timestamp` of the last ID                                  |
+    /// | 79 - 56  | 24    | `counter_hi` of the last ID                                 |
+    /// | 55 - 32  | 24    | `counter_lo` of the last ID                                 |
+    /// | 31 - 0   | 32    | milliseconds from the last renewal of `counter_hi`          |
+    ///
+    /// Therefore, the upper 96 bits equal the ID returned by [`last_id()`](Self::last_id), and the
+    /// packed states of a generator increase as it generates IDs. The last field holds the
+    /// difference between `timestamp` and the `timestamp` at which `counter_hi` was last renewed,
+    /// which is always less than 1000 after an ID is generated because the generator renews
+    /// `counter_hi` every second; a greater difference, which only matters in that it triggers
+    /// the renewal at the next generation, is saturated at [`u32::MAX`].
+    ///
+    /// The packed state covers the fields that determine the ordering of the following IDs only.
+    /// The configuration, such as the counter bits and the rollback allowance, as well as the
+    /// statistics, the random number generator, and the time source are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::new();
+    /// assert_eq!(g.to_packed_state(), 0);
+    ///
+    /// let x = g.generate_or_reset_core(0x0123_4567_89ab, 10_000);
+    /// let packed = g.to_packed_state();
+    /// assert_eq!(packed >> 32, x.to_u128() >> 32);
+    /// assert_eq!(packed as u32, 0);
+    /// # }
+    /// ```
+    pub fn to_packed_state(&self) -> u128 {
+        let elapsed = self.timestamp.saturating_sub(self.ts_counter_hi);
+        (self.timestamp as u128) << 80
+            | (self.counter_hi as u128) << 56
+            | (self.counter_lo as u128) << 32
+            | elapsed.min(u32::MAX as u64) as u128
+    }
+}
+
+impl<R> Scru128Generator<R> {
+    /// Creates a generator object with a specified random number generator and the monotonic
+    /// state unpacked from a value returned by [`to_packed_state()`](Self::to_packed_state).
+    ///
+    /// The returned generator continues the sequence of the generator that produced the packed
+    /// state: the IDs it generates are greater than those generated before the state was packed,
+    /// as long as the clock does not go backwards significantly. The other properties are set to
+    /// the defaults as [`with_rng()`](Self::with_rng) does; configure them again as needed. The
+    /// specified random number generator should be cryptographically strong and securely seeded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `packed` does not represent a valid state: a nonzero counter field or elapsed
+    /// milliseconds with the zero `timestamp`, or elapsed milliseconds greater than the
+    /// `timestamp`, which no generator produces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::generator::DefaultRng;
+    /// use scru128::Scru128Generator;
+    ///
+    /// let ts = 0x0123_4567_89ab;
+    /// let mut g = Scru128Generator::new();
+    /// let x = g.generate_or_reset_core(ts, 10_000);
+    ///
+    /// let mut h = Scru128Generator::from_packed_state(g.to_packed_state(), DefaultRng::default());
+    /// let y = h.generate_or_reset_core(ts, 10_000);
+    /// assert!(x < y);
+    /// assert_eq!(y.counter_hi(), x.counter_hi());
+    /// assert_eq!(y.counter_lo(), x.counter_lo() + 1);
+    /// # }
+    /// ```
+    pub fn from_packed_state(packed: u128, rng: R) -> Self {
+        let timestamp = (packed >> 80) as u64;
+        let elapsed = packed as u32 as u64;
+        if timestamp == 0 && packed != 0 {
+            panic!("invalid packed state: nonzero fields with zero `timestamp`");
+        } else if elapsed > timestamp {
+            panic!("invalid packed state: elapsed milliseconds out of range");
+        }
+        debug_assert!(timestamp <= MAX_TIMESTAMP);
+
+        let mut g = Self::with_rand_and_time_sources(rng, StdSystemTime::new());
+        g.timestamp = timestamp;
+        g.counter_hi = (packed >> 56) as u32 & 0xff_ffff;
+        g.counter_lo = (packed >> 32) as u32 & 0xff_ffff;
+        g.ts_counter_hi = timestamp - elapsed;
+        g
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::{DefaultRng, Scru128Generator};
+
+    /// Round-trips monotonic state through packed integer
+    #[test]
+    fn round_trips_monotonic_state_through_packed_integer() {
+        let g = Scru128Generator::from_packed_state(0, DefaultRng::default());
+        assert_eq!(g.to_packed_state(), 0);
+        assert_eq!(g.last_id(), None);
+
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::with_rng(DefaultRng::default());
+        let mut prev_packed = g.to_packed_state();
+        for i in 0..10_000 {
+            let x = g.generate_or_reset_core(ts + i / 2, 10_000);
+            let packed = g.to_packed_state();
+            assert!(prev_packed < packed);
+            assert_eq!(packed >> 32, x.to_u128() >> 32);
+            assert_eq!(packed as u32 as u64, g.timestamp - g.ts_counter_hi);
+            assert!((packed as u32) < 1_000);
+
+            let h = Scru128Generator::from_packed_state(packed, DefaultRng::default());
+            assert_eq!(h.to_packed_state(), packed);
+            assert_eq!(
+                (h.timestamp, h.counter_hi, h.counter_lo, h.ts_counter_hi),
+                (g.timestamp, g.counter_hi, g.counter_lo, g.ts_counter_hi)
+            );
+            prev_packed = packed;
+        }
+
+        // saturate elapsed milliseconds
+        g.ts_counter_hi = 1;
+        let packed = g.to_packed_state();
+        assert_eq!(packed as u32, u32::MAX);
+        let h = Scru128Generator::from_packed_state(packed, DefaultRng::default());
+        assert!(h.timestamp - h.ts_counter_hi >= 1_000);
+
+        // reject invalid states
+        #[cfg(feature = "std")]
+        for packed in [1, 1 << 32, 1 << 79, (1 << 80) | 2] {
+            let result = std::panic::catch_unwind(|| {
+                Scru128Generator::from_packed_state(packed, DefaultRng::default())
+            });
+            assert!(result.is_err());
+        }
+    }
+
+    /// Continues generation from unpacked state
+    #[test]
+    fn continues_generation_from_unpacked_state() {
+        let ts = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::with_rng(DefaultRng::default());
+        let mut prev = g.generate_or_reset_core(ts, 10_000);
+        for i in 0..10_000 {
+            // hand over the state to a new generator at every step
+            g = Scru128Generator::from_packed_state(g.to_packed_state(), DefaultRng::default());
+            let curr = g.generate_or_reset_core(ts + i / 8, 10_000);
+            assert!(prev < curr);
+            if curr.timestamp() == prev.timestamp() {
+                assert_eq!(curr.counter_lo(), prev.counter_lo() + 1);
+            }
+            if curr.timestamp() - ts < 1_000 {
+                assert_eq!(curr.counter_hi(), prev.counter_hi());
+            }
+            prev = curr;
+        }
+
+        // counter_hi was renewed one second after first ID despite handovers
+        assert_eq!(g.ts_counter_hi, ts + 1_000);
+    }
+}
@@ -0,0 +1,188 @@
+#![cfg(feature = "std")]
+#![cfg_attr(docsrs, doc(cfg(feature = "std")))]
+
+use super::{unix_ts_ms, DefaultRng, Scru128Generator, Scru128Id, Scru128Rng};
+
+/// The number of units that make up one token, which lets the bucket refill by fractions of a
+/// token every millisecond without floating-point arithmetic.
+const UNITS_PER_TOKEN: u64 = 1_000;
+
+/// A wrapper of [`Scru128Generator`] that throttles ID issuance with a token bucket.
+///
+/// The bucket holds up to `capacity` tokens and starts full. Each ID generated consumes one
+/// token, and the bucket is refilled at `refill_per_sec` tokens per second based on the system
+/// clock. [`try_generate()`](Self::try_generate) returns `None` without generating an ID while
+/// the bucket is empty, which helps tie ID issuance to a rate limit, e.g., of API requests that
+/// each require a new ID.
+///
+/// This wrapper is intended for issuance throttling only. The IDs it returns are generated by the
+/// inner generator just as [`Scru128Generator::generate()`] does and are monotonically ordered
+/// only within the wrapper; rate limiting neither strengthens nor weakens the ordering
+/// guarantees.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "default_rng")]
+/// # {
+/// use scru128::generator::RateLimitedGenerator;
+///
+/// let mut g = RateLimitedGenerator::new(2, 1);
+/// let x = g.try_generate().unwrap();
+/// let y = g.try_generate().unwrap();
+/// assert!(x < y);
+/// assert_eq!(g.try_generate(), None);
+/// # }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct RateLimitedGenerator<R = DefaultRng> {
+    inner: Scru128Generator<R>,
+
+    /// The maximum number of units the bucket holds.
+    capacity: u64,
+
+    /// The number of units added to the bucket every millisecond.
+    refill_per_ms: u64,
+
+    /// The number of units currently available.
+    units: u64,
+
+    /// The `timestamp` at which the bucket was last refilled, or zero if never.
+    ts_refill: u64,
+}
+
+impl<R: Scru128Rng> RateLimitedGenerator<R> {
+    /// Creates a generator object with a specified random number generator and a full token
+    /// bucket that holds up to `capacity` tokens and is refilled at `refill_per_sec` tokens per
+    /// second. The specified random number generator should be cryptographically strong and
+    /// securely seeded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub const fn with_rng(rng: R, capacity: u64, refill_per_sec: u64) -> Self {
+        if capacity == 0 {
+            panic!("`capacity` must be a positive integer");
+        }
+        let capacity = capacity.saturating_mul(UNITS_PER_TOKEN);
+        Self {
+            inner: Scru128Generator::with_rng(rng),
+            capacity,
+            // tokens per second equal units per millisecond
+            refill_per_ms: refill_per_sec,
+            units: capacity,
+            ts_refill: 0,
+        }
+    }
+
+    /// Generates a new SCRU128 ID object if a token is available in the bucket, or returns `None`
+    /// if the bucket is empty.
+    ///
+    /// Like [`Scru128Generator::generate()`], this method resets the generator upon significant
+    /// timestamp rollback.
+    pub fn try_generate(&mut self) -> Option<Scru128Id> {
+        self.try_generate_core(unix_ts_ms())
+    }
+
+    /// Generates a new SCRU128 ID object from the `timestamp` passed if a token is available in
+    /// the bucket, or returns `None` if the bucket is empty. The bucket is refilled according to
+    /// the time elapsed since the `timestamp` passed at the previous call, and a `timestamp`
+    /// going backwards adds no tokens.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp` is not a 48-bit positive integer.
+    pub fn try_generate_core(&mut self, timestamp: u64) -> Option<Scru128Id> {
+        self.refill(timestamp);
+        if self.units < UNITS_PER_TOKEN {
+            return None;
+        }
+        let value = self
+            .inner
+            .generate_or_reset_core(timestamp, self.inner.rollback_allowance);
+        self.units -= UNITS_PER_TOKEN;
+        Some(value)
+    }
+
+    /// Returns the number of whole tokens currently available in the bucket, without refilling
+    /// it for the time elapsed since the last call.
+    pub const fn available_tokens(&self) -> u64 {
+        self.units / UNITS_PER_TOKEN
+    }
+
+    /// Adds the units accumulated since the last refill up to the capacity.
+    fn refill(&mut self, timestamp: u64) {
+        if self.ts_refill != 0 && timestamp > self.ts_refill {
+            let added = (timestamp - self.ts_refill).saturating_mul(self.refill_per_ms);
+            self.units = self.units.saturating_add(added).min(self.capacity);
+        }
+        if timestamp > self.ts_refill {
+            self.ts_refill = timestamp;
+        }
+    }
+}
+
+#[cfg(feature = "default_rng")]
+#[cfg_attr(docsrs, doc(cfg(feature = "default_rng")))]
+impl RateLimitedGenerator {
+    /// Creates a generator object with the default random number generator and a full token
+    /// bucket that holds up to `capacity` tokens and is refilled at `refill_per_sec` tokens per
+    /// second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: u64, refill_per_sec: u64) -> Self {
+        Self::with_rng(DefaultRng::default(), capacity, refill_per_sec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DefaultRng, RateLimitedGenerator};
+
+    /// Returns None once bucket is exhausted until refilled
+    #[test]
+    fn returns_none_once_bucket_is_exhausted_until_refilled() {
+        let ts = 0x0123_4567_89abu64;
+        let mut g = RateLimitedGenerator::with_rng(DefaultRng::default(), 5, 2);
+        assert_eq!(g.available_tokens(), 5);
+
+        let mut prev = g.try_generate_core(ts).unwrap();
+        for _ in 1..5 {
+            let curr = g.try_generate_core(ts).unwrap();
+            assert!(prev < curr);
+            prev = curr;
+        }
+        assert_eq!(g.available_tokens(), 0);
+        assert_eq!(g.try_generate_core(ts), None);
+        assert_eq!(g.inner.stats().generated, 5);
+
+        // 2 tokens per second refill one token in 500 ms
+        assert_eq!(g.try_generate_core(ts + 499), None);
+        assert_eq!(g.try_generate_core(ts - 10_000), None);
+        let curr = g.try_generate_core(ts + 500).unwrap();
+        assert!(prev < curr);
+        assert_eq!(g.try_generate_core(ts + 500), None);
+
+        // refill up to capacity
+        for _ in 0..5 {
+            assert!(g.try_generate_core(ts + 60_000).is_some());
+        }
+        assert_eq!(g.try_generate_core(ts + 60_000), None);
+        assert_eq!(g.inner.stats().generated, 11);
+    }
+
+    /// Throttles issuance with system clock
+    #[test]
+    fn throttles_issuance_with_system_clock() {
+        let mut g = RateLimitedGenerator::with_rng(DefaultRng::default(), 3, 100);
+        for _ in 0..3 {
+            assert!(g.try_generate().is_some());
+        }
+        assert_eq!(g.try_generate(), None);
+
+        std::thread::sleep(std::time::Duration::from_millis(30));
+        assert!(g.try_generate().is_some());
+    }
+}
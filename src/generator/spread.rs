@@ -0,0 +1,85 @@
+#![cfg(feature = "default_rng")]
+#![cfg_attr(docsrs, doc(cfg(feature = "default_rng")))]
+
+use super::Scru128Generator;
+use crate::{Scru128Id, MAX_TIMESTAMP};
+
+/// Generates `n` monotonically increasing IDs whose `timestamp` values are spread across the
+/// `[start_ms, end_ms]` window, which is useful to seed a test database with time-ordered data.
+///
+/// The `timestamp` values are evenly distributed: the `i`-th ID (counted from zero) is based on
+/// `start_ms + (end_ms - start_ms) * i / (n - 1)` rounded down, so the first ID has `start_ms` and
+/// the last has `end_ms` if `n` is greater than one. Where the window is narrower than `n`
+/// milliseconds, consecutive IDs share a `timestamp` and are ordered by the counters. The
+/// `timestamp` values are deterministic, while the other fields are filled by a new generator with
+/// the default random number generator as usual.
+///
+/// The returned IDs are sorted in ascending order. The `timestamp` of an ID may exceed `end_ms`
+/// only if the counters overflow, which requires an enormous number of IDs (typically more than
+/// 2^24) to share a single millisecond.
+///
+/// # Panics
+///
+/// Panics if `start_ms` is zero, `start_ms` is greater than `end_ms`, or `end_ms` is greater than
+/// the maximum 48-bit value.
+///
+/// # Examples
+///
+/// ```rust
+/// use scru128::generator::generate_spread;
+///
+/// let ids = generate_spread(1_700_000_000_000, 1_700_086_400_000, 1_000);
+/// assert_eq!(ids.len(), 1_000);
+/// assert_eq!(ids[0].timestamp(), 1_700_000_000_000);
+/// assert_eq!(ids[999].timestamp(), 1_700_086_400_000);
+/// assert!(ids.windows(2).all(|w| w[0] < w[1]));
+/// ```
+pub fn generate_spread(start_ms: u64, end_ms: u64, n: usize) -> Vec<Scru128Id> {
+    if start_ms == 0 || start_ms > end_ms || end_ms > MAX_TIMESTAMP {
+        panic!("`start_ms` and `end_ms` must form a valid range of 48-bit positive integers");
+    }
+
+    let mut g = Scru128Generator::new();
+    let span = (end_ms - start_ms) as u128;
+    let steps = n.saturating_sub(1).max(1) as u128;
+    (0..n)
+        .map(|i| {
+            let timestamp = start_ms + (span * i as u128 / steps) as u64;
+            // allow any rollback to keep increasing order even after counter overflow
+            g.generate_or_abort_core(timestamp, MAX_TIMESTAMP).unwrap()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate_spread;
+
+    /// Spreads sorted IDs across given window
+    #[test]
+    fn spreads_sorted_ids_across_given_window() {
+        let cases = [
+            (1_700_000_000_000, 1_700_086_400_000, 10_000),
+            (1_700_000_000_000, 1_700_000_000_010, 10_000),
+            (1_700_000_000_000, 1_700_000_000_000, 100),
+            (1, 0xffff_ffff_ffff, 7),
+            (1_700_000_000_000, 1_700_086_400_000, 1),
+            (1_700_000_000_000, 1_700_086_400_000, 0),
+        ];
+
+        for (start_ms, end_ms, n) in cases {
+            let ids = generate_spread(start_ms, end_ms, n);
+            assert_eq!(ids.len(), n);
+            for i in 1..ids.len() {
+                assert!(ids[i - 1] < ids[i]);
+            }
+            for e in &ids {
+                assert!(start_ms <= e.timestamp() && e.timestamp() <= end_ms);
+            }
+            if n > 1 {
+                assert_eq!(ids[0].timestamp(), start_ms);
+                assert_eq!(ids[n - 1].timestamp(), end_ms);
+            }
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use super::Scru128Generator;
+
+/// A snapshot of the statistics collected by [`Scru128Generator`].
+///
+/// See [`Scru128Generator::stats()`] and [`Scru128Generator::set_stats_callback()`].
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub struct GeneratorStats {
+    /// The total number of IDs generated.
+    pub generated: u64,
+
+    /// The number of times the generator was reset upon significant timestamp rollback.
+    pub resets: u64,
+
+    /// The number of times the generator returned `None` upon significant timestamp rollback.
+    pub aborts: u64,
+
+    /// The number of times the counters overflowed and the generator incremented `timestamp` to
+    /// continue.
+    pub counter_overflows: u64,
+
+    /// The number of times the `entropy` field repeated that of the previous ID within the same
+    /// `timestamp`, counted only if enabled by
+    /// [`set_entropy_repeat_check()`](Scru128Generator::set_entropy_repeat_check).
+    pub entropy_repeats: u64,
+}
+
+impl GeneratorStats {
+    /// Creates an object with all the counts set to zero.
+    pub(super) const fn new() -> Self {
+        Self {
+            generated: 0,
+            resets: 0,
+            aborts: 0,
+            counter_overflows: 0,
+            entropy_repeats: 0,
+        }
+    }
+}
+
+impl<R, T> Scru128Generator<R, T> {
+    /// Returns a snapshot of the statistics collected by the generator.
+    pub const fn stats(&self) -> GeneratorStats {
+        self.stats
+    }
+
+    /// Records a successful generation and invokes the stats callback if due.
+    pub(super) fn record_generated(&mut self) {
+        self.stats.generated += 1;
+        #[cfg(feature = "std")]
+        if let Some(callback) = &self.stats_callback {
+            callback.invoke_if_due(&self.stats);
+        }
+    }
+
+    /// Records a repetition of the `entropy` field and invokes the stats callback immediately.
+    ///
+    /// This method is called before the ID in question is recorded as generated.
+    pub(super) fn record_entropy_repeat(&mut self) {
+        self.stats.entropy_repeats += 1;
+        #[cfg(feature = "std")]
+        if let Some(callback) = &self.stats_callback {
+            callback.invoke(&self.stats);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub(super) use with_std::StatsCallback;
+
+#[cfg(feature = "std")]
+mod with_std {
+    use super::{GeneratorStats, Scru128Generator};
+    use std::{fmt, sync};
+
+    type CallbackFn = dyn FnMut(&GeneratorStats) + Send;
+
+    /// A shared handle of the callback registered through
+    /// [`Scru128Generator::set_stats_callback()`].
+    #[derive(Clone)]
+    pub(in crate::generator) struct StatsCallback {
+        every: u64,
+        f: sync::Arc<sync::Mutex<CallbackFn>>,
+    }
+
+    impl StatsCallback {
+        // `u64::is_multiple_of()` requires Rust 1.87
+        #[allow(clippy::manual_is_multiple_of)]
+        pub(super) fn invoke_if_due(&self, stats: &GeneratorStats) {
+            if stats.generated % self.every == 0 {
+                self.invoke(stats);
+            }
+        }
+
+        pub(super) fn invoke(&self, stats: &GeneratorStats) {
+            let mut f = self
+                .f
+                .lock()
+                .expect("scru128: could not lock stats callback");
+            f(stats);
+        }
+    }
+
+    impl PartialEq for StatsCallback {
+        fn eq(&self, other: &Self) -> bool {
+            self.every == other.every && sync::Arc::ptr_eq(&self.f, &other.f)
+        }
+    }
+
+    impl Eq for StatsCallback {}
+
+    impl fmt::Debug for StatsCallback {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("StatsCallback")
+                .field("every", &self.every)
+                .finish_non_exhaustive()
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    impl<R, T> Scru128Generator<R, T> {
+        /// Registers a callback that receives a snapshot of the [`stats()`](Self::stats) each
+        /// time the total number of generated IDs reaches a multiple of `every`, replacing the
+        /// previously registered one if any.
+        ///
+        /// The callback is invoked synchronously from within the generator method that produced
+        /// the `every`-th ID, so it should return quickly and must not call the same generator.
+        /// The statistics are provided on a best-effort basis for observability purposes and are
+        /// not part of the ordering guarantees of the generator. A cloned generator shares the
+        /// callback with the original. The callback is also invoked out of the interval when the
+        /// check enabled by [`set_entropy_repeat_check()`](Self::set_entropy_repeat_check) detects
+        /// a repeated `entropy` field.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `every` is zero.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # #[cfg(feature = "default_rng")]
+        /// # {
+        /// use scru128::Scru128Generator;
+        ///
+        /// let mut g = Scru128Generator::new();
+        /// g.set_stats_callback(1_000, |stats| {
+        ///     println!("{} IDs generated", stats.generated);
+        /// });
+        /// for _ in 0..10_000 {
+        ///     g.generate();
+        /// }
+        /// # }
+        /// ```
+        pub fn set_stats_callback(
+            &mut self,
+            every: u64,
+            f: impl FnMut(&GeneratorStats) + Send + 'static,
+        ) {
+            if every == 0 {
+                panic!("`every` must be a positive integer");
+            }
+            self.stats_callback = Some(StatsCallback {
+                every,
+                f: sync::Arc::new(sync::Mutex::new(f)),
+            });
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{GeneratorStats, Scru128Generator};
+    use crate::generator::DefaultRng;
+    use std::sync::{Arc, Mutex};
+
+    /// Invokes stats callback every N IDs with increasing totals
+    #[test]
+    fn invokes_stats_callback_every_n_ids_with_increasing_totals() {
+        let snapshots = Arc::new(Mutex::new(Vec::<GeneratorStats>::new()));
+
+        let mut g = Scru128Generator::<DefaultRng>::default();
+        let cloned = Arc::clone(&snapshots);
+        g.set_stats_callback(10, move |stats| cloned.lock().unwrap().push(*stats));
+
+        let ts = 0x0123_4567_89ab;
+        for _ in 0..35 {
+            g.generate_or_reset_core(ts, 10_000);
+        }
+        assert!(g.generate_or_abort_core(ts - 10_001, 10_000).is_none());
+        for _ in 0..4 {
+            g.generate_or_abort_core(ts, 10_000).unwrap();
+        }
+        g.generate_or_reset_core(ts - 10_001, 10_000);
+
+        let snapshots = snapshots.lock().unwrap();
+        let totals: Vec<u64> = snapshots.iter().map(|e| e.generated).collect();
+        assert_eq!(totals, [10, 20, 30, 40]);
+        assert_eq!(snapshots[2].aborts, 0);
+        assert_eq!(snapshots[3].aborts, 1);
+        assert_eq!(snapshots[3].resets, 1);
+
+        let stats = g.stats();
+        assert_eq!(stats.generated, 40);
+        assert_eq!(stats.aborts, 1);
+        assert_eq!(stats.resets, 1);
+    }
+
+    /// Counts counter overflows in stats
+    #[test]
+    fn counts_counter_overflows_in_stats() {
+        let mut g = Scru128Generator::<DefaultRng>::default();
+        g.set_counter_bits(0);
+
+        let ts = 0x0123_4567_89ab;
+        for _ in 0..5 {
+            g.generate_or_reset_core(ts, 10_000);
+        }
+        assert_eq!(g.stats().generated, 5);
+        assert_eq!(g.stats().counter_overflows, 4);
+    }
+}
@@ -0,0 +1,248 @@
+/// The default time source used by [`Scru128Generator`], which reads the system clock
+/// ([`SystemTime`]) on every call.
+///
+/// This structure does exist without the `std` feature flag but is not able to be used as a time
+/// source.
+///
+/// [`Scru128Generator`]: super::Scru128Generator
+/// [`SystemTime`]: https://doc.rust-lang.org/std/time/struct.SystemTime.html
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub struct StdSystemTime {
+    _private: (),
+}
+
+impl StdSystemTime {
+    /// Creates an instance of the time source.
+    pub const fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+#[cfg(feature = "std")]
+impl super::TimeSource for StdSystemTime {
+    fn unix_ts_ms(&mut self) -> u64 {
+        super::unix_ts_ms()
+    }
+}
+
+/// A time source adapter that reads the current timestamp from a closure, which enables
+/// [`generate()`](super::Scru128Generator::generate) and other clock-reading methods under
+/// `no_std` environments.
+///
+/// The closure must return the current Unix timestamp in milliseconds (i.e., milliseconds elapsed
+/// since 1970-01-01 00:00:00 UTC). A monotonic counter that starts from an arbitrary point (e.g.,
+/// the system boot) must be offset by the Unix timestamp at that point; otherwise, the generated
+/// IDs will not represent the actual creation time.
+///
+/// # Examples
+///
+/// ```rust
+/// use scru128::generator::{FnTimeSource, Scru128Rng};
+/// use scru128::Scru128Generator;
+///
+/// # struct HardwareRng;
+/// # impl Scru128Rng for HardwareRng {
+/// #     fn next_u32(&mut self) -> u32 {
+/// #         4
+/// #     }
+/// # }
+/// # fn millis_since_boot() -> u64 {
+/// #     42
+/// # }
+/// const BOOT_TIME_UNIX_MS: u64 = 1_700_000_000_000;
+///
+/// let mut g = Scru128Generator::with_rand_and_time_sources(
+///     HardwareRng,
+///     FnTimeSource(|| BOOT_TIME_UNIX_MS + millis_since_boot()),
+/// );
+/// let x = g.generate();
+/// assert_eq!(x.timestamp(), 1_700_000_000_042);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct FnTimeSource<F>(pub F);
+
+impl<F: FnMut() -> u64> super::TimeSource for FnTimeSource<F> {
+    fn unix_ts_ms(&mut self) -> u64 {
+        (self.0)()
+    }
+}
+
+#[cfg(feature = "std")]
+pub use with_std::MonotonicTimeSource;
+
+#[cfg(feature = "std")]
+mod with_std {
+    use crate::generator::TimeSource;
+    use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+    /// A time source that derives the current timestamp from an anchor [`SystemTime`] and the
+    /// [`Instant`] elapsed since the anchor.
+    ///
+    /// This time source reads the system clock only once when it is created and then advances the
+    /// anchor timestamp by the monotonic clock. Therefore, the returned timestamp never decreases,
+    /// and it is immune to backward steps of the system clock caused by NTP or manual
+    /// adjustments, which would otherwise make [`Scru128Generator`](super::super::Scru128Generator)
+    /// reuse the previous timestamp or reset the generator.
+    ///
+    /// The flip side is that the returned timestamp does not follow any later correction of the
+    /// system clock, either backward or forward, and may drift away from the system clock over a
+    /// long period of time or across a system suspension, depending on the behavior of
+    /// [`Instant`] on the platform. Recreate the time source periodically if the drift matters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "default_rng")]
+    /// # {
+    /// use scru128::generator::{DefaultRng, MonotonicTimeSource};
+    /// use scru128::Scru128Generator;
+    ///
+    /// let mut g = Scru128Generator::with_rand_and_time_sources(
+    ///     DefaultRng::default(),
+    ///     MonotonicTimeSource::new(),
+    /// );
+    /// let x = g.generate();
+    /// let y = g.generate();
+    /// assert!(x < y);
+    /// # }
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[derive(Clone, Copy, Eq, PartialEq, Debug)]
+    pub struct MonotonicTimeSource {
+        anchor_ms: u64,
+        anchor_instant: Instant,
+    }
+
+    impl MonotonicTimeSource {
+        /// Creates a time source anchored at the current system clock.
+        pub fn new() -> Self {
+            Self::with_anchor(SystemTime::now(), Instant::now())
+        }
+
+        /// Creates a time source that regards `anchor_instant` as the moment when the system
+        /// clock indicated `anchor_time`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `anchor_time` is earlier than the Unix epoch.
+        pub fn with_anchor(anchor_time: SystemTime, anchor_instant: Instant) -> Self {
+            let anchor_ms = anchor_time
+                .duration_since(UNIX_EPOCH)
+                .expect("clock may have gone backwards")
+                .as_millis() as u64;
+            Self {
+                anchor_ms,
+                anchor_instant,
+            }
+        }
+
+        /// Returns the timestamp at `now`.
+        fn unix_ts_ms_at(&self, now: Instant) -> u64 {
+            let elapsed = now.saturating_duration_since(self.anchor_instant);
+            self.anchor_ms + elapsed.as_millis() as u64
+        }
+    }
+
+    impl Default for MonotonicTimeSource {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl TimeSource for MonotonicTimeSource {
+        fn unix_ts_ms(&mut self) -> u64 {
+            self.unix_ts_ms_at(Instant::now())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{MonotonicTimeSource, TimeSource};
+        use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+        /// Never returns smaller timestamp even if wall clock steps back
+        #[test]
+        fn never_returns_smaller_timestamp_even_if_wall_clock_steps_back() {
+            // injected clock: wall clock steps back by 30 seconds at t = 200 ms, while the
+            // monotonic clock keeps going
+            let start = Instant::now();
+            let wall_clock = |t: u64| {
+                let ms = 1_700_000_000_000 + t - if t >= 200 { 30_000 } else { 0 };
+                UNIX_EPOCH + Duration::from_millis(ms)
+            };
+
+            let src = MonotonicTimeSource::with_anchor(wall_clock(0), start);
+            let mut prev = 0;
+            for t in (0..1_000).step_by(10) {
+                let wall_ms = wall_clock(t)
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap()
+                    .as_millis();
+                let curr = src.unix_ts_ms_at(start + Duration::from_millis(t));
+                assert!(curr >= prev);
+                assert_eq!(curr, 1_700_000_000_000 + t);
+                if t >= 200 {
+                    assert!(curr as u128 > wall_ms);
+                }
+                prev = curr;
+            }
+
+            // instants before the anchor do not rewind the timestamp below the anchor
+            let later =
+                MonotonicTimeSource::with_anchor(wall_clock(0), start + Duration::from_secs(1));
+            assert_eq!(later.unix_ts_ms_at(start), 1_700_000_000_000);
+
+            // real clock
+            let mut src = MonotonicTimeSource::new();
+            let wall_ms = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64;
+            let mut prev = src.unix_ts_ms();
+            assert!(prev.abs_diff(wall_ms) < 1_000);
+            for _ in 0..10_000 {
+                let curr = src.unix_ts_ms();
+                assert!(curr >= prev);
+                prev = curr;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FnTimeSource;
+    use crate::generator::{Scru128Generator, Scru128Rng};
+
+    struct MockRng(u32);
+
+    impl Scru128Rng for MockRng {
+        fn next_u32(&mut self) -> u32 {
+            self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            self.0
+        }
+    }
+
+    /// Generates IDs from timestamps returned by closure
+    #[test]
+    fn generates_ids_from_timestamps_returned_by_closure() {
+        let mut clock = 0x0123_4567_89abu64;
+        let mut g = Scru128Generator::with_rand_and_time_sources(
+            MockRng(42),
+            FnTimeSource(|| {
+                clock += 1;
+                clock
+            }),
+        );
+
+        let mut prev = g.generate();
+        assert_eq!(prev.timestamp(), 0x0123_4567_89ac);
+        for i in 0..1_000 {
+            let curr = g.generate_or_abort().unwrap();
+            assert_eq!(curr.timestamp(), 0x0123_4567_89ad + i);
+            assert!(prev < curr);
+            prev = curr;
+        }
+        assert_eq!(g.next().unwrap().timestamp(), 0x0123_4567_8d95);
+    }
+}
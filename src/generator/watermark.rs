@@ -0,0 +1,148 @@
+#![cfg(feature = "std")]
+#![cfg_attr(docsrs, doc(cfg(feature = "std")))]
+
+use super::{Scru128Generator, Scru128Id, Scru128Rng, TimeSource};
+use std::sync::Mutex;
+
+/// A high-watermark shared by multiple generators to produce IDs that are monotonically ordered
+/// across the generators.
+///
+/// [`Scru128Generator::generate_with_watermark()`] ensures that each ID it returns is greater than
+/// the watermark and then raises the watermark to the ID. When generators share a watermark
+/// (typically through [`Arc`](std::sync::Arc)), the IDs they produce are strictly increasing in
+/// the order of production, while each generator keeps its own random number generator.
+///
+/// The watermark is guarded by a mutex held during the entire generation (Rust does not provide a
+/// stable `AtomicU128`), so the generators sharing a watermark are serialized much the same way
+/// as sharing one generator through `Arc<Mutex<Scru128Generator>>`. Moreover, a generator that
+/// finds its ID below the watermark takes over the watermark's `timestamp` and counters, so the
+/// `timestamp` of the generated IDs may lead the system clock of the lagging generator.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "default_rng")]
+/// # {
+/// use scru128::{generator::Watermark, Scru128Generator};
+///
+/// let watermark = Watermark::new();
+/// let mut g1 = Scru128Generator::new();
+/// let mut g2 = Scru128Generator::new();
+///
+/// let x = g1.generate_with_watermark(&watermark);
+/// let y = g2.generate_with_watermark(&watermark);
+/// assert!(x < y);
+/// assert_eq!(watermark.get(), y);
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct Watermark(Mutex<u128>);
+
+impl Watermark {
+    /// Creates a watermark initialized at the smallest value.
+    pub const fn new() -> Self {
+        Self(Mutex::new(0))
+    }
+
+    /// Returns the current value of the watermark, i.e., the greatest ID produced by the
+    /// generators sharing this watermark so far.
+    pub fn get(&self) -> Scru128Id {
+        Scru128Id::from_u128(*self.0.lock().expect("scru128: could not lock watermark"))
+    }
+}
+
+impl<R: Scru128Rng, T: TimeSource> Scru128Generator<R, T> {
+    /// Generates a new SCRU128 ID object that is greater than the `watermark` shared by multiple
+    /// generators, and then raises the `watermark` to the generated ID.
+    ///
+    /// If the ID generated from the current `timestamp` does not exceed the `watermark`, this
+    /// method takes over the `timestamp` and counters of the `watermark` and increments the
+    /// counters to generate a greater ID. See [`Watermark`] for the trade-offs.
+    pub fn generate_with_watermark(&mut self, watermark: &Watermark) -> Scru128Id {
+        let mut guard = watermark
+            .0
+            .lock()
+            .expect("scru128: could not lock watermark");
+
+        let timestamp = self.time_source.unix_ts_ms();
+        let mut value = self.generate_or_reset_unrecorded(timestamp, self.rollback_allowance);
+        if value.to_u128() <= *guard {
+            let last = Scru128Id::from_u128(*guard);
+            self.timestamp = last.timestamp();
+            self.counter_hi = last.counter_hi();
+            self.counter_lo = last.counter_lo();
+            self.ts_counter_hi = last.timestamp();
+            value = self.advance_counters();
+        }
+        self.record_generated();
+
+        *guard = value.to_u128();
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Scru128Generator, Watermark};
+
+    /// Generates globally increasing IDs with generators sharing watermark
+    #[test]
+    fn generates_globally_increasing_ids_with_generators_sharing_watermark() {
+        use std::sync::{Arc, Mutex};
+
+        let watermark = Arc::new(Watermark::new());
+        let produced = Arc::new(Mutex::new(Vec::new()));
+
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                let watermark = Arc::clone(&watermark);
+                let produced = Arc::clone(&produced);
+                s.spawn(move || {
+                    let mut g = Scru128Generator::new();
+                    for _ in 0..10_000 {
+                        // record IDs in production order
+                        let mut produced = produced.lock().unwrap();
+                        produced.push(g.generate_with_watermark(&watermark));
+                    }
+                });
+            }
+        });
+
+        let produced = produced.lock().unwrap();
+        assert_eq!(produced.len(), 4 * 10_000);
+        for i in 1..produced.len() {
+            assert!(produced[i - 1] < produced[i]);
+        }
+        assert_eq!(watermark.get(), produced[produced.len() - 1]);
+    }
+
+    /// Takes over watermark without flagging clock regression or double counting
+    #[test]
+    fn takes_over_watermark_without_flagging_clock_regression_or_double_counting() {
+        use crate::generator::{DefaultRng, FnTimeSource};
+        use std::cell::Cell;
+
+        let ts = 0x0123_4567_89abu64;
+        let watermark = Watermark::new();
+        let mut leader = Scru128Generator::with_rng(DefaultRng::default());
+        let x = leader.generate_or_reset_core(ts + 100, 10_000);
+        *watermark.0.lock().unwrap() = x.to_u128();
+
+        let clock = Cell::new(ts);
+        let mut follower = Scru128Generator::with_rand_and_time_sources(
+            DefaultRng::default(),
+            FnTimeSource(|| clock.get()),
+        );
+        let y = follower.generate_with_watermark(&watermark);
+        assert!(x < y);
+        assert_eq!(y.timestamp(), ts + 100);
+        assert!(!follower.last_clock_regressed());
+        assert_eq!(follower.stats().generated, 1);
+
+        clock.set(ts + 1);
+        let z = follower.generate_with_watermark(&watermark);
+        assert!(y < z);
+        assert!(!follower.last_clock_regressed());
+        assert_eq!(follower.stats().generated, 2);
+    }
+}
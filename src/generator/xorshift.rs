@@ -0,0 +1,103 @@
+#![cfg(feature = "bench-util")]
+#![cfg_attr(docsrs, doc(cfg(feature = "bench-util")))]
+
+use super::Scru128Rng;
+
+/// An extremely fast but insecure random number generator for benchmarks that measure the cost of
+/// the generator logic apart from that of the random number generator.
+///
+/// This type implements the xorshift64 algorithm by George Marsaglia and returns the high-order 32
+/// bits of each 64-bit state. It takes only a few instructions per call, so a benchmark of
+/// [`Scru128Generator`](super::Scru128Generator) built on this type mostly reflects the overhead
+/// of the timestamp handling and counter logic.
+///
+/// **Do not use this type to generate real IDs.** The outputs are fully predictable from a single
+/// observed value and never reseeded, and the default instance always starts from the same state;
+/// thus, IDs generated with it are easy to guess and likely to collide with those from other
+/// instances. Use [`DefaultRng`](super::DefaultRng) or another cryptographically strong random
+/// number generator outside of benchmarks.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "std")]
+/// # {
+/// use scru128::generator::XorShiftRng;
+/// use scru128::Scru128Generator;
+///
+/// let mut g = Scru128Generator::with_rng(XorShiftRng::default());
+/// let x = g.generate();
+/// let y = g.generate();
+/// assert!(x < y);
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct XorShiftRng {
+    state: u64,
+}
+
+impl XorShiftRng {
+    /// Creates an instance from a seed value. The seed is scrambled so that small seeds also
+    /// yield well-mixed outputs from the start, and a zero seed, which xorshift cannot start from,
+    /// is replaced with a fixed nonzero value.
+    pub const fn new(seed: u64) -> Self {
+        const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+        Self {
+            // multiplication by an odd constant maps nonzero seeds to nonzero states
+            state: if seed == 0 {
+                GOLDEN_GAMMA
+            } else {
+                seed.wrapping_mul(GOLDEN_GAMMA)
+            },
+        }
+    }
+}
+
+impl Default for XorShiftRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Scru128Rng for XorShiftRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        (x >> 32) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Scru128Rng, XorShiftRng};
+
+    /// Produces varying values
+    #[test]
+    fn produces_varying_values() {
+        for seed in [0, 1, 42, u64::MAX] {
+            let mut rng = XorShiftRng::new(seed);
+            let mut prev = rng.next_u32();
+            let (mut n_changes, mut ones) = (0, 0);
+            for _ in 0..10_000 {
+                let curr = rng.next_u32();
+                if curr != prev {
+                    n_changes += 1;
+                }
+                ones += curr.count_ones();
+                prev = curr;
+            }
+            assert!(n_changes > 9_990);
+
+            // roughly half of the bits are set
+            assert!((150_000..170_000).contains(&ones));
+        }
+
+        let mut a = XorShiftRng::new(1);
+        let mut b = XorShiftRng::new(2);
+        assert_ne!(a.next_u32(), b.next_u32());
+        assert_eq!(XorShiftRng::default(), XorShiftRng::new(0));
+    }
+}
@@ -0,0 +1,146 @@
+//! Process-wide SCRU128 generator for `no_std` environments guarded by critical sections.
+//!
+//! Unlike [`new()`](crate::new), which relies on the system clock and the operating system's
+//! random number generator, this module uses a time source and a random source registered by the
+//! application through [`register()`]. The shared generator is protected by
+//! [`critical_section::with()`], so it can be called from interrupt handlers and multiple cores
+//! as long as the application links a `critical-section` implementation suitable for the target.
+//!
+//! [`generate()`] panics if no sources have been registered yet; call [`register()`] once during
+//! the initialization of the application before generating any ID.
+//!
+//! # Examples
+//!
+//! ```rust
+//! fn unix_ts_ms() -> u64 {
+//!     // read a real-time clock on the target
+//!     # std::time::SystemTime::now()
+//!     #     .duration_since(std::time::UNIX_EPOCH)
+//!     #     .unwrap()
+//!     #     .as_millis() as u64
+//! }
+//!
+//! fn next_u32() -> u32 {
+//!     // read a hardware random number generator on the target
+//!     # 4 // chosen by fair dice roll
+//! }
+//!
+//! scru128::global::register(unix_ts_ms, next_u32);
+//!
+//! let x = scru128::global::generate();
+//! let y = scru128::global::generate();
+//! assert!(x < y);
+//! ```
+
+#![cfg(feature = "critical-section")]
+#![cfg_attr(docsrs, doc(cfg(feature = "critical-section")))]
+
+use crate::generator::{Scru128Rng, DEFAULT_ROLLBACK_ALLOWANCE};
+use crate::{Scru128Generator, Scru128Id};
+use core::cell::RefCell;
+use critical_section::Mutex;
+
+/// The global generator state, which is `None` until the sources are registered.
+static G: Mutex<RefCell<Option<GlobalGenInner>>> = Mutex::new(RefCell::new(None));
+
+/// The global generator and its time source.
+#[derive(Debug)]
+struct GlobalGenInner {
+    generator: Scru128Generator<FnRng>,
+    time_source: fn() -> u64,
+}
+
+/// An adapter to use a random source function as a [`Scru128Rng`].
+#[derive(Clone, Debug)]
+struct FnRng(fn() -> u32);
+
+impl Scru128Rng for FnRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.0)()
+    }
+}
+
+/// Registers the time source and random source of the global generator.
+///
+/// `time_source` must return the current Unix timestamp in milliseconds, and `rand_source` must
+/// return a random `u32` that should be cryptographically strong. Calling this function again
+/// replaces the sources and resets the internal states of the global generator.
+pub fn register(time_source: fn() -> u64, rand_source: fn() -> u32) {
+    let inner = GlobalGenInner {
+        generator: Scru128Generator::with_rng(FnRng(rand_source)),
+        time_source,
+    };
+    critical_section::with(|cs| *G.borrow_ref_mut(cs) = Some(inner));
+}
+
+/// Generates a new SCRU128 ID object from the global generator.
+///
+/// Like [`Scru128Generator::generate()`], this function resets the generator upon significant
+/// timestamp rollback.
+///
+/// # Panics
+///
+/// Panics if the sources have not been registered through [`register()`].
+pub fn generate() -> Scru128Id {
+    critical_section::with(|cs| {
+        G.borrow_ref_mut(cs).as_mut().map(|e| {
+            e.generator
+                .generate_or_reset_core((e.time_source)(), DEFAULT_ROLLBACK_ALLOWANCE)
+        })
+    })
+    .expect("scru128: global generator not registered; call scru128::global::register() first")
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{generate, register};
+
+    fn unix_ts_ms() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64
+    }
+
+    fn next_u32() -> u32 {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        static STATE: AtomicU32 = AtomicU32::new(0x9e37_79b9);
+        // xorshift32
+        let mut x = STATE.load(Ordering::Relaxed);
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        STATE.store(x, Ordering::Relaxed);
+        x
+    }
+
+    /// Panics before registration and generates monotonic IDs from multiple threads after it
+    #[test]
+    fn panics_before_registration_and_generates_monotonic_ids_from_multiple_threads_after_it() {
+        assert!(std::panic::catch_unwind(generate).is_err());
+
+        register(unix_ts_ms, next_u32);
+
+        let mut produced = std::thread::scope(|s| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| {
+                    s.spawn(|| {
+                        let ids: Vec<_> = (0..10_000).map(|_| generate()).collect();
+                        for i in 1..ids.len() {
+                            assert!(ids[i - 1] < ids[i]);
+                        }
+                        ids
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        produced.sort();
+        produced.dedup();
+        assert_eq!(produced.len(), 4 * 10_000);
+    }
+}
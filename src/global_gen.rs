@@ -1,20 +1,125 @@
 #![cfg(feature = "global_gen")]
 #![cfg_attr(docsrs, doc(cfg(feature = "global_gen")))]
 
-use crate::{Scru128Generator, Scru128Id};
+use crate::{generator::unix_ts_ms, Scru128Generator, Scru128Id};
+use std::sync::{Condvar, Mutex, MutexGuard, OnceLock};
+use std::{thread, time};
+
+/// The maximum amount of time in milliseconds by which the timestamp of the global generator may
+/// lead the system clock.
+const MAX_TIMESTAMP_LEAD: u64 = 1_000;
 
 /// Generates a new SCRU128 ID object using the global generator.
 ///
 /// This function is thread-safe; multiple threads in a process can call it concurrently without
 /// breaking the monotonic order of generated IDs. On Unix, this function resets the generator
 /// state when the process ID changes (i.e., upon forks) to avoid collisions across processes.
+///
+/// The global generator increments its `timestamp` when the counters overflow, so an extremely
+/// tight loop could in theory push its `timestamp` ahead of the system clock and affect all the
+/// callers in the process. To prevent this, this function blocks until the system clock catches
+/// up if a new ID could otherwise have a `timestamp` leading the clock by more than one second.
+/// See [`try_new()`] for a non-blocking variant.
 pub fn new() -> Scru128Id {
-    use std::sync::{Mutex, OnceLock};
-    static G: OnceLock<Mutex<GlobalGenInner>> = OnceLock::new();
-    G.get_or_init(Default::default)
-        .lock()
-        .expect("scru128: could not lock global generator")
-        .generate()
+    loop {
+        if let Some(value) = lock_global_gen().generate() {
+            return value;
+        }
+        thread::sleep(time::Duration::from_millis(1));
+    }
+}
+
+/// Generates a new SCRU128 ID object using the global generator, or returns `None` if a new ID
+/// could have a `timestamp` leading the system clock by more than one second.
+///
+/// This function is a non-blocking variant of [`new()`]. The `timestamp` lead is measured against
+/// the most advanced system clock reading observed by the global generator, so a clock rollback,
+/// which is handled by the generator's rollback allowance, does not cause this function to return
+/// `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// let x = scru128::try_new().expect("global generator ran ahead of system clock");
+/// assert!(x < scru128::new());
+/// ```
+pub fn try_new() -> Option<Scru128Id> {
+    lock_global_gen().generate()
+}
+
+/// Generates a new SCRU128 ID object using the global generator, serving concurrent callers in
+/// first-in, first-out order.
+///
+/// This function shares the global generator with [`new()`] and returns an ID in the same manner,
+/// but it also takes a ticket from a queue and waits for its turn before acquiring the generator.
+/// Under heavy contention, [`new()`] leaves the order of acquisition to the underlying mutex,
+/// which may let some threads acquire the generator repeatedly while starving others. This
+/// function bounds the waiting time of each caller by the number of callers queued ahead of it.
+///
+/// The trade-off is latency and throughput: the handoff to the next ticket holder wakes up all
+/// the waiting threads and forces the thread scheduler to run a specific one, so each call takes
+/// longer on average than [`new()`] does. Use this function only where the tail latency of
+/// individual threads matters more than the overall throughput.
+///
+/// # Examples
+///
+/// ```rust
+/// let x = scru128::new_fair();
+/// let y = scru128::new();
+/// assert!(x < y);
+/// ```
+pub fn new_fair() -> Scru128Id {
+    static Q: TicketQueue = TicketQueue::new();
+    let _turn = Q.wait_turn();
+    new()
+}
+
+/// A ticket lock that lets threads proceed one by one in the order of arrival.
+#[derive(Debug)]
+struct TicketQueue {
+    /// The next ticket to be issued and the ticket being served.
+    tickets: Mutex<(u64, u64)>,
+    turn_changed: Condvar,
+}
+
+impl TicketQueue {
+    const fn new() -> Self {
+        Self {
+            tickets: Mutex::new((0, 0)),
+            turn_changed: Condvar::new(),
+        }
+    }
+
+    /// Takes a ticket and blocks until it is served, returning a guard that passes the turn to
+    /// the next ticket when dropped.
+    fn wait_turn(&self) -> TicketGuard<'_> {
+        let mut tickets = self.lock();
+        let ticket = tickets.0;
+        tickets.0 += 1;
+        while tickets.1 != ticket {
+            tickets = self
+                .turn_changed
+                .wait(tickets)
+                .expect("scru128: could not lock ticket queue");
+        }
+        TicketGuard(self)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, (u64, u64)> {
+        self.tickets
+            .lock()
+            .expect("scru128: could not lock ticket queue")
+    }
+}
+
+/// The turn of a ticket holder in [`TicketQueue`].
+struct TicketGuard<'a>(&'a TicketQueue);
+
+impl Drop for TicketGuard<'_> {
+    fn drop(&mut self) {
+        self.0.lock().1 += 1;
+        self.0.turn_changed.notify_all();
+    }
 }
 
 /// Generates a new SCRU128 ID encoded in the 25-digit canonical string representation using the
@@ -37,12 +142,27 @@ pub fn new_string() -> String {
     new().into()
 }
 
-/// A thin wrapper to reset the state when the process ID changes (i.e., upon Unix forks).
+/// Returns the lock guard of the global generator.
+fn lock_global_gen() -> MutexGuard<'static, GlobalGenInner> {
+    static G: OnceLock<Mutex<GlobalGenInner>> = OnceLock::new();
+    G.get_or_init(Default::default)
+        .lock()
+        .expect("scru128: could not lock global generator")
+}
+
+/// A thin wrapper to reset the state when the process ID changes (i.e., upon Unix forks) and to
+/// keep the generator's `timestamp` from running far ahead of the system clock.
 #[derive(Debug)]
 struct GlobalGenInner {
     #[cfg(unix)]
     pid: u32,
     generator: Scru128Generator,
+
+    /// The `timestamp` of the last generated ID.
+    ts_last_id: u64,
+
+    /// The most advanced system clock reading observed so far.
+    ts_clock_max: u64,
 }
 
 impl Default for GlobalGenInner {
@@ -51,17 +171,28 @@ impl Default for GlobalGenInner {
             #[cfg(unix)]
             pid: std::process::id(),
             generator: Default::default(),
+            ts_last_id: 0,
+            ts_clock_max: 0,
         }
     }
 }
 
 impl GlobalGenInner {
-    fn generate(&mut self) -> Scru128Id {
+    fn generate(&mut self) -> Option<Scru128Id> {
         #[cfg(unix)]
         if self.pid != std::process::id() {
             *self = Default::default();
         }
-        self.generator.generate()
+
+        // the next ID may take `ts_last_id + 1` upon counter overflow
+        self.ts_clock_max = self.ts_clock_max.max(unix_ts_ms());
+        if self.ts_last_id + 1 > self.ts_clock_max + MAX_TIMESTAMP_LEAD {
+            return None;
+        }
+
+        let value = self.generator.generate();
+        self.ts_last_id = value.timestamp();
+        Some(value)
     }
 }
 
@@ -94,4 +225,80 @@ mod tests {
         assert_eq!(s.len(), 4 * 10000);
         Ok(())
     }
+
+    /// Serves contended callers fairly without generating duplicate IDs
+    #[test]
+    fn serves_contended_callers_fairly_without_generating_duplicate_ids() {
+        use std::collections::HashSet;
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::{thread, time};
+
+        const N_THREADS: usize = 8;
+        let stop = AtomicBool::new(false);
+        let results: Vec<Vec<_>> = thread::scope(|s| {
+            let handles: Vec<_> = (0..N_THREADS)
+                .map(|_| {
+                    s.spawn(|| {
+                        let mut ids = Vec::new();
+                        while !stop.load(Ordering::Relaxed) {
+                            ids.push(super::new_fair());
+                        }
+                        ids
+                    })
+                })
+                .collect();
+            thread::sleep(time::Duration::from_millis(200));
+            stop.store(true, Ordering::Relaxed);
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        for ids in &results {
+            for i in 1..ids.len() {
+                assert!(ids[i - 1] < ids[i]);
+            }
+        }
+
+        // best-effort check: no thread gets far fewer turns than the others
+        let counts: Vec<usize> = results.iter().map(Vec::len).collect();
+        let (min, max) = (counts.iter().min().unwrap(), counts.iter().max().unwrap());
+        assert!(
+            *min > 0 && min * 4 >= *max,
+            "unfair distribution: {:?}",
+            counts
+        );
+
+        let unique: HashSet<_> = results.iter().flatten().collect();
+        assert_eq!(unique.len(), counts.iter().sum::<usize>());
+    }
+
+    /// Keeps timestamp lead over system clock bounded
+    #[test]
+    fn keeps_timestamp_lead_over_system_clock_bounded() {
+        use super::{unix_ts_ms, GlobalGenInner, MAX_TIMESTAMP_LEAD};
+
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for _ in 0..100_000 {
+                        let x = super::new();
+                        assert!(x.timestamp() <= unix_ts_ms() + MAX_TIMESTAMP_LEAD);
+                    }
+                });
+            }
+        });
+
+        let mut g = GlobalGenInner::default();
+        assert!(g.generate().is_some());
+        g.ts_last_id = unix_ts_ms() + MAX_TIMESTAMP_LEAD + 60_000;
+        assert!(g.generate().is_none());
+        g.ts_last_id = unix_ts_ms() + MAX_TIMESTAMP_LEAD - 60_000;
+        assert!(g.generate().is_some());
+
+        // boundary: refuses once the next ID could exceed the lead by one millisecond
+        g.ts_clock_max = unix_ts_ms() + 60_000;
+        g.ts_last_id = g.ts_clock_max + MAX_TIMESTAMP_LEAD;
+        assert!(g.generate().is_none());
+        g.ts_last_id = g.ts_clock_max + MAX_TIMESTAMP_LEAD - 1;
+        assert!(g.generate().is_some());
+    }
 }
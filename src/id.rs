@@ -1,13 +1,16 @@
 #[cfg(not(feature = "std"))]
 use core as std;
 
-use crate::{MAX_COUNTER_HI, MAX_COUNTER_LO, MAX_TIMESTAMP};
+use crate::{Scru128Timestamp, MAX_COUNTER_HI, MAX_COUNTER_LO, MAX_TIMESTAMP};
 use fstr::FStr;
-use std::{fmt, str};
+use std::{borrow, cmp, fmt, str};
 
 /// Digit characters used in the Base36 notation.
 const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
 
+/// Uppercase digit characters used in the Base36 notation.
+const DIGITS_UPPER: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
 /// An O(1) map from ASCII code points to Base36 digit values.
 const DECODE_MAP: [u8; 256] = [
     0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
@@ -28,6 +31,26 @@ const DECODE_MAP: [u8; 256] = [
     0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
 ];
 
+/// Digit characters used in the Crockford Base32 notation.
+const DIGITS_CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Returns the Crockford Base32 digit value of an ASCII character, or `0xff` if the character is
+/// not a valid digit. This function accepts lowercase letters and the substitutions for ambiguous
+/// characters (`I` and `L` for `1`, and `O` for `0`).
+const fn decode_crockford(c: u8) -> u8 {
+    match c.to_ascii_uppercase() {
+        c @ b'0'..=b'9' => c - b'0',
+        b'O' => 0,
+        b'I' | b'L' => 1,
+        c @ b'A'..=b'H' => c - b'A' + 10,
+        c @ b'J'..=b'K' => c - b'J' + 18,
+        c @ b'M'..=b'N' => c - b'M' + 20,
+        c @ b'P'..=b'T' => c - b'P' + 22,
+        c @ b'V'..=b'Z' => c - b'V' + 27,
+        _ => 0xff,
+    }
+}
+
 /// Represents a SCRU128 ID and provides converters and comparison operators.
 ///
 /// # Examples
@@ -42,41 +65,331 @@ const DECODE_MAP: [u8; 256] = [
 /// assert_eq!(y.to_u128(), 0x017fa1de51a80fd992f9e8cc2d5eb88eu128);
 /// # Ok::<(), scru128::ParseError>(())
 /// ```
+///
+/// The [`Default`] implementation returns the nil ID, whose bits are all zero (i.e.,
+/// [`Scru128Id::MIN`]). Use [`is_nil()`](Self::is_nil) to test whether an ID is the nil ID.
+///
+/// With the `bitcode` feature enabled, this type implements `bitcode::Encode` and
+/// `bitcode::Decode` and is encoded as the 16-byte big-endian binary representation. Each ID takes
+/// at most 16 bytes (128 bits) on the wire with no length prefix, though a message as a whole
+/// carries a header of a few bytes, and bitcode may pack low-entropy values such as
+/// [`Scru128Id::MIN`] into fewer bytes.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "bitcode", derive(bitcode::Encode, bitcode::Decode))]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Binary)
+)]
 #[repr(transparent)]
 pub struct Scru128Id([u8; 16]);
 
 impl Scru128Id {
+    /// The length of the canonical string representation returned by [`encode()`](Self::encode).
+    pub const STR_LEN: usize = 25;
+
+    /// The length of the byte array representation returned by [`to_bytes()`](Self::to_bytes).
+    pub const BYTE_LEN: usize = 16;
+
+    /// The smallest possible ID value, whose string representation is
+    /// `0000000000000000000000000`.
+    ///
+    /// This constant is useful as the lower bound of range queries (e.g., for keyset pagination)
+    /// together with [`MAX`](Self::MAX).
+    pub const MIN: Self = Self::from_u128(0);
+
+    /// The largest possible ID value, whose string representation is
+    /// `f5lxx1zz5pnorynqglhzmsp33`.
+    pub const MAX: Self = Self::from_u128(u128::MAX);
+
     /// Creates an object from a 128-bit unsigned integer.
     pub const fn from_u128(int_value: u128) -> Self {
         Self(int_value.to_be_bytes())
     }
 
+    /// Creates an object from a 128-bit unsigned integer, or returns `None` if the integer is zero
+    /// and thus represents the nil ID.
+    ///
+    /// This method helps callers that regard the nil ID as invalid, e.g., as a placeholder for a
+    /// missing value, reject it upon construction instead of checking [`is_nil()`](Self::is_nil)
+    /// afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// assert_eq!(Scru128Id::from_u128_non_nil(0), None);
+    /// assert_eq!(
+    ///     Scru128Id::from_u128_non_nil(0x017fa1de51a80fd992f9e8cc2d5eb88e),
+    ///     Some(Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e))
+    /// );
+    /// ```
+    pub const fn from_u128_non_nil(int_value: u128) -> Option<Self> {
+        if int_value == 0 {
+            None
+        } else {
+            Some(Self::from_u128(int_value))
+        }
+    }
+
     /// Returns the 128-bit unsigned integer representation.
+    ///
+    /// Since `Scru128Id` is `Copy`, this method does not consume the original object. See also
+    /// [`bits()`](Self::bits) that takes `&self`.
     pub const fn to_u128(self) -> u128 {
         u128::from_be_bytes(self.0)
     }
 
+    /// Returns the 128-bit unsigned integer representation from a reference.
+    ///
+    /// This method is equivalent to [`to_u128()`](Self::to_u128) but takes `&self`, which reads
+    /// cleaner in bit operations on borrowed objects.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// const X: Scru128Id = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88eu128);
+    /// const LOW_64: u64 = X.bits() as u64;
+    /// assert_eq!(LOW_64, 0x92f9e8cc2d5eb88e);
+    /// ```
+    pub const fn bits(&self) -> u128 {
+        u128::from_be_bytes(self.0)
+    }
+
+    /// Creates an object from a pair of the high and low 64 bits returned by
+    /// [`to_u64_pair()`](Self::to_u64_pair).
+    pub const fn from_u64_pair(hi: u64, lo: u64) -> Self {
+        Self::from_u128((hi as u128) << 64 | lo as u128)
+    }
+
+    /// Returns the high and low 64 bits of the 128-bit unsigned integer representation as a pair,
+    /// which helps store IDs in two 64-bit integer columns.
+    ///
+    /// The pairs compare in the same order as the IDs under the lexicographic ordering of tuples.
+    /// Note that the ordering is preserved only if both halves are stored and compared as
+    /// unsigned integers; reinterpreting them as signed integers (e.g., to fit a `BIGINT` column)
+    /// breaks the ordering when the most significant bit of either half is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+    /// assert_eq!(x.to_u64_pair(), (0x017fa1de51a80fd9, 0x92f9e8cc2d5eb88e));
+    /// assert_eq!(Scru128Id::from_u64_pair(0x017fa1de51a80fd9, 0x92f9e8cc2d5eb88e), x);
+    /// ```
+    pub const fn to_u64_pair(self) -> (u64, u64) {
+        let int_value = self.to_u128();
+        ((int_value >> 64) as u64, int_value as u64)
+    }
+
+    /// Returns `true` if this is the nil ID, whose bits are all zero.
+    ///
+    /// The nil ID is returned by [`Default::default()`] and equals [`Scru128Id::MIN`]. Generators
+    /// never produce the nil ID because the `timestamp` of a generated ID is greater than zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// const NIL: bool = Scru128Id::MIN.is_nil();
+    /// assert!(NIL);
+    /// assert!(Scru128Id::default().is_nil());
+    /// assert!(!Scru128Id::from_u128(1).is_nil());
+    /// ```
+    pub const fn is_nil(&self) -> bool {
+        self.bits() == 0
+    }
+
     /// Creates an object from a 16-byte big-endian byte array.
+    ///
+    /// This is the canonical binary representation. See also
+    /// [`from_bytes_le()`](Self::from_bytes_le) for the little-endian counterpart.
     pub const fn from_bytes(array_value: [u8; 16]) -> Self {
         Self(array_value)
     }
 
     /// Returns the big-endian byte array representation.
+    ///
+    /// This is the canonical binary representation, whose byte order agrees with the sort order
+    /// of IDs. See also [`to_bytes_le()`](Self::to_bytes_le) for the little-endian counterpart.
     pub const fn to_bytes(self) -> [u8; 16] {
         self.0
     }
 
+    /// Creates an object from a 16-byte little-endian byte array, which is the byte-reversed
+    /// version of the big-endian representation accepted by [`from_bytes()`](Self::from_bytes).
+    ///
+    /// Use this method to read a 128-bit integer stored in the native byte order of
+    /// little-endian platforms (e.g., by C libraries that hold the value as `unsigned __int128`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+    /// let le = 0x017fa1de51a80fd992f9e8cc2d5eb88eu128.to_le_bytes();
+    /// assert_eq!(Scru128Id::from_bytes_le(le), x);
+    /// ```
+    pub const fn from_bytes_le(array_value: [u8; 16]) -> Self {
+        Self::from_u128(u128::from_le_bytes(array_value))
+    }
+
+    /// Returns the little-endian byte array representation, which is the byte-reversed version
+    /// of the big-endian representation returned by [`to_bytes()`](Self::to_bytes).
+    ///
+    /// Note that the little-endian byte arrays do not sort in the same order as the IDs; prefer
+    /// the canonical big-endian representation unless an external interface requires this form.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+    /// assert_eq!(x.to_bytes_le(), x.to_u128().to_le_bytes());
+    /// assert_eq!(x.to_bytes_le()[0], 0x8e);
+    /// assert_eq!(x.to_bytes_le()[15], 0x01);
+    /// ```
+    pub const fn to_bytes_le(self) -> [u8; 16] {
+        self.to_u128().to_le_bytes()
+    }
+
     /// Returns a reference to the big-endian byte array representation.
     pub const fn as_bytes(&self) -> &[u8; 16] {
         &self.0
     }
 
+    /// Returns the descending key, which is the bitwise complement of the big-endian byte array
+    /// representation.
+    ///
+    /// The descending keys sort in the reverse order of IDs, so storing them in place of the
+    /// canonical bytes lets a key-value store that iterates in ascending byte order list entries
+    /// newest-first, while the IDs sharing a timestamp remain in the reverse order of generation.
+    ///
+    /// A descending key is a derived value, not a valid SCRU128 ID representation. Do not pass it
+    /// to [`from_bytes()`](Self::from_bytes); use
+    /// [`from_descending_key()`](Self::from_descending_key) to restore the original ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+    /// let y = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88f);
+    /// assert!(x < y);
+    /// assert!(x.to_descending_key() > y.to_descending_key());
+    /// assert_eq!(Scru128Id::from_descending_key(x.to_descending_key()), x);
+    /// ```
+    pub const fn to_descending_key(&self) -> [u8; 16] {
+        (!self.to_u128()).to_be_bytes()
+    }
+
+    /// Restores an object from a descending key returned by
+    /// [`to_descending_key()`](Self::to_descending_key).
+    pub const fn from_descending_key(key: [u8; 16]) -> Self {
+        Self::from_u128(!u128::from_be_bytes(key))
+    }
+
+    /// Splits a composite key built by [`to_composite_key()`](Self::to_composite_key) into the
+    /// prefix and the trailing ID, or returns `None` if `key` is shorter than 16 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let key = b"tenant-1\x01\x7f\xa1\xde\x51\xa8\x0f\xd9\x92\xf9\xe8\xcc\x2d\x5e\xb8\x8e";
+    /// let (prefix, id) = Scru128Id::from_composite_key(key).unwrap();
+    /// assert_eq!(prefix, b"tenant-1");
+    /// assert_eq!(id, Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e));
+    /// assert!(Scru128Id::from_composite_key(b"too short").is_none());
+    /// ```
+    pub const fn from_composite_key(key: &[u8]) -> Option<(&[u8], Self)> {
+        if key.len() < 16 {
+            return None;
+        }
+        let (prefix, suffix) = key.split_at(key.len() - 16);
+        let mut bytes = [0u8; 16];
+        let mut i = 0;
+        while i < 16 {
+            bytes[i] = suffix[i];
+            i += 1;
+        }
+        Some((prefix, Self::from_bytes(bytes)))
+    }
+
+    /// Creates an object from a `timestamp` and 10 bytes of external random material that fill
+    /// the remaining 80 bits, or returns an error if `timestamp` is out of the 48-bit range.
+    ///
+    /// This method helps derive IDs deterministically from random material supplied by an
+    /// external source (e.g., a key management service). The bytes are placed as is after the
+    /// 6-byte big-endian `timestamp` in the 16-byte big-endian binary representation; that is,
+    /// `rand10[0..3]` becomes the 24-bit `counter_hi`, `rand10[3..6]` the 24-bit `counter_lo`, and
+    /// `rand10[6..10]` the 32-bit `entropy`, each in big-endian order. Hence, the last ten bytes of
+    /// [`to_bytes()`](Self::to_bytes) of the result always equal `rand10`.
+    ///
+    /// Unlike the IDs produced by a generator, the IDs created this way do not carry monotonic
+    /// counters, so those sharing a `timestamp` are ordered by the random material only.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `timestamp` is greater than the maximum 48-bit value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let rand10 = [0x0f, 0xd9, 0x92, 0xf9, 0xe8, 0xcc, 0x2d, 0x5e, 0xb8, 0x8e];
+    /// let x = Scru128Id::from_timestamp_and_bytes(0x017f_a1de_51a8, rand10)?;
+    /// assert_eq!(x, Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e));
+    /// assert_eq!(x.counter_hi(), 0x0f_d992);
+    /// assert_eq!(x.counter_lo(), 0xf9_e8cc);
+    /// assert_eq!(x.entropy(), 0x2d5e_b88e);
+    ///
+    /// assert!(Scru128Id::from_timestamp_and_bytes(1 << 48, rand10).is_err());
+    /// # Ok::<(), scru128::FieldError>(())
+    /// ```
+    pub const fn from_timestamp_and_bytes(
+        timestamp: u64,
+        rand10: [u8; 10],
+    ) -> Result<Self, FieldError> {
+        if timestamp > MAX_TIMESTAMP {
+            return Err(FieldError::out_of_range("timestamp"));
+        }
+        let mut bytes = [0u8; 16];
+        let ts_bytes = timestamp.to_be_bytes();
+        let mut i = 0;
+        while i < 6 {
+            bytes[i] = ts_bytes[i + 2];
+            i += 1;
+        }
+        while i < 16 {
+            bytes[i] = rand10[i - 6];
+            i += 1;
+        }
+        Ok(Self::from_bytes(bytes))
+    }
+
     /// Creates an object from field values.
     ///
+    /// The fields are packed into the 128-bit integer representation from the most significant
+    /// bit as follows: the 48-bit `timestamp` (bits 127-80), the 24-bit `counter_hi` (bits 79-56),
+    /// the 24-bit `counter_lo` (bits 55-32), and the 32-bit `entropy` (bits 31-0). Since `entropy`
+    /// takes exactly the low 32 bits, any `u32` value is valid and never affects the other fields.
+    /// Values taken from wider integers must be narrowed to `u32` by the caller.
+    ///
     /// # Panics
     ///
-    /// Panics if any argument is out of the value range of the field.
+    /// Panics if `timestamp`, `counter_hi`, or `counter_lo` is out of the value range of the field.
     pub const fn from_fields(
         timestamp: u64,
         counter_hi: u32,
@@ -86,12 +399,164 @@ impl Scru128Id {
         if timestamp > MAX_TIMESTAMP || counter_hi > MAX_COUNTER_HI || counter_lo > MAX_COUNTER_LO {
             panic!("invalid field value");
         } else {
-            Self::from_u128(
-                ((timestamp as u128) << 80)
-                    | ((counter_hi as u128) << 56)
-                    | ((counter_lo as u128) << 32)
-                    | (entropy as u128),
-            )
+            let int_value = ((timestamp as u128) << 80)
+                | ((counter_hi as u128) << 56)
+                | ((counter_lo as u128) << 32)
+                | (entropy as u128);
+            debug_assert!(
+                (int_value >> 80) as u64 == timestamp
+                    && (int_value >> 56) as u32 & MAX_COUNTER_HI == counter_hi
+                    && (int_value >> 32) as u32 & MAX_COUNTER_LO == counter_lo
+                    && int_value as u32 == entropy,
+                "fields must not overlap"
+            );
+            Self::from_u128(int_value)
+        }
+    }
+
+    /// Creates the smallest ID whose `timestamp` field equals `timestamp`, with all the counter
+    /// and entropy bits set to zero.
+    ///
+    /// Together with [`from_timestamp_upper_bound()`](Self::from_timestamp_upper_bound), this
+    /// method helps construct range queries that select the IDs created within a time window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp` is greater than the maximum 48-bit value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// // WHERE id >= lower AND id <= upper
+    /// let lower = Scru128Id::from_timestamp_lower_bound(0x0123_4567_89ab);
+    /// let upper = Scru128Id::from_timestamp_upper_bound(0x0123_4567_89ab);
+    /// assert_eq!(lower.to_u128(), 0x0123_4567_89ab << 80);
+    /// assert!(lower <= Scru128Id::from_fields(0x0123_4567_89ab, 42, 42, 42));
+    /// assert!(upper >= Scru128Id::from_fields(0x0123_4567_89ab, 42, 42, 42));
+    ///
+    /// // WHERE id >= lower AND id < lower of next timestamp
+    /// assert!(upper < Scru128Id::from_timestamp_lower_bound(0x0123_4567_89ac));
+    /// ```
+    pub const fn from_timestamp_lower_bound(timestamp: u64) -> Self {
+        Self::from_fields(timestamp, 0, 0, 0)
+    }
+
+    /// Creates the largest ID whose `timestamp` field equals `timestamp`, with all the counter
+    /// and entropy bits set to one.
+    ///
+    /// See [`from_timestamp_lower_bound()`](Self::from_timestamp_lower_bound) for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp` is greater than the maximum 48-bit value.
+    pub const fn from_timestamp_upper_bound(timestamp: u64) -> Self {
+        Self::from_fields(timestamp, MAX_COUNTER_HI, MAX_COUNTER_LO, u32::MAX)
+    }
+
+    /// Returns the inclusive lower and upper bounds of the IDs whose `timestamp` field falls
+    /// within the time window from `start_ms` to `end_ms`, both inclusive.
+    ///
+    /// The returned pair consists of the [lower bound](Self::from_timestamp_lower_bound) of
+    /// `start_ms` and the [upper bound](Self::from_timestamp_upper_bound) of `end_ms`, which is
+    /// suitable for range predicates such as `WHERE id BETWEEN ? AND ?` and for range filters
+    /// over sorted IDs. Because IDs sort by `timestamp` first, an ID is within the returned bounds
+    /// if and only if its `timestamp` is within the window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start_ms` is greater than `end_ms` or if either is greater than the maximum
+    /// 48-bit value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// // WHERE id BETWEEN lower AND upper
+    /// let (lower, upper) = Scru128Id::timestamp_range_bounds(1_700_000_000_000, 1_700_000_060_000);
+    /// let x = Scru128Id::from_fields(1_700_000_030_000, 42, 42, 42);
+    /// assert!(lower <= x && x <= upper);
+    /// let y = Scru128Id::from_fields(1_700_000_060_001, 0, 0, 0);
+    /// assert!(upper < y);
+    /// ```
+    pub const fn timestamp_range_bounds(start_ms: u64, end_ms: u64) -> (Self, Self) {
+        if start_ms > end_ms {
+            panic!("`start_ms` must not be greater than `end_ms`");
+        }
+        (
+            Self::from_timestamp_lower_bound(start_ms),
+            Self::from_timestamp_upper_bound(end_ms),
+        )
+    }
+
+    /// Returns the ID immediately following `self` in the 128-bit integer order, wrapping around
+    /// to [`MIN`](Self::MIN) at [`MAX`](Self::MAX).
+    ///
+    /// This method is useful to compute an exclusive upper bound or the smallest ID strictly
+    /// greater than a given ID. Use [`checked_increment()`](Self::checked_increment) to detect
+    /// the boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+    /// assert_eq!(x.increment().to_u128(), 0x017fa1de51a80fd992f9e8cc2d5eb88f);
+    /// assert!(x < x.increment());
+    /// assert_eq!(Scru128Id::MAX.increment(), Scru128Id::MIN);
+    /// ```
+    pub const fn increment(self) -> Self {
+        Self::from_u128(self.to_u128().wrapping_add(1))
+    }
+
+    /// Returns the ID immediately following `self` in the 128-bit integer order, or `None` if
+    /// `self` is [`MAX`](Self::MAX).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+    /// assert_eq!(x.checked_increment(), Some(x.increment()));
+    /// assert_eq!(Scru128Id::MAX.checked_increment(), None);
+    /// ```
+    pub const fn checked_increment(self) -> Option<Self> {
+        match self.to_u128().checked_add(1) {
+            Some(int_value) => Some(Self::from_u128(int_value)),
+            None => None,
+        }
+    }
+
+    /// Returns an ID whose `timestamp` is `ms` milliseconds later than that of `self` and whose
+    /// `counter_hi`, `counter_lo`, and `entropy` are the same as those of `self`, or `None` if the
+    /// `timestamp` would exceed the maximum 48-bit value.
+    ///
+    /// The result is a synthetic value that no generator has produced, which is intended to build
+    /// test fixtures for time-windowed logic. It may collide with or fall out of order with the
+    /// IDs actually generated at the shifted `timestamp`, so do not use it as a unique identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_fields(0x0123_4567_89ab, 42, 43, 0xdead_beef);
+    /// let y = x.checked_add_millis(60_000).unwrap();
+    /// assert_eq!(y.timestamp(), x.timestamp() + 60_000);
+    /// assert_eq!(y.entropy(), 0xdead_beef);
+    /// assert_eq!(Scru128Id::MAX.checked_add_millis(1), None);
+    /// ```
+    pub const fn checked_add_millis(self, ms: u64) -> Option<Self> {
+        match self.timestamp().checked_add(ms) {
+            Some(timestamp) if timestamp <= MAX_TIMESTAMP => {
+                let rand80 = self.to_u128() & ((1 << 80) - 1);
+                Some(Self::from_u128((timestamp as u128) << 80 | rand80))
+            }
+            _ => None,
         }
     }
 
@@ -100,6 +565,27 @@ impl Scru128Id {
         (self.to_u128() >> 80) as u64
     }
 
+    /// Returns the 48-bit `timestamp` field value as a signed integer, which is the type taken by
+    /// many date-time libraries and database drivers (e.g.,
+    /// `chrono::DateTime::from_timestamp_millis()`).
+    ///
+    /// The conversion never overflows nor yields a negative value, because the 48-bit `timestamp`
+    /// is always less than `2^48` and thus within the positive range of `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
+    /// assert_eq!(x.timestamp_millis_i64(), x.timestamp() as i64);
+    /// assert_eq!(Scru128Id::MAX.timestamp_millis_i64(), (1 << 48) - 1);
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub const fn timestamp_millis_i64(&self) -> i64 {
+        self.timestamp() as i64
+    }
+
     /// Returns the 24-bit `counter_hi` field value.
     pub const fn counter_hi(&self) -> u32 {
         (self.to_u128() >> 56) as u32 & MAX_COUNTER_HI
@@ -110,67 +596,572 @@ impl Scru128Id {
         (self.to_u128() >> 32) as u32 & MAX_COUNTER_LO
     }
 
+    /// Returns the 48-bit combined counter, i.e., `counter_hi` and `counter_lo` concatenated as
+    /// `(counter_hi << 24) | counter_lo`, which ranges from `0` to `2^48 - 1`.
+    ///
+    /// The two counter fields are split only for the bit layout and together work as a single
+    /// 48-bit counter incremented by one for each ID generated within the same `timestamp`. Since
+    /// the generator initializes the counters to random numbers, the value itself does not tell
+    /// the number of IDs generated, but the difference between two IDs generated by the same
+    /// generator within the same `timestamp` does.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_fields(0x0123_4567_89ab, 0x00_0001, 0xff_ffff, 0);
+    /// let y = Scru128Id::from_fields(0x0123_4567_89ab, 0x00_0002, 0x00_0000, 0);
+    /// assert_eq!(x.counter(), 0x0000_01ff_ffff);
+    /// assert_eq!(y.counter() - x.counter(), 1);
+    /// ```
+    pub const fn counter(&self) -> u64 {
+        (self.to_u128() >> 32) as u64 & 0xffff_ffff_ffff
+    }
+
     /// Returns the 32-bit `entropy` field value.
     pub const fn entropy(&self) -> u32 {
-        self.to_u128() as u32 & u32::MAX
+        self.to_u128() as u32
     }
 
-    /// Creates an object from a 25-digit string representation.
+    /// Returns all the field values as a tuple of `(timestamp, counter_hi, counter_lo, entropy)`,
+    /// in the same order as the arguments of [`from_fields()`](Self::from_fields).
     ///
     /// # Examples
     ///
     /// ```rust
     /// use scru128::Scru128Id;
     ///
-    /// let x = Scru128Id::try_from_str("037d0xye6op48cmce8ey4xlcf")?;
-    /// let y = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
-    /// assert_eq!(x, y);
+    /// let x = "036z8puq4tsxsigk6o19y164q".parse::<Scru128Id>()?;
+    /// let (timestamp, counter_hi, counter_lo, entropy) = x.fields();
+    /// assert_eq!(timestamp, x.timestamp());
+    /// assert_eq!(Scru128Id::from_fields(timestamp, counter_hi, counter_lo, entropy), x);
     /// # Ok::<(), scru128::ParseError>(())
     /// ```
-    pub const fn try_from_str(str_value: &str) -> Result<Self, ParseError> {
-        if str_value.len() != 25 {
-            return Err(ParseError::invalid_length(str_value.len()));
-        }
+    pub const fn fields(&self) -> (u64, u32, u32, u32) {
+        (
+            self.timestamp(),
+            self.counter_hi(),
+            self.counter_lo(),
+            self.entropy(),
+        )
+    }
 
-        let mut int_value = 0u128;
-        let mut i = 0;
-        while i < 25 {
-            let n = DECODE_MAP[str_value.as_bytes()[i] as usize];
-            if n == 0xff {
-                return Err(ParseError::invalid_digit(str_value, i));
-            }
-            int_value = match int_value.checked_mul(36) {
-                Some(int_value) => match int_value.checked_add(n as u128) {
-                    Some(int_value) => int_value,
-                    _ => return Err(ParseError::out_of_u128_range()),
-                },
-                _ => return Err(ParseError::out_of_u128_range()),
-            };
-            i += 1;
-        }
-        Ok(Self::from_u128(int_value))
+    /// Returns the `timestamp` field value wrapped in [`Scru128Timestamp`], which formats as an
+    /// ISO 8601 date and time and helps avoid mixing the millisecond value up with other integers.
+    ///
+    /// The resolution is one millisecond. See [`Scru128Timestamp`] for an example.
+    pub const fn creation_time(&self) -> Scru128Timestamp {
+        Scru128Timestamp::from_millis(self.timestamp())
     }
 
-    /// Returns the 25-digit string representation stored in a stack-allocated string-like type
-    /// that can be handled like [`String`] through common traits.
+    /// Returns the number of leading bytes (`0` to `16`) shared by the 16-byte big-endian binary
+    /// representations of `self` and `other`, which is useful to build radix trees and prefix
+    /// compression over IDs.
+    ///
+    /// IDs generated by the same generator within the same millisecond share at least the six
+    /// bytes of `timestamp` and usually the three bytes of `counter_hi` as well, and consecutive
+    /// ones often share most of the `counter_lo` bytes, while the `entropy` bytes rarely match.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use scru128::Scru128Id;
     ///
-    /// let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
-    /// let y = x.encode();
-    /// assert_eq!(y, "037d0xye6op48cmce8ey4xlcf");
-    /// assert_eq!(format!("{}", y), "037d0xye6op48cmce8ey4xlcf");
-    /// # Ok::<(), scru128::ParseError>(())
+    /// let x = Scru128Id::from_fields(0x0123_4567_89ab, 42, 42, 0xdead_beef);
+    /// let y = Scru128Id::from_fields(0x0123_4567_89ab, 42, 43, 0xcafe_babe);
+    /// assert_eq!(x.common_prefix_len(&y), 11);
+    /// assert_eq!(x.common_prefix_len(&x), 16);
     /// ```
-    pub const fn encode(&self) -> FStr<25> {
-        let int_value = self.to_u128();
-        let mut dst = [0u8; 25];
-        // implement Base36 using 56-bit words because Div<u128> is slow
-        let mut min_index: isize = 99; // any number greater than size of output array
-        let mut shift = 56 * 3;
+    pub const fn common_prefix_len(&self, other: &Self) -> usize {
+        ((self.to_u128() ^ other.to_u128()).leading_zeros() / 8) as usize
+    }
+
+    /// Returns `true` if `self` and `other` share the same `timestamp`, regardless of their
+    /// counters and `entropy`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_fields(0x0123_4567_89ab, 42, 42, 0xdead_beef);
+    /// let y = Scru128Id::from_fields(0x0123_4567_89ab, 43, 0, 0xcafe_babe);
+    /// let z = Scru128Id::from_fields(0x0123_4567_89ac, 0, 0, 0);
+    /// assert!(x.same_millisecond(&y));
+    /// assert!(!x.same_millisecond(&z));
+    /// ```
+    pub const fn same_millisecond(&self, other: &Self) -> bool {
+        self.timestamp() == other.timestamp()
+    }
+
+    /// Compares `self` and `other` by `timestamp` only, ignoring their counters and `entropy`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    /// use std::cmp::Ordering;
+    ///
+    /// let x = Scru128Id::from_fields(0x0123_4567_89ab, 42, 42, 0xdead_beef);
+    /// let y = Scru128Id::from_fields(0x0123_4567_89ab, 0, 0, 0);
+    /// let z = Scru128Id::from_fields(0x0123_4567_89ac, 0, 0, 0);
+    /// assert_eq!(x.timestamp_cmp(&y), Ordering::Equal);
+    /// assert_eq!(x.timestamp_cmp(&z), Ordering::Less);
+    /// assert_eq!(z.timestamp_cmp(&x), Ordering::Greater);
+    /// ```
+    pub const fn timestamp_cmp(&self, other: &Self) -> cmp::Ordering {
+        let (a, b) = (self.timestamp(), other.timestamp());
+        if a < b {
+            cmp::Ordering::Less
+        } else if a > b {
+            cmp::Ordering::Greater
+        } else {
+            cmp::Ordering::Equal
+        }
+    }
+
+    /// Creates an object from a 25-digit string representation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::try_from_str("037d0xye6op48cmce8ey4xlcf")?;
+    /// let y = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
+    /// assert_eq!(x, y);
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub const fn try_from_str(str_value: &str) -> Result<Self, ParseError> {
+        Self::try_from_bytes_str(str_value.as_bytes())
+    }
+
+    /// Creates an object from a 25-digit string representation given as a byte slice of ASCII
+    /// characters, without validating the byte slice as UTF-8 beforehand.
+    ///
+    /// This method returns the same result as [`try_from_str()`](Self::try_from_str) if `bytes` is
+    /// valid UTF-8. If `bytes` is not valid UTF-8 and an invalid digit is a non-ASCII byte, the
+    /// error reports the replacement character (U+FFFD) as the invalid digit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::try_from_bytes_str(b"037d0xye6op48cmce8ey4xlcf")?;
+    /// assert_eq!(x, "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?);
+    /// assert!(Scru128Id::try_from_bytes_str(b"037d0xye6op48cmce8ey4xlc\xff").is_err());
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub const fn try_from_bytes_str(bytes: &[u8]) -> Result<Self, ParseError> {
+        if bytes.len() != Self::STR_LEN {
+            return Err(ParseError::invalid_length(bytes.len(), "25"));
+        }
+
+        let mut int_value = 0u128;
+        let mut i = 0;
+        while i < Self::STR_LEN {
+            let n = DECODE_MAP[bytes[i] as usize];
+            if n == 0xff {
+                return Err(ParseError::invalid_digit_in_bytes(bytes, i));
+            }
+            int_value = match int_value.checked_mul(36) {
+                Some(int_value) => match int_value.checked_add(n as u128) {
+                    Some(int_value) => int_value,
+                    _ => return Err(ParseError::out_of_u128_range()),
+                },
+                _ => return Err(ParseError::out_of_u128_range()),
+            };
+            i += 1;
+        }
+        Ok(Self::from_u128(int_value))
+    }
+
+    /// Creates an object from a 25-digit string representation surrounded by optional ASCII
+    /// whitespace, which is common in log files and other loosely formatted sources.
+    ///
+    /// This method trims leading and trailing ASCII whitespace (as defined by
+    /// [`u8::is_ascii_whitespace()`]) and then parses the rest as
+    /// [`try_from_str()`](Self::try_from_str) does, so the trimmed part must still be exactly 25
+    /// digits long, and whitespace between digits remains an error. The position of an invalid
+    /// digit in the error, if any, is counted from the beginning of the untrimmed `str_value`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::parse_trimmed("  036z8puq4tsxsigk6o19y164q\t\n")?;
+    /// assert_eq!(x, "036z8puq4tsxsigk6o19y164q");
+    /// assert!(Scru128Id::parse_trimmed("036z8puq4tsx sigk6o19y164q").is_err());
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub const fn parse_trimmed(str_value: &str) -> Result<Self, ParseError> {
+        let bs = str_value.as_bytes();
+        let (mut start, mut end) = (0, bs.len());
+        while start < end && bs[start].is_ascii_whitespace() {
+            start += 1;
+        }
+        while start < end && bs[end - 1].is_ascii_whitespace() {
+            end -= 1;
+        }
+
+        let trimmed = bs.split_at(end).0.split_at(start).1;
+        match Self::try_from_bytes_str(trimmed) {
+            Err(ParseError {
+                repr: ParseErrorRepr::InvalidDigit { position, .. },
+                ..
+            }) => Err(ParseError::invalid_digit(str_value, start + position)),
+            result => result,
+        }
+    }
+
+    /// Returns the 25-digit string representation stored in a stack-allocated string-like type
+    /// that can be handled like [`String`] through common traits.
+    ///
+    /// The returned values compare lexicographically as strings do, so sorting them yields the
+    /// same order as sorting the IDs themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
+    /// let y = x.encode();
+    /// assert_eq!(y, "037d0xye6op48cmce8ey4xlcf");
+    /// assert_eq!(format!("{}", y), "037d0xye6op48cmce8ey4xlcf");
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub const fn encode(&self) -> FStr<25> {
+        self.encode_with(DIGITS)
+    }
+
+    /// Returns the 25-digit string representation in lowercase, which is identical to
+    /// [`encode()`](Self::encode).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::MAX;
+    /// assert_eq!(x.encode_lower(), "f5lxx1zz5pnorynqglhzmsp33");
+    /// assert_eq!(x.encode_upper(), "F5LXX1ZZ5PNORYNQGLHZMSP33");
+    /// assert_eq!(x.encode_lower().parse::<Scru128Id>()?, x);
+    /// assert_eq!(x.encode_upper().parse::<Scru128Id>()?, x);
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub const fn encode_lower(&self) -> FStr<25> {
+        self.encode_with(DIGITS)
+    }
+
+    /// Returns the 25-digit string representation in uppercase.
+    ///
+    /// The uppercase form is not canonical but is accepted by the parsers as they are
+    /// case-insensitive. See [`encode_lower()`](Self::encode_lower) for an example.
+    pub const fn encode_upper(&self) -> FStr<25> {
+        self.encode_with(DIGITS_UPPER)
+    }
+
+    /// Returns the 25-digit canonical string representation using a reference Base36 encoder that
+    /// always runs the long division over all the 25 digits.
+    ///
+    /// This method produces the same result as [`encode()`](Self::encode) and exists only to
+    /// measure the effect of the early exit taken by `encode()` in benchmarks. Use `encode()` in
+    /// ordinary code.
+    #[cfg(any(test, feature = "bench-util"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bench-util")))]
+    pub fn encode_fixed_iteration(&self) -> FStr<25> {
+        let int_value = self.to_u128();
+        let mut dst = [0u8; 25];
+        for shift in [112, 56, 0] {
+            let mut carry = (int_value >> shift) as u64 & 0xff_ffff_ffff_ffff;
+            for d in dst.iter_mut().rev() {
+                carry += (*d as u64) << 56;
+                *d = (carry % 36) as u8;
+                carry /= 36;
+            }
+        }
+        unsafe { FStr::from_bytes_unchecked(dst.map(|d| DIGITS[d as usize])) }
+    }
+
+    /// Writes the 25-digit canonical string representation into a caller-provided buffer and
+    /// returns the written part as a string slice.
+    ///
+    /// This method is available under `no_std` environments and lets callers keep the `fstr`
+    /// crate out of their public signatures, e.g., when the buffer is part of a larger structure.
+    /// Use [`encode()`](Self::encode) if a self-contained value is preferred.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
+    /// let mut buf = [0u8; 25];
+    /// assert_eq!(x.encode_to_slice(&mut buf), "037d0xye6op48cmce8ey4xlcf");
+    /// assert_eq!(&buf, b"037d0xye6op48cmce8ey4xlcf");
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub fn encode_to_slice<'a>(&self, buf: &'a mut [u8; 25]) -> &'a str {
+        *buf = *self.encode().as_bytes();
+        // the canonical representation consists of ASCII digits and letters only
+        unsafe { str::from_utf8_unchecked(buf) }
+    }
+
+    /// Returns a small [`Copy`] value that writes the 25-digit canonical string representation
+    /// only when it is formatted.
+    ///
+    /// The returned value defers encoding to its [`Display`](fmt::Display) implementation, so
+    /// passing it to a logging macro costs nothing if the log record is filtered out. It formats
+    /// exactly as `Scru128Id` itself does, including the URN form under the alternate flag
+    /// (`{:#}`). This method is helpful where a value that implements [`Display`](fmt::Display)
+    /// but not the other traits of `Scru128Id` is desired, e.g., to keep a logging framework
+    /// from recording an ID through [`Debug`](fmt::Debug) or serde.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
+    /// let lazy = x.lazy_display();
+    /// assert_eq!(format!("id={}", lazy), "id=037d0xye6op48cmce8ey4xlcf");
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub fn lazy_display(&self) -> impl fmt::Display + Copy {
+        LazyDisplay(*self)
+    }
+
+    /// Returns the 25-digit canonical string representation split into five groups of five digits
+    /// separated by hyphens, which is intended to help humans read and transcribe IDs.
+    ///
+    /// The grouped form is stored in a stack buffer and is available under `no_std` environments.
+    /// It is for display purposes only and is not accepted by the parsers; remove the hyphens to
+    /// restore the canonical form.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
+    /// assert_eq!(x.encode_grouped_5(), "037d0-xye6o-p48cm-ce8ey-4xlcf");
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub const fn encode_grouped_5(&self) -> FStr<29> {
+        let src = self.encode();
+        let src = src.as_bytes();
+        let mut dst = [b'-'; 29];
+        let mut i = 0;
+        while i < src.len() {
+            dst[i + i / 5] = src[i];
+            i += 1;
+        }
+        unsafe { FStr::from_bytes_unchecked(dst) }
+    }
+
+    /// Splits the 25-digit canonical string representation into the first 13 digits and the last
+    /// 12 digits, which is useful to derive a shard key from the prefix of an ID.
+    ///
+    /// Because the canonical strings of IDs have the same length and sort in the same order as
+    /// the IDs, the 13-digit prefixes sort in the same order as well: if `x < y`, then the prefix
+    /// of `x` is lexicographically less than or equal to that of `y`, and IDs sharing a prefix are
+    /// ordered by the suffixes. Use [`from_text_parts()`](Self::from_text_parts) to reassemble the
+    /// ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
+    /// let (hi, lo) = x.split_text();
+    /// assert_eq!(hi, "037d0xye6op48");
+    /// assert_eq!(lo, "cmce8ey4xlcf");
+    /// assert_eq!(Scru128Id::from_text_parts(&hi, &lo)?, x);
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub const fn split_text(&self) -> (FStr<13>, FStr<12>) {
+        let src = self.encode();
+        let src = src.as_bytes();
+        let mut hi = [0u8; 13];
+        let mut lo = [0u8; 12];
+        let mut i = 0;
+        while i < src.len() {
+            if i < hi.len() {
+                hi[i] = src[i];
+            } else {
+                lo[i - hi.len()] = src[i];
+            }
+            i += 1;
+        }
+        unsafe {
+            (
+                FStr::from_bytes_unchecked(hi),
+                FStr::from_bytes_unchecked(lo),
+            )
+        }
+    }
+
+    /// Returns the last `N` digits of the 25-digit string representation as a short tag to
+    /// display in user interfaces, much like an abbreviated commit hash.
+    ///
+    /// The tail is taken rather than the head because the leading digits mostly encode the
+    /// `timestamp` and look alike among IDs generated around the same time, whereas the trailing
+    /// digits mostly encode the random `entropy` field and thus tell IDs apart at a glance. The
+    /// last six digits or so are derived almost entirely from the 32-bit `entropy`.
+    ///
+    /// Short tags are for display only and must not be used as identifiers. A tag of `N` digits
+    /// takes one of at most 36<sup>`N`</sup> values, and a collision among tags of different IDs
+    /// becomes likely once the number of IDs shown together approaches the square root of that
+    /// (e.g., tens of thousands of IDs for six-digit tags). Always keep the full ID to refer to
+    /// the underlying record.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is greater than 25.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
+    /// assert_eq!(x.short_tag::<7>(), "ey4xlcf");
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub const fn short_tag<const N: usize>(&self) -> FStr<N> {
+        if N > Self::STR_LEN {
+            panic!("short tag must not be longer than 25 digits");
+        }
+        let src = self.encode();
+        let src = src.as_bytes();
+        let mut dst = [0u8; N];
+        let mut i = 0;
+        while i < N {
+            dst[i] = src[Self::STR_LEN - N + i];
+            i += 1;
+        }
+        unsafe { FStr::from_bytes_unchecked(dst) }
+    }
+
+    /// Creates an object from the first 13 digits and the last 12 digits of a 25-digit string
+    /// representation, which is the inverse of [`split_text()`](Self::split_text).
+    ///
+    /// The position of an invalid digit in the error, if any, is counted from the beginning of
+    /// `hi` as if the two parts were concatenated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `hi` is not 13 bytes long, `lo` is not 12 bytes long, or the
+    /// concatenated string is not a valid string representation.
+    pub const fn from_text_parts(hi: &str, lo: &str) -> Result<Self, ParseError> {
+        let (hi, lo) = (hi.as_bytes(), lo.as_bytes());
+        if hi.len() != 13 {
+            return Err(ParseError::invalid_length(hi.len(), "13 for first part"));
+        } else if lo.len() != 12 {
+            return Err(ParseError::invalid_length(lo.len(), "12 for second part"));
+        }
+
+        let mut src = [0u8; 25];
+        let mut i = 0;
+        while i < src.len() {
+            src[i] = if i < hi.len() {
+                hi[i]
+            } else {
+                lo[i - hi.len()]
+            };
+            i += 1;
+        }
+        Self::try_from_bytes_str(&src)
+    }
+
+    /// Returns the 128-bit integer representation encoded in 26 Crockford Base32 digits, the
+    /// notation used by ULID, which helps migrate from or interoperate with ULID-based systems.
+    ///
+    /// The encoded strings consist of digits and uppercase letters excluding `I`, `L`, `O`, and
+    /// `U`, and they sort lexicographically in the same order as the IDs. Note that SCRU128 and
+    /// ULID have different field layouts, and thus the string does not represent a meaningful
+    /// ULID; it is merely another notation of the same 128-bit value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+    /// let y = x.to_base32_crockford();
+    /// assert_eq!(y, "01FYGXWMD81ZCS5YF8SGPNXE4E");
+    /// assert_eq!(Scru128Id::from_base32_crockford(&y)?, x);
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub const fn to_base32_crockford(&self) -> FStr<26> {
+        let int_value = self.to_u128();
+        let mut dst = [0u8; 26];
+        let mut i = 0;
+        while i < dst.len() {
+            let shift = 5 * (dst.len() - 1 - i);
+            dst[i] = DIGITS_CROCKFORD[(int_value >> shift) as usize & 0x1f];
+            i += 1;
+        }
+        unsafe { FStr::from_bytes_unchecked(dst) }
+    }
+
+    /// Creates an object from a 26-digit Crockford Base32 string representation.
+    ///
+    /// This method is case-insensitive and accepts `I` and `L` as `1` and `O` as `0`, following
+    /// the Crockford Base32 specification. Hyphens and check symbols are not supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `str_value` is not 26 bytes long, contains an invalid digit, or
+    /// represents a value greater than the maximum 128-bit integer (i.e., begins with a digit
+    /// greater than `7`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_base32_crockford("01fygxwmd81zcs5yf8sgpnxe4e")?;
+    /// let y = Scru128Id::from_base32_crockford("OIFYGXWMD81ZCS5YF8SGPNXE4E")?;
+    /// assert_eq!(x, y);
+    /// assert_eq!(x.to_u128(), 0x017fa1de51a80fd992f9e8cc2d5eb88e);
+    /// assert!(Scru128Id::from_base32_crockford("01FYGXWMD81ZCS5YF8SGPNXE4").is_err());
+    /// assert!(Scru128Id::from_base32_crockford("81FYGXWMD81ZCS5YF8SGPNXE4E").is_err());
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub const fn from_base32_crockford(str_value: &str) -> Result<Self, ParseError> {
+        let bytes = str_value.as_bytes();
+        if bytes.len() != 26 {
+            return Err(ParseError::invalid_length(bytes.len(), "26"));
+        }
+
+        let mut int_value = 0u128;
+        let mut i = 0;
+        while i < bytes.len() {
+            let n = decode_crockford(bytes[i]);
+            if n == 0xff {
+                return Err(ParseError::invalid_digit(str_value, i));
+            } else if i == 0 && n > 7 {
+                return Err(ParseError::out_of_u128_range());
+            }
+            int_value = (int_value << 5) | n as u128;
+            i += 1;
+        }
+        Ok(Self::from_u128(int_value))
+    }
+
+    /// Implements the Base36 encoder using the specified digit characters.
+    const fn encode_with(&self, digits: &[u8; 36]) -> FStr<25> {
+        let int_value = self.to_u128();
+        let mut dst = [0u8; 25];
+        // implement Base36 using 56-bit words because Div<u128> is slow
+        let mut min_index: isize = 99; // any number greater than size of output array
+        let mut shift = 56 * 3;
         while shift > 0 {
             shift -= 56;
             let mut carry = (int_value >> shift) as u64 & 0xff_ffff_ffff_ffff;
@@ -189,12 +1180,194 @@ impl Scru128Id {
 
         let mut i = 0;
         while i < dst.len() {
-            dst[i] = DIGITS[dst[i] as usize];
+            dst[i] = digits[dst[i] as usize];
             i += 1;
         }
-        unsafe { FStr::from_inner_unchecked(dst) }
+        unsafe { FStr::from_bytes_unchecked(dst) }
     }
-}
+
+    /// Creates an object from a 32-digit hexadecimal string representation of the 16-byte
+    /// big-endian byte array, optionally hyphenated and enclosed in braces like a Windows GUID
+    /// string (e.g., `{017fa1de-51a8-0fd9-92f9-e8cc2d5eb88e}`).
+    ///
+    /// This method accepts the following four forms, with hexadecimal digits in either case:
+    ///
+    /// - `xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx`
+    /// - `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+    /// - `{xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx}`
+    /// - `{xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx}`
+    ///
+    /// See [`to_braced_hex()`](Self::to_braced_hex) for the inverse conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_braced_hex("{017fa1de-51a8-0fd9-92f9-e8cc2d5eb88e}")?;
+    /// let y = Scru128Id::from_braced_hex("017FA1DE51A80FD992F9E8CC2D5EB88E")?;
+    /// assert_eq!(x, y);
+    /// assert_eq!(x, Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e));
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub const fn from_braced_hex(str_value: &str) -> Result<Self, ParseError> {
+        let bs = str_value.as_bytes();
+        let (start, end) = match bs.len() {
+            32 | 36 => (0, bs.len()),
+            34 | 38 => (1, bs.len() - 1),
+            n_bytes => return Err(ParseError::invalid_length(n_bytes, "32, 34, 36, or 38")),
+        };
+        if start == 1 && bs[0] != b'{' {
+            return Err(ParseError::invalid_digit(str_value, 0));
+        }
+
+        let hyphenated = end - start == 36;
+        let mut int_value = 0u128;
+        let mut i = start;
+        while i < end {
+            if hyphenated && matches!(i - start, 8 | 13 | 18 | 23) {
+                if bs[i] != b'-' {
+                    return Err(ParseError::invalid_digit(str_value, i));
+                }
+            } else {
+                let n = DECODE_MAP[bs[i] as usize];
+                if n >= 16 {
+                    return Err(ParseError::invalid_digit(str_value, i));
+                }
+                int_value = (int_value << 4) | n as u128;
+            }
+            i += 1;
+        }
+
+        if start == 1 && bs[end] != b'}' {
+            return Err(ParseError::invalid_digit(str_value, end));
+        }
+        Ok(Self::from_u128(int_value))
+    }
+
+    /// Creates an object from an HTTP entity tag (`ETag`) that encloses the 25-digit string
+    /// representation in double quotes, optionally prefixed with the weak indicator `W/` (e.g.,
+    /// `"037d0xye6op48cmce8ey4xlcf"` or `W/"037d0xye6op48cmce8ey4xlcf"`).
+    ///
+    /// This method accepts the `entity-tag` syntax defined in RFC 7232, Section 2.3, restricted to
+    /// the tags produced by [`to_etag()`](Self::to_etag). The weak indicator is case-sensitive as
+    /// specified by the RFC, while the digits are case-insensitive. Whitespace around the tag and
+    /// lists of multiple tags (as in `If-None-Match` headers) are not accepted; split and trim the
+    /// header value beforehand.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `str_value` is not 27 bytes long (strong) or 29 bytes long (weak), if
+    /// the quotes or weak indicator are missing, or if the quoted part is not a valid string
+    /// representation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_etag("\"037d0xye6op48cmce8ey4xlcf\"")?;
+    /// let y = Scru128Id::from_etag("W/\"037d0xye6op48cmce8ey4xlcf\"")?;
+    /// assert_eq!(x, y);
+    /// assert_eq!(x, "037d0xye6op48cmce8ey4xlcf");
+    /// assert!(Scru128Id::from_etag("037d0xye6op48cmce8ey4xlcf").is_err());
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub const fn from_etag(str_value: &str) -> Result<Self, ParseError> {
+        let bs = str_value.as_bytes();
+        let start = match bs.len() {
+            27 => 1,
+            29 => {
+                if bs[0] != b'W' {
+                    return Err(ParseError::invalid_digit(str_value, 0));
+                } else if bs[1] != b'/' {
+                    return Err(ParseError::invalid_digit(str_value, 1));
+                }
+                3
+            }
+            n_bytes => return Err(ParseError::invalid_length(n_bytes, "27, or 29 if weak")),
+        };
+        if bs[start - 1] != b'"' {
+            return Err(ParseError::invalid_digit(str_value, start - 1));
+        }
+
+        let mut digits = [0u8; Self::STR_LEN];
+        let mut i = 0;
+        while i < digits.len() {
+            if DECODE_MAP[bs[start + i] as usize] == 0xff {
+                return Err(ParseError::invalid_digit(str_value, start + i));
+            }
+            digits[i] = bs[start + i];
+            i += 1;
+        }
+
+        if bs[bs.len() - 1] != b'"' {
+            return Err(ParseError::invalid_digit(str_value, bs.len() - 1));
+        }
+        Self::try_from_bytes_str(&digits)
+    }
+
+    /// Creates an object from either a `0x`-prefixed hexadecimal string or a 25-digit string
+    /// representation, detecting the form from the input.
+    ///
+    /// This method applies the following rules in order:
+    ///
+    /// 1. A string starting with `0x` or `0X` is parsed as a hexadecimal integer of 1 to 32 digits
+    ///    in either case, like the output of `format!("{:#x}", id.to_u128())`. Leading zeros may
+    ///    be omitted.
+    /// 2. A string of 25 characters is parsed as the canonical Base36 representation like
+    ///    [`try_from_str()`](Self::try_from_str).
+    /// 3. Any other string is rejected with an invalid length error.
+    ///
+    /// Note that the prefix rule takes precedence: a 25-digit Base36 string that happens to start
+    /// with `0x` is interpreted as hexadecimal, and it results in an error or a different ID.
+    /// This method does not detect other forms such as the hyphenated hexadecimal form accepted by
+    /// [`from_braced_hex()`](Self::from_braced_hex) or hexadecimal digits without the prefix.
+    /// Use the form-specific methods to parse untrusted input whose form is known.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_str_auto("0x017fa1de51a80fd992f9e8cc2d5eb88e")?;
+    /// let y = Scru128Id::from_str_auto("036z8puq4tsxsigk6o19y164q")?;
+    /// assert_eq!(x, Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e));
+    /// assert_eq!(y, Scru128Id::try_from_str("036z8puq4tsxsigk6o19y164q")?);
+    /// assert_eq!(Scru128Id::from_str_auto("0xff")?, Scru128Id::from_u128(0xff));
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub const fn from_str_auto(str_value: &str) -> Result<Self, ParseError> {
+        let bs = str_value.as_bytes();
+        if bs.len() >= 2 && bs[0] == b'0' && (bs[1] == b'x' || bs[1] == b'X') {
+            if bs.len() < 3 || bs.len() > 34 {
+                return Err(ParseError::invalid_length(
+                    bs.len(),
+                    "3 to 34 with 0x prefix",
+                ));
+            }
+
+            let mut int_value = 0u128;
+            let mut i = 2;
+            while i < bs.len() {
+                let n = DECODE_MAP[bs[i] as usize];
+                if n >= 16 {
+                    return Err(ParseError::invalid_digit(str_value, i));
+                }
+                int_value = (int_value << 4) | n as u128;
+                i += 1;
+            }
+            Ok(Self::from_u128(int_value))
+        } else if bs.len() == Self::STR_LEN {
+            Self::try_from_str(str_value)
+        } else {
+            Err(ParseError::invalid_length(
+                bs.len(),
+                "25, or 3 to 34 with 0x prefix",
+            ))
+        }
+    }
+}
 
 impl From<u128> for Scru128Id {
     fn from(value: u128) -> Self {
@@ -222,328 +1395,2662 @@ impl From<Scru128Id> for [u8; 16] {
     }
 }
 
+impl TryFrom<&[u8]> for Scru128Id {
+    type Error = ParseError;
+
+    /// Creates an object from a byte slice of either the binary or textual representation,
+    /// distinguished solely by the length of the slice.
+    ///
+    /// A 16-byte slice is always interpreted as the big-endian binary representation (as
+    /// [`from_bytes()`](Self::from_bytes) does), even if it happens to consist of ASCII
+    /// characters, whereas a 25-byte slice is always interpreted as the 25-digit string
+    /// representation of ASCII characters (as [`try_from_bytes_str()`](Self::try_from_bytes_str)
+    /// does). A slice of any other length results in an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::try_from(&b"037d0xye6op48cmce8ey4xlcf"[..])?;
+    /// let y = Scru128Id::try_from(&x.to_bytes()[..])?;
+    /// assert_eq!(x, y);
+    /// assert!(Scru128Id::try_from(&[0u8; 20][..]).is_err());
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        match <[u8; 16]>::try_from(value) {
+            Ok(array_value) => Ok(Self::from_bytes(array_value)),
+            Err(_) if value.len() == Self::STR_LEN => Self::try_from_bytes_str(value),
+            Err(_) => Err(ParseError::invalid_length(value.len(), "16 or 25")),
+        }
+    }
+}
+
 impl AsRef<[u8]> for Scru128Id {
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
     }
-}
+}
+
+/// Borrows the 16-byte big-endian binary representation, which enables map lookups by a byte
+/// array key.
+///
+/// The borrowed form is compared and hashed consistently with `Scru128Id`.
+///
+/// # Examples
+///
+/// ```rust
+/// use scru128::Scru128Id;
+/// use std::collections::BTreeMap;
+///
+/// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+/// let map = BTreeMap::from([(x, "foo")]);
+///
+/// let key: &[u8; 16] = &[
+///     0x01, 0x7f, 0xa1, 0xde, 0x51, 0xa8, 0x0f, 0xd9, 0x92, 0xf9, 0xe8, 0xcc, 0x2d, 0x5e, 0xb8, 0x8e,
+/// ];
+/// assert_eq!(map.get(key), Some(&"foo"));
+/// assert_eq!(map.get(&key[..]), Some(&"foo"));
+/// ```
+impl borrow::Borrow<[u8; 16]> for Scru128Id {
+    fn borrow(&self) -> &[u8; 16] {
+        self.as_bytes()
+    }
+}
+
+/// Borrows the 16-byte big-endian binary representation as a slice, which enables map lookups by
+/// a byte slice key.
+///
+/// The borrowed form is compared and hashed consistently with `Scru128Id`.
+impl borrow::Borrow<[u8]> for Scru128Id {
+    fn borrow(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl str::FromStr for Scru128Id {
+    type Err = ParseError;
+
+    /// Creates an object from a 25-digit string representation.
+    fn from_str(str_value: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(str_value)
+    }
+}
+
+impl fmt::Display for Scru128Id {
+    /// Returns the 25-digit canonical string representation.
+    ///
+    /// The alternate flag (`{:#}`) prepends the `urn:scru128:` prefix to produce the URN form.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = "03997ft3ckz99o1i3f82zat1t".parse::<Scru128Id>()?;
+    /// assert_eq!(format!("{}", x), "03997ft3ckz99o1i3f82zat1t");
+    /// assert_eq!(format!("{:32}", x), "03997ft3ckz99o1i3f82zat1t       ");
+    /// assert_eq!(format!("{:->32}", x), "-------03997ft3ckz99o1i3f82zat1t");
+    /// assert_eq!(format!("{:.^7.5}", x), ".03997.");
+    /// assert_eq!(format!("{:#}", x), "urn:scru128:03997ft3ckz99o1i3f82zat1t");
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            const PREFIX: &[u8; 12] = b"urn:scru128:";
+            let mut buffer = [0u8; 37];
+            buffer[..12].copy_from_slice(PREFIX);
+            buffer[12..].copy_from_slice(self.encode().as_bytes());
+            fmt::Display::fmt(str::from_utf8(&buffer).unwrap(), f)
+        } else {
+            fmt::Display::fmt(self.encode().as_str(), f)
+        }
+    }
+}
+
+/// The value returned by [`Scru128Id::lazy_display()`].
+#[derive(Clone, Copy)]
+struct LazyDisplay(Scru128Id);
+
+impl fmt::Display for LazyDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Scru128Id {
+    /// Formats the 128-bit integer as 32 zero-padded hexadecimal digits in the same manner as the
+    /// standard integer implementations do.
+    fn fmt_hex(&self, f: &mut fmt::Formatter<'_>, digits: &[u8; 36]) -> fmt::Result {
+        let int_value = self.to_u128();
+        let mut buffer = [0u8; 32];
+        for (i, e) in buffer.iter_mut().enumerate() {
+            *e = digits[(int_value >> (124 - 4 * i)) as usize & 0xf];
+        }
+        f.pad_integral(true, "0x", str::from_utf8(&buffer).unwrap())
+    }
+}
+
+impl fmt::LowerHex for Scru128Id {
+    /// Returns the 128-bit integer representation as 32 lowercase hexadecimal digits.
+    ///
+    /// Unlike the integer types, the digits are zero-padded to 32 characters so that the
+    /// hexadecimal strings sort in the same order as the IDs. The width, fill, and alternate
+    /// (`0x` prefix) flags are respected as the standard integer implementations do.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+    /// assert_eq!(format!("{:x}", x), "017fa1de51a80fd992f9e8cc2d5eb88e");
+    /// assert_eq!(format!("{:#x}", x), "0x017fa1de51a80fd992f9e8cc2d5eb88e");
+    /// assert_eq!(format!("{:>36x}", x), "    017fa1de51a80fd992f9e8cc2d5eb88e");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_hex(f, DIGITS)
+    }
+}
+
+impl fmt::UpperHex for Scru128Id {
+    /// Returns the 128-bit integer representation as 32 uppercase hexadecimal digits.
+    ///
+    /// See the [`LowerHex`](fmt::LowerHex) implementation for the details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    ///
+    /// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+    /// assert_eq!(format!("{:X}", x), "017FA1DE51A80FD992F9E8CC2D5EB88E");
+    /// assert_eq!(format!("{:#X}", x), "0x017FA1DE51A80FD992F9E8CC2D5EB88E");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_hex(f, DIGITS_UPPER)
+    }
+}
+
+impl Scru128Id {
+    /// Compares the canonical string representation with `text`, converting ASCII uppercase
+    /// letters in `text` to lowercase.
+    fn cmp_text(&self, text: &[u8]) -> cmp::Ordering {
+        let this = self.encode();
+        this.as_bytes()
+            .iter()
+            .copied()
+            .cmp(text.iter().map(u8::to_ascii_lowercase))
+    }
+}
+
+/// Compares an ID with a string by the canonical string representation of the ID.
+///
+/// The string is compared as is except that ASCII uppercase letters are converted to lowercase;
+/// that is, the comparison is case-insensitive as the canonical representation consists of digits
+/// and lowercase letters only. The string is not parsed, and thus any string, including one of an
+/// invalid length, can be compared lexicographically.
+///
+/// Because of the case insensitivity, these comparisons are not transitive across string values:
+/// an ID equals both `"036z8puq4tsxsigk6o19y164q"` and `"036Z8PUQ4TSXSIGK6O19Y164Q"` although
+/// the two strings are not equal to each other. Do not rely on them where an equivalence relation
+/// is required (e.g., to deduplicate strings); parse strings into `Scru128Id` to compare them
+/// strictly.
+///
+/// # Examples
+///
+/// ```rust
+/// use scru128::Scru128Id;
+///
+/// let x = "036z8puq4tsxsigk6o19y164q".parse::<Scru128Id>()?;
+/// assert!(x == "036z8puq4tsxsigk6o19y164q");
+/// assert!(x == "036Z8PUQ4TSXSIGK6O19Y164Q");
+/// assert!(x > "036z8puq4tsxsigk6o19y164p");
+/// assert!(x < "036z8puq4tsxsigk6o19y164q0");
+/// assert!(x.encode() == x);
+/// # Ok::<(), scru128::ParseError>(())
+/// ```
+///
+/// # Type inference
+///
+/// As `Scru128Id` can now be compared with multiple types, the compiler no longer infers the
+/// target type of `parse()` from a comparison with an ID. Specify the type explicitly with a
+/// turbofish or a type annotation:
+///
+/// ```rust
+/// use scru128::Scru128Id;
+///
+/// let x = "036z8puq4tsxsigk6o19y164q".parse::<Scru128Id>()?;
+/// assert_eq!(x, "036z8puq4tsxsigk6o19y164q".parse::<Scru128Id>()?);
+/// // `assert_eq!(x, "036z8puq4tsxsigk6o19y164q".parse()?)` does not compile
+/// # Ok::<(), scru128::ParseError>(())
+/// ```
+impl PartialEq<str> for Scru128Id {
+    fn eq(&self, other: &str) -> bool {
+        self.cmp_text(other.as_bytes()).is_eq()
+    }
+}
+
+impl PartialOrd<str> for Scru128Id {
+    fn partial_cmp(&self, other: &str) -> Option<cmp::Ordering> {
+        Some(self.cmp_text(other.as_bytes()))
+    }
+}
+
+impl PartialEq<&str> for Scru128Id {
+    fn eq(&self, other: &&str) -> bool {
+        self.cmp_text(other.as_bytes()).is_eq()
+    }
+}
+
+impl PartialOrd<&str> for Scru128Id {
+    fn partial_cmp(&self, other: &&str) -> Option<cmp::Ordering> {
+        Some(self.cmp_text(other.as_bytes()))
+    }
+}
+
+impl PartialEq<FStr<25>> for Scru128Id {
+    fn eq(&self, other: &FStr<25>) -> bool {
+        self.cmp_text(other.as_bytes()).is_eq()
+    }
+}
+
+impl PartialOrd<FStr<25>> for Scru128Id {
+    fn partial_cmp(&self, other: &FStr<25>) -> Option<cmp::Ordering> {
+        Some(self.cmp_text(other.as_bytes()))
+    }
+}
+
+impl PartialEq<Scru128Id> for str {
+    fn eq(&self, other: &Scru128Id) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<Scru128Id> for str {
+    fn partial_cmp(&self, other: &Scru128Id) -> Option<cmp::Ordering> {
+        other.partial_cmp(self).map(cmp::Ordering::reverse)
+    }
+}
+
+impl PartialEq<Scru128Id> for &str {
+    fn eq(&self, other: &Scru128Id) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<Scru128Id> for &str {
+    fn partial_cmp(&self, other: &Scru128Id) -> Option<cmp::Ordering> {
+        other.partial_cmp(self).map(cmp::Ordering::reverse)
+    }
+}
+
+impl PartialEq<Scru128Id> for FStr<25> {
+    fn eq(&self, other: &Scru128Id) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<Scru128Id> for FStr<25> {
+    fn partial_cmp(&self, other: &Scru128Id) -> Option<cmp::Ordering> {
+        other.partial_cmp(self).map(cmp::Ordering::reverse)
+    }
+}
+
+/// An error parsing an invalid string representation of SCRU128 ID.
+///
+/// Use [`kind()`](Self::kind) to examine the category of the error programmatically. Errors
+/// compare equal and hash identically if they report the same failure at the same position,
+/// which allows collecting them in a `HashSet` to deduplicate.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseError {
+    repr: ParseErrorRepr,
+
+    /// The index of the invalid item in a sequence, set by `parse_all()`.
+    index: Option<usize>,
+}
+
+/// The category of a [`ParseError`], which is returned by [`ParseError::kind()`].
+///
+/// This enum is marked as non-exhaustive to allow new categories to be added in the future, so a
+/// `match` expression must include a wildcard arm.
+///
+/// # Examples
+///
+/// ```rust
+/// use scru128::{ParseErrorKind, Scru128Id};
+///
+/// fn status_code(input: &str) -> u16 {
+///     match input.parse::<Scru128Id>() {
+///         Ok(_) => 200,
+///         Err(err) => match err.kind() {
+///             ParseErrorKind::InvalidLength | ParseErrorKind::InvalidDigit => 400,
+///             ParseErrorKind::OutOfU128Range => 422,
+///             _ => 500,
+///         },
+///     }
+/// }
+///
+/// assert_eq!(status_code("036z8puq4tsxsigk6o19y164q"), 200);
+/// assert_eq!(status_code("036z8puq4tsxsigk6o19y164"), 400);
+/// assert_eq!(status_code("036z8puq4tsxsigk6o19y164!"), 400);
+/// assert_eq!(status_code("zzzzzzzzzzzzzzzzzzzzzzzzz"), 422);
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input is not of the expected length.
+    InvalidLength,
+
+    /// The input contains a character that is not a valid digit or delimiter at its position.
+    InvalidDigit,
+
+    /// The input represents a value greater than the maximum 128-bit unsigned integer.
+    OutOfU128Range,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+enum ParseErrorRepr {
+    InvalidLength {
+        n_bytes: usize,
+        /// Describes the expected length(s) in the error message.
+        expected: &'static str,
+    },
+    InvalidDigit {
+        /// Holds the invalid character as a UTF-8 byte array to work in the const context.
+        utf8_char: [u8; 4],
+        position: usize,
+    },
+    OutOfU128Range,
+}
+
+impl ParseError {
+    /// Returns the category of the error.
+    pub const fn kind(&self) -> ParseErrorKind {
+        match self.repr {
+            ParseErrorRepr::InvalidLength { .. } => ParseErrorKind::InvalidLength,
+            ParseErrorRepr::InvalidDigit { .. } => ParseErrorKind::InvalidDigit,
+            ParseErrorRepr::OutOfU128Range => ParseErrorKind::OutOfU128Range,
+        }
+    }
+
+    /// Returns the zero-based index of the invalid item if the error was returned by
+    /// [`parse_all()`](crate::parse_all), or `None` otherwise.
+    pub const fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Creates an `InvalidLength` variant from the actual and expected lengths.
+    const fn invalid_length(n_bytes: usize, expected: &'static str) -> Self {
+        Self {
+            index: None,
+            repr: ParseErrorRepr::InvalidLength { n_bytes, expected },
+        }
+    }
+
+    /// Creates an `InvalidDigit` variant from the entire string and the position of invalid digit.
+    const fn invalid_digit(src: &str, position: usize) -> Self {
+        const fn is_char_boundary(utf8_bytes: &[u8], index: usize) -> bool {
+            match index {
+                0 => true,
+                i if i < utf8_bytes.len() => (utf8_bytes[i] as i8) >= -64,
+                _ => index == utf8_bytes.len(),
+            }
+        }
+
+        let bs = src.as_bytes();
+        assert!(is_char_boundary(bs, position));
+        let mut utf8_char = [bs[position], 0, 0, 0];
+
+        let mut i = 1;
+        while !is_char_boundary(bs, position + i) {
+            utf8_char[i] = bs[position + i];
+            i += 1;
+        }
+
+        Self {
+            index: None,
+            repr: ParseErrorRepr::InvalidDigit {
+                utf8_char,
+                position,
+            },
+        }
+    }
+
+    /// Creates an `InvalidDigit` variant from a byte slice that may not be valid UTF-8 and the
+    /// position of invalid digit.
+    const fn invalid_digit_in_bytes(src: &[u8], position: usize) -> Self {
+        if src[position].is_ascii() {
+            Self {
+                index: None,
+                repr: ParseErrorRepr::InvalidDigit {
+                    utf8_char: [src[position], 0, 0, 0],
+                    position,
+                },
+            }
+        } else if let Ok(src) = str::from_utf8(src) {
+            Self::invalid_digit(src, position)
+        } else {
+            Self {
+                index: None,
+                repr: ParseErrorRepr::InvalidDigit {
+                    utf8_char: [0xef, 0xbf, 0xbd, 0], // U+FFFD
+                    position,
+                },
+            }
+        }
+    }
+
+    /// Creates an `OutOfU128Range` variant.
+    const fn out_of_u128_range() -> Self {
+        Self {
+            index: None,
+            repr: ParseErrorRepr::OutOfU128Range,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(index) = self.index {
+            write!(f, "could not parse item {} as SCRU128 ID: ", index)?;
+        } else {
+            write!(f, "could not parse string as SCRU128 ID: ")?;
+        }
+        match self.repr {
+            ParseErrorRepr::InvalidLength { n_bytes, expected } => {
+                write!(
+                    f,
+                    "invalid length: {} bytes (expected {})",
+                    n_bytes, expected
+                )
+            }
+            ParseErrorRepr::InvalidDigit {
+                utf8_char,
+                position,
+            } => {
+                let chr = str::from_utf8(&utf8_char).unwrap().chars().next().unwrap();
+                write!(f, "invalid digit '{}' at {}", chr.escape_debug(), position)
+            }
+            ParseErrorRepr::OutOfU128Range => write!(f, "out of 128-bit value range"),
+        }
+    }
+}
+
+/// An error creating an ID from a field value out of the value range of the field.
+///
+/// # Examples
+///
+/// ```rust
+/// use scru128::Scru128Id;
+///
+/// let err = Scru128Id::from_timestamp_and_bytes(1 << 48, [0; 10]).unwrap_err();
+/// assert_eq!(err.field(), "timestamp");
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct FieldError {
+    field: &'static str,
+}
+
+impl FieldError {
+    /// Creates an error reporting that `field` is out of range.
+    const fn out_of_range(field: &'static str) -> Self {
+        Self { field }
+    }
+
+    /// Returns the name of the field whose value is out of range (e.g., `"timestamp"`).
+    pub const fn field(&self) -> &'static str {
+        self.field
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid field value: `{}` out of range", self.field)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+mod with_std {
+    use super::{cmp, ParseError, Scru128Id};
+    use std::borrow::Cow;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    impl TryFrom<String> for Scru128Id {
+        type Error = ParseError;
+
+        fn try_from(value: String) -> Result<Self, Self::Error> {
+            Self::try_from_str(&value)
+        }
+    }
+
+    /// Parses a borrowed or owned string uniformly, which helps handlers that receive either
+    /// form (e.g., a path segment that may or may not have been percent-decoded).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    /// use std::borrow::Cow;
+    ///
+    /// let x = Scru128Id::try_from(Cow::Borrowed("037d0xye6op48cmce8ey4xlcf"))?;
+    /// let y = Scru128Id::try_from(Cow::<str>::Owned("037d0xye6op48cmce8ey4xlcf".to_owned()))?;
+    /// assert_eq!(x, y);
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    impl TryFrom<Cow<'_, str>> for Scru128Id {
+        type Error = ParseError;
+
+        fn try_from(value: Cow<'_, str>) -> Result<Self, Self::Error> {
+            Self::try_from_str(&value)
+        }
+    }
+
+    impl From<Scru128Id> for String {
+        fn from(object: Scru128Id) -> Self {
+            object.encode().into()
+        }
+    }
+
+    /// Compares an ID with a string case-insensitively by the canonical string representation of
+    /// the ID, as the `PartialEq<str>` implementation does.
+    ///
+    /// Like the `PartialEq<str>` implementation, this comparison is not transitive across string
+    /// values differing only in letter case, and it requires the target type of `parse()` to be
+    /// specified explicitly when the result is compared with an ID.
+    impl PartialEq<String> for Scru128Id {
+        fn eq(&self, other: &String) -> bool {
+            self.cmp_text(other.as_bytes()).is_eq()
+        }
+    }
+
+    impl PartialOrd<String> for Scru128Id {
+        fn partial_cmp(&self, other: &String) -> Option<cmp::Ordering> {
+            Some(self.cmp_text(other.as_bytes()))
+        }
+    }
+
+    impl PartialEq<Scru128Id> for String {
+        fn eq(&self, other: &Scru128Id) -> bool {
+            other == self
+        }
+    }
+
+    impl PartialOrd<Scru128Id> for String {
+        fn partial_cmp(&self, other: &Scru128Id) -> Option<cmp::Ordering> {
+            other.partial_cmp(self).map(cmp::Ordering::reverse)
+        }
+    }
+
+    impl Scru128Id {
+        /// Returns the 16-byte big-endian byte array representation as a hyphenated hexadecimal
+        /// string enclosed in braces like a Windows GUID string (e.g.,
+        /// `{017fa1de-51a8-0fd9-92f9-e8cc2d5eb88e}`).
+        ///
+        /// Note that the grouping of hexadecimal digits follows the GUID convention and does not
+        /// correspond to the SCRU128 field boundaries; for example, the 48-bit `timestamp` spans
+        /// the first and second groups. See [`from_braced_hex()`](Self::from_braced_hex) for the
+        /// inverse conversion.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use scru128::Scru128Id;
+        ///
+        /// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+        /// assert_eq!(x.to_braced_hex(), "{017fa1de-51a8-0fd9-92f9-e8cc2d5eb88e}");
+        /// ```
+        pub fn to_braced_hex(&self) -> String {
+            let int_value = self.to_u128();
+            format!(
+                "{{{:08x}-{:04x}-{:04x}-{:04x}-{:012x}}}",
+                int_value >> 96,
+                (int_value >> 80) & 0xffff,
+                (int_value >> 64) & 0xffff,
+                (int_value >> 48) & 0xffff,
+                int_value & 0xffff_ffff_ffff,
+            )
+        }
+
+        /// Returns an HTTP entity tag (`ETag`) that encloses the 25-digit string representation in
+        /// double quotes, prefixed with the weak indicator `W/` if `weak` is `true`.
+        ///
+        /// The returned value conforms to the `entity-tag` syntax defined in RFC 7232, Section 2.3,
+        /// and it can be placed in `ETag` and `If-None-Match` headers as is. A strong tag (e.g.,
+        /// `"037d0xye6op48cmce8ey4xlcf"`) claims that the representation is byte-for-byte
+        /// identical, whereas a weak tag (e.g., `W/"037d0xye6op48cmce8ey4xlcf"`) claims only
+        /// semantic equivalence; choose one according to how the ID relates to the resource. See
+        /// [`from_etag()`](Self::from_etag) for the inverse conversion.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use scru128::Scru128Id;
+        ///
+        /// let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
+        /// assert_eq!(x.to_etag(false), "\"037d0xye6op48cmce8ey4xlcf\"");
+        /// assert_eq!(x.to_etag(true), "W/\"037d0xye6op48cmce8ey4xlcf\"");
+        /// # Ok::<(), scru128::ParseError>(())
+        /// ```
+        pub fn to_etag(&self, weak: bool) -> String {
+            format!("{}\"{}\"", if weak { "W/" } else { "" }, self.encode())
+        }
+
+        /// Returns the 25-digit string representation as a [`Cow`] to pass to APIs that take
+        /// either borrowed or owned strings.
+        ///
+        /// The returned value is always [`Cow::Owned`] because the canonical string is not stored
+        /// anywhere that outlives this call: an ID holds the binary representation only, and
+        /// [`encode()`](Self::encode) builds the string in a stack buffer that cannot be borrowed
+        /// beyond the call. Prefer `encode()` where an `&str` suffices to avoid the allocation.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use scru128::Scru128Id;
+        ///
+        /// let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
+        /// assert_eq!(x.to_cow(), "037d0xye6op48cmce8ey4xlcf");
+        /// assert_eq!(Scru128Id::try_from(x.to_cow())?, x);
+        /// # Ok::<(), scru128::ParseError>(())
+        /// ```
+        pub fn to_cow(&self) -> Cow<'static, str> {
+            Cow::Owned(self.encode().into())
+        }
+
+        /// Returns a composite key that concatenates `prefix` and the 16-byte big-endian byte
+        /// array representation, which is suitable for key-value stores that iterate keys by
+        /// prefix in byte order (e.g., RocksDB and other LSM-tree databases).
+        ///
+        /// Since the big-endian byte order is the same as the integer order of IDs, composite
+        /// keys sharing the same prefix sort by ID when compared as unsigned byte strings.
+        /// Moreover, composite keys built with prefixes of the same length sort by the prefix
+        /// first and then by ID. This guarantee does not hold for prefixes of different lengths,
+        /// where a shorter prefix may be followed by bytes of the ID; use fixed-length prefixes
+        /// (or a prefix extractor configured to the prefix length) to iterate keys in order. Use
+        /// [`from_composite_key()`](Self::from_composite_key) to extract the ID from a key.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use scru128::Scru128Id;
+        ///
+        /// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+        /// let key = x.to_composite_key(b"tenant-1");
+        /// assert_eq!(&key[..8], b"tenant-1");
+        /// assert_eq!(&key[8..], x.as_bytes());
+        /// assert_eq!(Scru128Id::from_composite_key(&key), Some((&b"tenant-1"[..], x)));
+        /// ```
+        pub fn to_composite_key(&self, prefix: &[u8]) -> Vec<u8> {
+            let mut key = Vec::with_capacity(prefix.len() + 16);
+            key.extend_from_slice(prefix);
+            key.extend_from_slice(self.as_bytes());
+            key
+        }
+
+        /// Returns the `timestamp` field value as a [`SystemTime`].
+        ///
+        /// The resolution of the returned value is milliseconds, as is that of the `timestamp`
+        /// field. The entire 48-bit range of `timestamp` (up to year 10889) is representable
+        /// without overflow.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use scru128::Scru128Id;
+        /// use std::time::{Duration, UNIX_EPOCH};
+        ///
+        /// let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
+        /// assert_eq!(
+        ///     x.timestamp_as_system_time(),
+        ///     UNIX_EPOCH + Duration::from_millis(x.timestamp())
+        /// );
+        /// # Ok::<(), scru128::ParseError>(())
+        /// ```
+        pub fn timestamp_as_system_time(&self) -> SystemTime {
+            UNIX_EPOCH + Duration::from_millis(self.timestamp())
+        }
+
+        /// Creates an object from field values, taking the `timestamp` field value from a
+        /// [`SystemTime`].
+        ///
+        /// The `time` is truncated to milliseconds. This is the inverse of
+        /// [`timestamp_as_system_time()`](Self::timestamp_as_system_time).
+        ///
+        /// # Panics
+        ///
+        /// Panics if `time` is earlier than the Unix epoch or later than the maximum 48-bit
+        /// `timestamp`, or if any other argument is out of the value range of the field.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use scru128::Scru128Id;
+        /// use std::time::SystemTime;
+        ///
+        /// let x = Scru128Id::from_system_time_and_fields(SystemTime::now(), 0, 0, 0);
+        /// let y = Scru128Id::from_system_time_and_fields(x.timestamp_as_system_time(), 0, 0, 0);
+        /// assert_eq!(x, y);
+        /// ```
+        pub fn from_system_time_and_fields(
+            time: SystemTime,
+            counter_hi: u32,
+            counter_lo: u32,
+            entropy: u32,
+        ) -> Self {
+            let timestamp = time
+                .duration_since(UNIX_EPOCH)
+                .ok()
+                .and_then(|e| u64::try_from(e.as_millis()).ok())
+                .expect("invalid field value");
+            Self::from_fields(timestamp, counter_hi, counter_lo, entropy)
+        }
+    }
+
+    impl std::error::Error for ParseError {}
+
+    impl std::error::Error for super::FieldError {}
+
+    /// Parses a sequence of 25-digit string representations into a vector of SCRU128 ID objects,
+    /// stopping at the first invalid item.
+    ///
+    /// This function parses each item with [`Scru128Id::try_from_str()`] and allocates no memory
+    /// other than the returned vector. It is handy to load newline-delimited lists of IDs with
+    /// [`str::lines()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first invalid item, with its zero-based position in the sequence
+    /// available through [`ParseError::index()`] and included in the error message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let dump = "036z8puq4tsxsigk6o19y164q\n036z8puq54qny1vq3hcbrkweb\n";
+    /// let ids = scru128::parse_all(dump.lines())?;
+    /// assert_eq!(ids.len(), 2);
+    ///
+    /// let err = scru128::parse_all(["036z8puq4tsxsigk6o19y164q", "invalid"]).unwrap_err();
+    /// assert_eq!(err.index(), Some(1));
+    /// # Ok::<(), scru128::ParseError>(())
+    /// ```
+    pub fn parse_all<I, S>(iter: I) -> Result<Vec<Scru128Id>, ParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        iter.into_iter()
+            .enumerate()
+            .map(|(i, e)| {
+                Scru128Id::try_from_str(e.as_ref()).map_err(|err| ParseError {
+                    index: Some(i),
+                    ..err
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+pub use with_std::parse_all;
+
+#[cfg(test)]
+mod tests {
+    use super::{cmp, str, FStr, Scru128Id};
+
+    #[cfg(feature = "std")]
+    use crate::Scru128Generator;
+
+    const MAX_UINT48: u64 = (1 << 48) - 1;
+    const MAX_UINT24: u32 = (1 << 24) - 1;
+    const MAX_UINT32: u32 = u32::MAX;
+
+    /// Encodes and decodes prepared cases correctly
+    #[test]
+    fn encodes_and_decodes_prepared_cases_correctly() {
+        #[allow(clippy::type_complexity)]
+        let cases: &[((u64, u32, u32, u32), &str)] = &[
+            ((0, 0, 0, 0), "0000000000000000000000000"),
+            ((MAX_UINT48, 0, 0, 0), "F5LXX1ZZ5K6TP71GEEH2DB7K0"),
+            ((MAX_UINT48, 0, 0, 0), "f5lxx1zz5k6tp71geeh2db7k0"),
+            ((0, MAX_UINT24, 0, 0), "0000000005GV2R2KJWR7N8XS0"),
+            ((0, MAX_UINT24, 0, 0), "0000000005gv2r2kjwr7n8xs0"),
+            ((0, 0, MAX_UINT24, 0), "00000000000000JPIA7QL4HS0"),
+            ((0, 0, MAX_UINT24, 0), "00000000000000jpia7ql4hs0"),
+            ((0, 0, 0, MAX_UINT32), "0000000000000000001Z141Z3"),
+            ((0, 0, 0, MAX_UINT32), "0000000000000000001z141z3"),
+            (
+                (MAX_UINT48, MAX_UINT24, MAX_UINT24, MAX_UINT32),
+                "F5LXX1ZZ5PNORYNQGLHZMSP33",
+            ),
+            (
+                (MAX_UINT48, MAX_UINT24, MAX_UINT24, MAX_UINT32),
+                "f5lxx1zz5pnorynqglhzmsp33",
+            ),
+        ];
+
+        for e in cases {
+            let from_fields = Scru128Id::from_fields(e.0 .0, e.0 .1, e.0 .2, e.0 .3);
+            let from_string = e.1.parse::<Scru128Id>().unwrap();
+
+            assert_eq!(from_fields, from_string);
+            assert_eq!(
+                from_fields.to_u128(),
+                u128::from_str_radix(e.1, 36).unwrap()
+            );
+            assert_eq!(
+                from_string.to_u128(),
+                u128::from_str_radix(e.1, 36).unwrap()
+            );
+            assert_eq!(
+                from_fields.to_bytes(),
+                u128::from_str_radix(e.1, 36).unwrap().to_be_bytes()
+            );
+            assert_eq!(
+                from_string.to_bytes(),
+                u128::from_str_radix(e.1, 36).unwrap().to_be_bytes()
+            );
+            assert_eq!(
+                (
+                    (
+                        from_fields.timestamp(),
+                        from_fields.counter_hi(),
+                        from_fields.counter_lo(),
+                        from_fields.entropy(),
+                    ),
+                    &from_fields.encode() as &str
+                ),
+                (e.0, e.1.to_lowercase().as_str())
+            );
+            assert_eq!(
+                (
+                    (
+                        from_string.timestamp(),
+                        from_string.counter_hi(),
+                        from_string.counter_lo(),
+                        from_string.entropy(),
+                    ),
+                    &from_string.encode() as &str
+                ),
+                (e.0, e.1.to_lowercase().as_str())
+            );
+            #[cfg(feature = "std")]
+            assert_eq!(from_fields.to_string(), e.1.to_lowercase());
+            #[cfg(feature = "std")]
+            assert_eq!(from_string.to_string(), e.1.to_lowercase());
+        }
+    }
+
+    /// Creates an `InvalidLength` variant for the canonical string representation.
+    fn invalid_length(n_bytes: usize) -> super::ParseErrorRepr {
+        super::ParseErrorRepr::InvalidLength {
+            n_bytes,
+            expected: "25",
+        }
+    }
+
+    /// Creates an `InvalidDigit` variant from a character.
+    fn invalid_digit(c: char, position: usize) -> super::ParseErrorRepr {
+        let mut utf8_char = [0u8; 4];
+        c.encode_utf8(&mut utf8_char);
+        super::ParseErrorRepr::InvalidDigit {
+            utf8_char,
+            position,
+        }
+    }
+
+    /// Returns error if an invalid string representation is supplied
+    #[test]
+    fn returns_error_if_an_invalid_string_representation_is_supplied() {
+        use super::ParseErrorRepr::*;
+
+        let cases = [
+            ("", invalid_length(0)),
+            (" 036z8puq4tsxsigk6o19y164q", invalid_length(26)),
+            ("036z8puq54qny1vq3hcbrkweb ", invalid_length(26)),
+            (" 036z8puq54qny1vq3helivwax ", invalid_length(27)),
+            ("+036z8puq54qny1vq3hfcv3ss0", invalid_length(26)),
+            ("-036z8puq54qny1vq3hhy8u1ch", invalid_length(26)),
+            ("+36z8puq54qny1vq3hjq48d9p", invalid_digit('+', 0)),
+            ("-36z8puq5a7j0ti08oz6zdrdy", invalid_digit('-', 0)),
+            ("036z8puq5a7j0t_08p2cdz28v", invalid_digit('_', 14)),
+            ("036z8pu-5a7j0ti08p3ol8ool", invalid_digit('-', 7)),
+            ("036z8puq5a7j0ti08p4j 6cya", invalid_digit(' ', 20)),
+            ("f5lxx1zz5pnorynqglhzmsp34", OutOfU128Range),
+            ("zzzzzzzzzzzzzzzzzzzzzzzzz", OutOfU128Range),
+            ("039o\tvvklfmqlqe7fzllz7c7t", invalid_digit('\t', 4)),
+            ("039onvvklfmqlq漢字fgvd1", invalid_digit('漢', 14)),
+            ("039onvvkl🤣qe7fzr2hdoqu", invalid_digit('🤣', 9)),
+            ("頭onvvklfmqlqe7fzrhtgcfz", invalid_digit('頭', 0)),
+            ("039onvvklfmqlqe7fztft5尾", invalid_digit('尾', 22)),
+            ("039漢字a52xp4bvf4sn94e09cja", invalid_length(29)),
+            ("039ooa52xp4bv😘sn97642mwl", invalid_length(27)),
+        ];
+
+        for e in cases {
+            let result = e.0.parse::<Scru128Id>();
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err().repr, e.1);
+        }
+    }
+
+    /// Reports category of parse error
+    #[test]
+    fn reports_category_of_parse_error() {
+        use super::ParseErrorKind::*;
+
+        let cases = [
+            (Scru128Id::try_from_str(""), InvalidLength),
+            (
+                Scru128Id::try_from_str("036z8puq4tsxsigk6o19y164"),
+                InvalidLength,
+            ),
+            (
+                Scru128Id::try_from_str("036z8puq4tsxsigk6o19y164q0"),
+                InvalidLength,
+            ),
+            (
+                Scru128Id::try_from_str("039onvvklfmqlq漢字fgvd1"),
+                InvalidDigit,
+            ),
+            (
+                Scru128Id::try_from_str("036z8puq5a7j0t_08p2cdz28v"),
+                InvalidDigit,
+            ),
+            (
+                Scru128Id::try_from_str("f5lxx1zz5pnorynqglhzmsp34"),
+                OutOfU128Range,
+            ),
+            (
+                Scru128Id::try_from_bytes_str(b"\xff36z8puq54qny1vq3hjq48d9p"),
+                InvalidDigit,
+            ),
+            (
+                Scru128Id::from_braced_hex("{017fa1de51a80fd992f9e8cc2d5eb88e"),
+                InvalidLength,
+            ),
+            (
+                Scru128Id::from_braced_hex("017fa1de51a80fd992f9e8cc2d5eb88g"),
+                InvalidDigit,
+            ),
+            (Scru128Id::from_str_auto("0x"), InvalidLength),
+            (Scru128Id::from_str_auto("0xg"), InvalidDigit),
+            (
+                Scru128Id::from_base32_crockford("80000000000000000000000000"),
+                OutOfU128Range,
+            ),
+            (
+                Scru128Id::from_text_parts("036z8puq4tsxs", "igk6o19y164"),
+                InvalidLength,
+            ),
+            (
+                Scru128Id::from_etag("W/'036z8puq4tsxsigk6o19y164q'"),
+                InvalidDigit,
+            ),
+        ];
+        for (result, kind) in cases {
+            assert_eq!(result.unwrap_err().kind(), kind);
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let messages = [
+                (
+                    "036z8puq4tsxsigk6o19y164",
+                    "could not parse string as SCRU128 ID: invalid length: 24 bytes (expected 25)",
+                ),
+                (
+                    "036z8puq5a7j0t_08p2cdz28v",
+                    "could not parse string as SCRU128 ID: invalid digit '_' at 14",
+                ),
+                (
+                    "f5lxx1zz5pnorynqglhzmsp34",
+                    "could not parse string as SCRU128 ID: out of 128-bit value range",
+                ),
+            ];
+            for (input, message) in messages {
+                assert_eq!(input.parse::<Scru128Id>().unwrap_err().to_string(), message);
+            }
+        }
+    }
+
+    /// Parses all items or reports first invalid one
+    #[cfg(feature = "std")]
+    #[test]
+    fn parses_all_items_or_reports_first_invalid_one() {
+        use super::ParseErrorKind;
+
+        let mut g = Scru128Generator::new();
+        let ids: Vec<_> = (0..100).map(|_| g.generate()).collect();
+        let dump: String = ids.iter().map(|e| format!("{}\n", e)).collect();
+        assert_eq!(super::parse_all(dump.lines()), Ok(ids.clone()));
+        assert_eq!(super::parse_all(dump.lines().map(String::from)), Ok(ids));
+        assert_eq!(super::parse_all(Vec::<&str>::new()), Ok(Vec::new()));
+
+        let items = [
+            "036z8puq4tsxsigk6o19y164q",
+            "036z8puq54qny1vq3hcbrkweb",
+            "036z8puq5a7j0t_08p2cdz28v",
+            "036z8puq54qny1vq3helivwax",
+            "f5lxx1zz5pnorynqglhzmsp34",
+        ];
+        let err = super::parse_all(items).unwrap_err();
+        assert_eq!(err.index(), Some(2));
+        assert_eq!(err.kind(), ParseErrorKind::InvalidDigit);
+        assert_eq!(
+            err.to_string(),
+            "could not parse item 2 as SCRU128 ID: invalid digit '_' at 14"
+        );
+
+        let err = items[2].parse::<Scru128Id>().unwrap_err();
+        assert_eq!(err.index(), None);
+        assert_eq!(
+            err.to_string(),
+            "could not parse string as SCRU128 ID: invalid digit '_' at 14"
+        );
+    }
+
+    /// Deduplicates parse errors in hash set
+    #[cfg(feature = "std")]
+    #[test]
+    fn deduplicates_parse_errors_in_hash_set() {
+        use std::collections::HashSet;
+
+        let inputs = [
+            "036z8puq4tsxsigk6o19y164",
+            "036z8puq4tsxsigk6o19y164",
+            "036z8puq4tsxsigk6o19y16",
+            "036z8puq5a7j0t_08p2cdz28v",
+            "036z8puq5a7j0t_08p2cdz28v",
+            "036z8puq5a7j0t-08p2cdz28v",
+            "036z8puq5a7j0_i08p2cdz28v",
+            "f5lxx1zz5pnorynqglhzmsp34",
+            "zzzzzzzzzzzzzzzzzzzzzzzzz",
+        ];
+        let errors: Vec<_> = inputs
+            .iter()
+            .map(|e| e.parse::<Scru128Id>().unwrap_err())
+            .collect();
+        let set: HashSet<_> = errors.iter().cloned().collect();
+        assert_eq!(set.len(), 6);
+        for e in &errors {
+            assert!(set.contains(e));
+        }
+
+        let kinds: HashSet<_> = set.iter().map(|e| e.kind()).collect();
+        assert_eq!(kinds.len(), 3);
+    }
+
+    /// Parses string representation surrounded by whitespace
+    #[test]
+    fn parses_string_representation_surrounded_by_whitespace() {
+        use super::ParseErrorRepr::*;
+
+        let x = "036z8puq4tsxsigk6o19y164q".parse::<Scru128Id>().unwrap();
+        let cases = [
+            "036z8puq4tsxsigk6o19y164q",
+            " 036z8puq4tsxsigk6o19y164q",
+            "036z8puq4tsxsigk6o19y164q ",
+            "   036z8puq4tsxsigk6o19y164q   ",
+            "\t036z8puq4tsxsigk6o19y164q\t",
+            "\r\n036Z8PUQ4TSXSIGK6O19Y164Q\r\n",
+            " \t\x0c036z8puq4tsxsigk6o19y164q\n",
+        ];
+        for e in cases {
+            assert_eq!(Scru128Id::parse_trimmed(e), Ok(x));
+        }
+
+        let cases = [
+            ("", invalid_length(0)),
+            ("   ", invalid_length(0)),
+            (" 036z8puq4tsxsigk6o19y164 ", invalid_length(24)),
+            (" 036z8puq4tsxsigk6o19y164q0 ", invalid_length(26)),
+            ("036z8puq4tsx sigk6o19y164", invalid_digit(' ', 12)),
+            ("  036z8puq4tsx\tigk6o19y164q ", invalid_digit('\t', 14)),
+            ("\u{a0}036z8puq4tsxsigk6o19y16", invalid_digit('\u{a0}', 0)),
+            (" \"036z8puq4tsxsigk6o19y16\" ", invalid_digit('"', 1)),
+            ("\t036z8puq4tsxsigk6o19y1漢 ", invalid_digit('漢', 23)),
+            (" f5lxx1zz5pnorynqglhzmsp34 ", OutOfU128Range),
+        ];
+        for (e, repr) in cases {
+            assert_eq!(Scru128Id::parse_trimmed(e).unwrap_err().repr, repr);
+        }
+    }
+
+    /// Returns error if an invalid byte string representation is supplied
+    #[test]
+    fn returns_error_if_an_invalid_byte_string_representation_is_supplied() {
+        use super::ParseErrorRepr::*;
+
+        let cases: [(&[u8], _); 12] = [
+            (b"", invalid_length(0)),
+            (b" 036z8puq4tsxsigk6o19y164q", invalid_length(26)),
+            (b"+036z8puq54qny1vq3hfcv3ss0", invalid_length(26)),
+            (b"+36z8puq54qny1vq3hjq48d9p", invalid_digit('+', 0)),
+            (b"036z8puq5a7j0t_08p2cdz28v", invalid_digit('_', 14)),
+            (b"036z8puq5a7j0ti08p4j 6cya", invalid_digit(' ', 20)),
+            (b"f5lxx1zz5pnorynqglhzmsp34", OutOfU128Range),
+            (b"039o\tvvklfmqlqe7fzllz7c7t", invalid_digit('\t', 4)),
+            (
+                "039onvvklfmqlq漢字fgvd1".as_bytes(),
+                invalid_digit('漢', 14),
+            ),
+            (
+                "頭onvvklfmqlqe7fzrhtgcfz".as_bytes(),
+                invalid_digit('頭', 0),
+            ),
+            (
+                b"039onvvklfmqlq\xff\xfe\x80fgvd1abc",
+                invalid_digit('\u{fffd}', 14),
+            ),
+            (
+                b"\x80\x80\x80onvvklfmqlqe7fzrhtgcfz",
+                invalid_digit('\u{fffd}', 0),
+            ),
+        ];
+
+        for e in cases {
+            let result = Scru128Id::try_from_bytes_str(e.0);
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err().repr, e.1);
+            if let Ok(s) = core::str::from_utf8(e.0) {
+                assert_eq!(
+                    Scru128Id::try_from_bytes_str(e.0),
+                    Scru128Id::try_from_str(s)
+                );
+            }
+        }
+
+        #[cfg(feature = "std")]
+        assert_eq!(
+            Scru128Id::try_from_bytes_str(b"\xff36z8puq54qny1vq3hjq48d9p")
+                .unwrap_err()
+                .to_string(),
+            "could not parse string as SCRU128 ID: invalid digit '\u{fffd}' at 0"
+        );
+    }
+
+    /// Encodes same digits as fixed-iteration long division
+    ///
+    /// The encoder skips the digits known to be zero to finish small values early. This test
+    /// compares it with a straightforward reference that always runs the full long division.
+    #[test]
+    fn encodes_same_digits_as_fixed_iteration_long_division() {
+        let mut cases = [Scru128Id::MIN; 512];
+        let mut state = 0x0123_4567_89ab_cdef_u64;
+        for (i, e) in cases.iter_mut().enumerate() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            *e = match i % 8 {
+                0 => Scru128Id::from_u128(i as u128),
+                1 => Scru128Id::from_u128(u128::MAX - i as u128),
+                2 => Scru128Id::from_u128(1 << (i % 128)),
+                3 => Scru128Id::from_u128((1 << (i % 128)) - 1),
+                4 => Scru128Id::from_timestamp_lower_bound(state >> 16),
+                5 => Scru128Id::from_u128(state as u128),
+                _ => Scru128Id::from_u128(((state as u128) << 64) | state.rotate_left(7) as u128),
+            };
+        }
+
+        for e in cases {
+            assert_eq!(e.encode(), e.encode_fixed_iteration());
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let mut g = Scru128Generator::new();
+            for _ in 0..1000 {
+                let e = g.generate();
+                assert_eq!(e.encode(), e.encode_fixed_iteration());
+            }
+        }
+    }
+
+    /// Creates ID from timestamp and 10 bytes of random material
+    #[test]
+    fn creates_id_from_timestamp_and_10_bytes_of_random_material() {
+        let cases = [
+            (1, [0u8; 10]),
+            (MAX_UINT48, [0xff; 10]),
+            (
+                0,
+                [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a],
+            ),
+            (0x0123_4567_89ab, [0x80, 0, 0, 0x80, 0, 0, 0x80, 0, 0, 1]),
+        ];
+        for (ts, rand10) in cases {
+            let x = Scru128Id::from_timestamp_and_bytes(ts, rand10).unwrap();
+            assert_eq!(x.timestamp(), ts);
+            assert_eq!(&x.as_bytes()[6..], &rand10);
+            assert_eq!(
+                x.counter_hi(),
+                u32::from_be_bytes([0, rand10[0], rand10[1], rand10[2]])
+            );
+            assert_eq!(
+                x.counter_lo(),
+                u32::from_be_bytes([0, rand10[3], rand10[4], rand10[5]])
+            );
+            assert_eq!(
+                x.entropy(),
+                u32::from_be_bytes([rand10[6], rand10[7], rand10[8], rand10[9]])
+            );
+        }
+
+        for ts in [MAX_UINT48 + 1, u64::MAX] {
+            let err = Scru128Id::from_timestamp_and_bytes(ts, [0; 10]).unwrap_err();
+            assert_eq!(err.field(), "timestamp");
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let err = Scru128Id::from_timestamp_and_bytes(1 << 48, [0; 10]).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "invalid field value: `timestamp` out of range"
+            );
+
+            let mut g = Scru128Generator::new();
+            for _ in 0..1000 {
+                let e = g.generate();
+                let rand10 = e.as_bytes()[6..].try_into().unwrap();
+                assert_eq!(
+                    Scru128Id::from_timestamp_and_bytes(e.timestamp(), rand10),
+                    Ok(e)
+                );
+            }
+        }
+    }
+
+    /// Combines counter_hi and counter_lo into 48-bit counter
+    #[test]
+    fn combines_counter_hi_and_counter_lo_into_48_bit_counter() {
+        let cases = [
+            (0, 0, 0, 0),
+            (MAX_UINT48, MAX_UINT24, MAX_UINT24, u32::MAX),
+            (MAX_UINT48, 0, 0, u32::MAX),
+            (0, MAX_UINT24, 0, 0),
+            (0, 0, MAX_UINT24, 0),
+            (0, 1, 0, 0),
+            (0, 0, 1, 0),
+            (1, 0x12_3456, 0x78_9abc, 0xdef0_1234),
+        ];
+        for (a, b, c, d) in cases {
+            let e = Scru128Id::from_fields(a, b, c, d);
+            assert_eq!(e.counter(), ((b as u64) << 24) | c as u64);
+            assert!(e.counter() <= MAX_UINT48);
+        }
+        assert_eq!(Scru128Id::MAX.counter(), MAX_UINT48);
+        assert_eq!(Scru128Id::MIN.counter(), 0);
+
+        #[cfg(feature = "std")]
+        {
+            let mut g = Scru128Generator::new();
+            let mut prev = g.generate_or_abort_core(0x0123_4567_89ab, 10_000).unwrap();
+            for _ in 0..1000 {
+                let curr = g.generate_or_abort_core(0x0123_4567_89ab, 10_000).unwrap();
+                assert_eq!(
+                    curr.counter(),
+                    ((curr.counter_hi() as u64) << 24) | curr.counter_lo() as u64
+                );
+                if curr.timestamp() == prev.timestamp() {
+                    assert_eq!(curr.counter(), prev.counter() + 1);
+                }
+                prev = curr;
+            }
+        }
+    }
+
+    /// Returns all fields in from_fields argument order
+    #[test]
+    fn returns_all_fields_in_from_fields_argument_order() {
+        let cases = [
+            (0, 0, 0, 0),
+            (MAX_UINT48, 0, 0, 0),
+            (0, MAX_UINT24, 0, 0),
+            (0, 0, MAX_UINT24, 0),
+            (0, 0, 0, u32::MAX),
+            (MAX_UINT48, MAX_UINT24, MAX_UINT24, u32::MAX),
+            (1, 2, 3, 4),
+            (0x017f_a1de_51a8, 0x0f_d992, 0xf9_e8cc, 0x2d5e_b88e),
+        ];
+        for (a, b, c, d) in cases {
+            let e = Scru128Id::from_fields(a, b, c, d);
+            assert_eq!(e.fields(), (a, b, c, d));
+            assert_eq!(
+                e.fields(),
+                (e.timestamp(), e.counter_hi(), e.counter_lo(), e.entropy())
+            );
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let mut g = Scru128Generator::new();
+            for _ in 0..1000 {
+                let e = g.generate();
+                let (a, b, c, d) = e.fields();
+                assert_eq!(Scru128Id::from_fields(a, b, c, d), e);
+            }
+        }
+    }
+
+    /// Has symmetric converters from/to various values
+    #[test]
+    fn has_symmetric_converters_from_to_various_values() {
+        let cases = [
+            Scru128Id::from_fields(0, 0, 0, 0),
+            Scru128Id::from_fields(MAX_UINT48, 0, 0, 0),
+            Scru128Id::from_fields(0, MAX_UINT24, 0, 0),
+            Scru128Id::from_fields(0, 0, MAX_UINT24, 0),
+            Scru128Id::from_fields(0, 0, 0, MAX_UINT32),
+            Scru128Id::from_fields(MAX_UINT48, MAX_UINT24, MAX_UINT24, MAX_UINT32),
+        ];
+
+        #[cfg(feature = "std")]
+        let cases = {
+            let mut v = cases.to_vec();
+            let mut g = Scru128Generator::new();
+            for _ in 0..1000 {
+                v.push(g.generate());
+            }
+            v
+        };
+
+        for e in cases {
+            assert_eq!(Scru128Id::try_from_str(&e.encode()), Ok(e));
+            assert_eq!(e.encode_lower(), e.encode());
+            assert_eq!(Scru128Id::try_from_str(&e.encode_lower()), Ok(e));
+            assert_eq!(Scru128Id::try_from_str(&e.encode_upper()), Ok(e));
+            assert!(e.encode_upper().chars().all(|c| !c.is_ascii_lowercase()));
+            assert!(e.encode_upper().eq_ignore_ascii_case(&e.encode()));
+            assert_eq!(e.encode().parse::<Scru128Id>(), Ok(e));
+            #[cfg(feature = "std")]
+            assert_eq!(e.to_string().parse::<Scru128Id>(), Ok(e));
+            #[cfg(feature = "std")]
+            assert_eq!(Scru128Id::try_from(String::from(e)), Ok(e));
+            assert_eq!(Scru128Id::from_u128(e.to_u128()), e);
+            assert_eq!(Scru128Id::from(u128::from(e)), e);
+            assert_eq!(Scru128Id::from_bytes(e.to_bytes()), e);
+            assert_eq!(Scru128Id::from(<[u8; 16]>::from(e)), e);
+            assert_eq!(Scru128Id::from_bytes(*e.as_bytes()), e);
+            assert_eq!(
+                Scru128Id::from_fields(e.timestamp(), e.counter_hi(), e.counter_lo(), e.entropy()),
+                e
+            );
+        }
+    }
+
+    /// Converts to and from braced hexadecimal string
+    #[test]
+    fn converts_to_and_from_braced_hexadecimal_string() {
+        use super::ParseErrorRepr::*;
+
+        let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+        #[cfg(feature = "std")]
+        assert_eq!(x.to_braced_hex(), "{017fa1de-51a8-0fd9-92f9-e8cc2d5eb88e}");
+
+        let cases = [
+            "017fa1de51a80fd992f9e8cc2d5eb88e",
+            "017fa1de-51a8-0fd9-92f9-e8cc2d5eb88e",
+            "{017fa1de51a80fd992f9e8cc2d5eb88e}",
+            "{017fa1de-51a8-0fd9-92f9-e8cc2d5eb88e}",
+            "{017FA1DE-51A8-0FD9-92F9-E8CC2D5EB88E}",
+        ];
+        for e in cases {
+            assert_eq!(Scru128Id::from_braced_hex(e), Ok(x));
+        }
+
+        let invalid_cases = [
+            "017fa1de51a80fd992f9e8cc2d5eb88",
+            "{017fa1de-51a8-0fd9-92f9-e8cc2d5eb88e",
+            "{017fa1de-51a8-0fd9-92f9-e8cc2d5eb88e]",
+            "[017fa1de-51a8-0fd9-92f9-e8cc2d5eb88e}",
+            "017fa1de5-1a8-0fd9-92f9-e8cc2d5eb88e",
+            "017fa1de-51a8-0fd9-92f9-e8cc2d5eb88g",
+            "{017fa1de51a80fd992f9e8cc2d5eb8漢}",
+        ];
+        for e in invalid_cases {
+            let err = Scru128Id::from_braced_hex(e).unwrap_err();
+            assert!(matches!(
+                err.repr,
+                InvalidLength { .. } | InvalidDigit { .. }
+            ));
+        }
+
+        let cases = [
+            (
+                Scru128Id::from_u128(0),
+                "{00000000-0000-0000-0000-000000000000}",
+            ),
+            (
+                Scru128Id::from_u128(u128::MAX),
+                "{ffffffff-ffff-ffff-ffff-ffffffffffff}",
+            ),
+            (
+                Scru128Id::from_fields(MAX_UINT48, 0, MAX_UINT24, 0),
+                "{ffffffff-ffff-0000-00ff-ffff00000000}",
+            ),
+        ];
+        for e in cases {
+            assert_eq!(Scru128Id::from_braced_hex(e.1), Ok(e.0));
+            #[cfg(feature = "std")]
+            assert_eq!(e.0.to_braced_hex(), e.1);
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let mut g = Scru128Generator::new();
+            for _ in 0..1000 {
+                let e = g.generate();
+                let braced = e.to_braced_hex();
+                assert_eq!(Scru128Id::from_braced_hex(&braced), Ok(e));
+                assert_eq!(Scru128Id::from_braced_hex(&braced.replace('-', "")), Ok(e));
+                assert_eq!(Scru128Id::from_braced_hex(&braced[1..37]), Ok(e));
+                assert_eq!(
+                    Scru128Id::from_braced_hex(&format!("{:032x}", e.to_u128())),
+                    Ok(e)
+                );
+            }
+        }
+    }
+
+    /// Converts to and from borrowed and owned Cow strings
+    #[cfg(feature = "std")]
+    #[test]
+    fn converts_to_and_from_borrowed_and_owned_cow_strings() {
+        use std::borrow::Cow;
+
+        let text = "037d0xye6op48cmce8ey4xlcf";
+        let x = text.parse::<Scru128Id>().unwrap();
+        assert!(matches!(x.to_cow(), Cow::Owned(_)));
+        assert_eq!(x.to_cow(), text);
+
+        assert_eq!(Scru128Id::try_from(Cow::Borrowed(text)), Ok(x));
+        assert_eq!(Scru128Id::try_from(Cow::<str>::Owned(text.into())), Ok(x));
+        assert_eq!(
+            Scru128Id::try_from(Cow::Borrowed("037D0XYE6OP48CMCE8EY4XLCF")),
+            Ok(x)
+        );
+        assert_eq!(
+            Scru128Id::try_from(Cow::Borrowed("037d0xye6op48cmce8ey4xlc")),
+            Err(super::ParseError {
+                repr: invalid_length(24),
+                index: None
+            })
+        );
+        assert_eq!(
+            Scru128Id::try_from(Cow::<str>::Owned("037d0xye6op48cmce8ey4xlc-".into()))
+                .unwrap_err()
+                .repr,
+            invalid_digit('-', 24)
+        );
+
+        let mut g = Scru128Generator::new();
+        for _ in 0..1000 {
+            let e = g.generate();
+            let cow = e.to_cow();
+            assert_eq!(cow, e.to_string());
+            assert_eq!(Scru128Id::try_from(Cow::Borrowed(&*cow)), Ok(e));
+            assert_eq!(Scru128Id::try_from(cow), Ok(e));
+        }
+    }
+
+    /// Converts to and from strong and weak ETags
+    #[test]
+    fn converts_to_and_from_strong_and_weak_etags() {
+        use super::ParseErrorRepr::*;
+
+        let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>().unwrap();
+        let cases = [
+            "\"037d0xye6op48cmce8ey4xlcf\"",
+            "W/\"037d0xye6op48cmce8ey4xlcf\"",
+            "\"037D0XYE6OP48CMCE8EY4XLCF\"",
+            "W/\"037D0XYE6OP48CMCE8EY4XLCF\"",
+        ];
+        for e in cases {
+            assert_eq!(Scru128Id::from_etag(e), Ok(x));
+        }
+
+        let invalid_cases = [
+            (
+                "",
+                InvalidLength {
+                    n_bytes: 0,
+                    expected: "27, or 29 if weak",
+                },
+            ),
+            (
+                "037d0xye6op48cmce8ey4xlcf",
+                InvalidLength {
+                    n_bytes: 25,
+                    expected: "27, or 29 if weak",
+                },
+            ),
+            (
+                "\"037d0xye6op48cmce8ey4xlcf\" ",
+                InvalidLength {
+                    n_bytes: 28,
+                    expected: "27, or 29 if weak",
+                },
+            ),
+            ("'037d0xye6op48cmce8ey4xlcf'", invalid_digit('\'', 0)),
+            ("\"037d0xye6op48cmce8ey4xlcf'", invalid_digit('\'', 26)),
+            ("w/\"037d0xye6op48cmce8ey4xlcf\"", invalid_digit('w', 0)),
+            ("W-\"037d0xye6op48cmce8ey4xlcf\"", invalid_digit('-', 1)),
+            ("W/ 037d0xye6op48cmce8ey4xlcf\"", invalid_digit(' ', 2)),
+            ("\"037d0xye6op48cmce8ey4xlc-\"", invalid_digit('-', 25)),
+            ("W/\"037d0xye6op48cmce8ey4xlc-\"", invalid_digit('-', 27)),
+            ("\"037d0xye6op48cmce8ey4xl漢", invalid_digit('漢', 24)),
+            ("\"f5lxx1zz5pnorynqglhzmsp34\"", OutOfU128Range),
+        ];
+        for (e, kind) in invalid_cases {
+            assert_eq!(Scru128Id::from_etag(e).unwrap_err().repr, kind);
+        }
+
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(x.to_etag(false), cases[0]);
+            assert_eq!(x.to_etag(true), cases[1]);
+
+            let mut g = Scru128Generator::new();
+            for _ in 0..1000 {
+                let e = g.generate();
+                assert_eq!(Scru128Id::from_etag(&e.to_etag(false)), Ok(e));
+                assert_eq!(Scru128Id::from_etag(&e.to_etag(true)), Ok(e));
+            }
+        }
+    }
+
+    /// Detects hexadecimal and Base36 forms from input
+    #[test]
+    fn detects_hexadecimal_and_base36_forms_from_input() {
+        use super::ParseErrorRepr::*;
+
+        let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+        assert_eq!(
+            Scru128Id::from_str_auto("0x017fa1de51a80fd992f9e8cc2d5eb88e"),
+            Ok(x)
+        );
+        assert_eq!(
+            Scru128Id::from_str_auto("0X17FA1DE51A80FD992F9E8CC2D5EB88E"),
+            Ok(x)
+        );
+        assert_eq!(Scru128Id::from_str_auto("0x0"), Ok(Scru128Id::from_u128(0)));
+        assert_eq!(
+            Scru128Id::from_str_auto("0xffffffffffffffffffffffffffffffff"),
+            Ok(Scru128Id::from_u128(u128::MAX))
+        );
+
+        let y = Scru128Id::try_from_str("036z8puq4tsxsigk6o19y164q").unwrap();
+        assert_eq!(Scru128Id::from_str_auto("036z8puq4tsxsigk6o19y164q"), Ok(y));
+        assert_eq!(Scru128Id::from_str_auto("036Z8PUQ4TSXSIGK6O19Y164Q"), Ok(y));
+
+        // valid Base36 but interpreted as hexadecimal due to prefix
+        let z = "0x1234567890abcdef1234567";
+        assert!(Scru128Id::try_from_str(z).is_ok());
+        assert_eq!(
+            Scru128Id::from_str_auto(z),
+            Ok(Scru128Id::from_u128(0x1234567890abcdef1234567))
+        );
+        assert_ne!(Scru128Id::from_str_auto(z), Scru128Id::try_from_str(z));
+
+        let invalid_cases = [
+            "",
+            "0x",
+            "0x017fa1de51a80fd992f9e8cc2d5eb88e0",
+            "0x017fa1de51a80fd992f9e8cc2d5eb88g",
+            "0x036z8puq4tsxsigk6o19y16",
+            "+0x017fa1de51a80fd992f9e8cc2d5eb88e",
+            "0x+17fa1de51a80fd992f9e8cc2d5eb88e",
+            "017fa1de51a80fd992f9e8cc2d5eb88e",
+            "{017fa1de-51a8-0fd9-92f9-e8cc2d5eb88e}",
+            "036z8puq4tsxsigk6o19y164",
+            "zzzzzzzzzzzzzzzzzzzzzzzzz",
+        ];
+        for e in invalid_cases {
+            let err = Scru128Id::from_str_auto(e).unwrap_err();
+            assert!(matches!(
+                err.repr,
+                InvalidLength { .. } | InvalidDigit { .. } | OutOfU128Range
+            ));
+        }
+    }
+
+    /// Converts timestamp to and from SystemTime
+    #[cfg(feature = "std")]
+    #[test]
+    fn converts_timestamp_to_and_from_system_time() {
+        use std::time::{Duration, UNIX_EPOCH};
+
+        let mut cases = vec![
+            Scru128Id::from_fields(0, 0, 0, 0),
+            Scru128Id::from_fields(1, MAX_UINT24, 0, MAX_UINT32),
+            Scru128Id::from_fields(MAX_UINT48, MAX_UINT24, MAX_UINT24, MAX_UINT32),
+        ];
+        let mut g = Scru128Generator::new();
+        for _ in 0..1000 {
+            cases.push(g.generate());
+        }
+
+        for e in cases {
+            let time = e.timestamp_as_system_time();
+            assert_eq!(time, UNIX_EPOCH + Duration::from_millis(e.timestamp()));
+            assert_eq!(
+                Scru128Id::from_system_time_and_fields(
+                    time,
+                    e.counter_hi(),
+                    e.counter_lo(),
+                    e.entropy()
+                ),
+                e
+            );
+        }
+
+        // truncates sub-millisecond part
+        let time = UNIX_EPOCH + Duration::new(1_234_567_890, 123_456_789);
+        let x = Scru128Id::from_system_time_and_fields(time, 0, 0, 0);
+        assert_eq!(x.timestamp(), 1_234_567_890_123);
+
+        let before_epoch = UNIX_EPOCH - Duration::from_millis(1);
+        assert!(std::panic::catch_unwind(|| {
+            Scru128Id::from_system_time_and_fields(before_epoch, 0, 0, 0)
+        })
+        .is_err());
+        let beyond_max = UNIX_EPOCH + Duration::from_millis(MAX_UINT48 + 1);
+        assert!(std::panic::catch_unwind(|| {
+            Scru128Id::from_system_time_and_fields(beyond_max, 0, 0, 0)
+        })
+        .is_err());
+    }
+
+    /// Bounds IDs generated at given timestamp
+    #[test]
+    fn bounds_ids_generated_at_given_timestamp() {
+        let cases = [1, 0x0123_4567_89ab, MAX_UINT48];
+        for ts in cases {
+            let lower = Scru128Id::from_timestamp_lower_bound(ts);
+            let upper = Scru128Id::from_timestamp_upper_bound(ts);
+            assert_eq!(lower.to_u128(), (ts as u128) << 80);
+            assert_eq!(upper.to_u128(), ((ts as u128) << 80) | ((1 << 80) - 1));
+            assert_eq!(lower.timestamp(), ts);
+            assert_eq!(upper.timestamp(), ts);
+            if ts < MAX_UINT48 {
+                assert!(upper < Scru128Id::from_timestamp_lower_bound(ts + 1));
+            }
+
+            let mut g = crate::Scru128Generator::new();
+            for _ in 0..1000 {
+                let e = g.generate_or_reset_core(ts, 10_000);
+                if e.timestamp() == ts {
+                    assert!(lower <= e && e <= upper);
+                } else {
+                    assert!(upper < e);
+                }
+            }
+        }
+
+        assert_eq!(Scru128Id::from_timestamp_lower_bound(0), Scru128Id::MIN);
+        assert_eq!(
+            Scru128Id::from_timestamp_upper_bound(MAX_UINT48),
+            Scru128Id::MAX
+        );
+    }
+
+    /// Bounds IDs within given time window
+    #[test]
+    fn bounds_ids_within_given_time_window() {
+        let cases = [
+            (1, 1),
+            (0x0123_4567_89ab, 0x0123_4567_89ab),
+            (0x0123_4567_89ab, 0x0123_4567_89ab + 60_000),
+            (0, MAX_UINT48),
+        ];
+        for (start, end) in cases {
+            let (lower, upper) = Scru128Id::timestamp_range_bounds(start, end);
+            assert_eq!(lower, Scru128Id::from_timestamp_lower_bound(start));
+            assert_eq!(upper, Scru128Id::from_timestamp_upper_bound(end));
+
+            for ts in [start, (start + end) / 2, end] {
+                for e in [
+                    Scru128Id::from_fields(ts, 0, 0, 0),
+                    Scru128Id::from_fields(ts, 42, 42, 42),
+                    Scru128Id::from_fields(ts, MAX_UINT24, MAX_UINT24, u32::MAX),
+                ] {
+                    assert!(lower <= e && e <= upper);
+                }
+            }
+            if start > 0 {
+                let e = Scru128Id::from_fields(start - 1, MAX_UINT24, MAX_UINT24, u32::MAX);
+                assert!(e < lower);
+            }
+            if end < MAX_UINT48 {
+                assert!(upper < Scru128Id::from_fields(end + 1, 0, 0, 0));
+            }
+        }
+
+        assert_eq!(
+            Scru128Id::timestamp_range_bounds(0, MAX_UINT48),
+            (Scru128Id::MIN, Scru128Id::MAX)
+        );
+    }
+
+    /// Rejects invalid time window
+    #[cfg(feature = "std")]
+    #[test]
+    fn rejects_invalid_time_window() {
+        use std::panic::catch_unwind;
+        assert!(catch_unwind(|| Scru128Id::timestamp_range_bounds(2, 1)).is_err());
+        assert!(catch_unwind(|| Scru128Id::timestamp_range_bounds(0, MAX_UINT48 + 1)).is_err());
+        assert!(
+            catch_unwind(|| Scru128Id::timestamp_range_bounds(MAX_UINT48 + 1, MAX_UINT48 + 1))
+                .is_err()
+        );
+    }
+
+    /// Compares with encoded forms by canonical text
+    #[test]
+    fn compares_with_encoded_forms_by_canonical_text() {
+        let text = "036z8puq4tsxsigk6o19y164q";
+        let x = text.parse::<Scru128Id>().unwrap();
+        let encoded = x.encode();
+
+        assert!(x == encoded);
+        assert!(encoded == x);
+        assert!(x == text);
+        assert!(text == x);
+        assert!(x == *text);
+        assert!(*text == x);
+        assert!(x == "036Z8PUQ4TSXSIGK6O19Y164Q");
+        assert!(x <= encoded);
+        assert!(x >= encoded);
+
+        let prev = Scru128Id::from_u128(x.to_u128() - 1);
+        let next = Scru128Id::from_u128(x.to_u128() + 1);
+        assert!(prev < encoded);
+        assert!(encoded > prev);
+        assert!(next > encoded);
+        assert!(encoded < next);
+        assert!(prev.encode() < x);
+        assert!(next.encode() > x);
+
+        let cases = [
+            ("036z8puq4tsxsigk6o19y164p", cmp::Ordering::Greater),
+            ("036z8puq4tsxsigk6o19y164r", cmp::Ordering::Less),
+            ("036Z8PUQ4TSXSIGK6O19Y164R", cmp::Ordering::Less),
+            ("036z8puq4tsxsigk6o19y164", cmp::Ordering::Greater),
+            ("036z8puq4tsxsigk6o19y164q0", cmp::Ordering::Less),
+            ("", cmp::Ordering::Greater),
+            ("1", cmp::Ordering::Less),
+        ];
+        for e in cases {
+            assert_eq!(x.partial_cmp(e.0), Some(e.1));
+            assert_eq!(e.0.partial_cmp(&x), Some(e.1.reverse()));
+            assert_eq!(x == e.0, e.1.is_eq());
+
+            #[cfg(feature = "std")]
+            {
+                let owned = String::from(e.0);
+                assert_eq!(x.partial_cmp(&owned), Some(e.1));
+                assert_eq!(owned.partial_cmp(&x), Some(e.1.reverse()));
+                assert_eq!(x == owned, e.1.is_eq());
+                assert_eq!(owned == x, e.1.is_eq());
+            }
+        }
+
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(x, String::from(text));
+            assert_eq!(String::from(text), x);
+            assert_eq!(x, text.to_uppercase());
+            assert_eq!(text.to_uppercase(), x);
+            assert_ne!(x, String::from("036z8puq4tsxsigk6o19y164r"));
+            assert_ne!(x, String::new());
+
+            let mut g = Scru128Generator::new();
+            for _ in 0..1000 {
+                let e = g.generate();
+                assert_eq!(e, e.to_string());
+                assert_eq!(e, e.encode_upper().to_string());
+                assert_ne!(e, x.to_string());
+            }
+        }
+    }
+
+    /// Sorts composite keys by prefix and ID
+    #[cfg(feature = "std")]
+    #[test]
+    fn sorts_composite_keys_by_prefix_and_id() {
+        let mut g = Scru128Generator::new();
+        let prefixes: [&[u8]; 4] = [b"\x00\x00", b"\x00\x01", b"\x7f\xff", b"\xff\xff"];
+
+        let mut pairs = Vec::new();
+        for prefix in prefixes {
+            for _ in 0..100 {
+                pairs.push((prefix, g.generate()));
+            }
+            pairs.push((prefix, Scru128Id::MIN));
+            pairs.push((prefix, Scru128Id::MAX));
+        }
+
+        let mut keys: Vec<Vec<u8>> = pairs.iter().map(|e| e.1.to_composite_key(e.0)).collect();
+        keys.reverse();
+        keys.sort();
+        pairs.sort();
+
+        assert_eq!(keys.len(), pairs.len());
+        for (key, pair) in keys.iter().zip(&pairs) {
+            assert_eq!(Scru128Id::from_composite_key(key), Some((pair.0, pair.1)));
+        }
+
+        assert_eq!(
+            Scru128Id::from_composite_key(Scru128Id::MAX.as_bytes()),
+            Some((&[][..], Scru128Id::MAX))
+        );
+        assert_eq!(Scru128Id::from_composite_key(&[0xff; 15]), None);
+        assert_eq!(Scru128Id::from_composite_key(&[]), None);
+    }
+
+    /// Converts to and from pair of u64 values preserving order
+    #[test]
+    fn converts_to_and_from_pair_of_u64_values_preserving_order() {
+        let cases = [
+            (Scru128Id::MIN, (0, 0)),
+            (Scru128Id::MAX, (u64::MAX, u64::MAX)),
+            (Scru128Id::from_u128(1), (0, 1)),
+            (Scru128Id::from_u128(1 << 64), (1, 0)),
+            (Scru128Id::from_u128(u64::MAX as u128), (0, u64::MAX)),
+            (
+                Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e),
+                (0x017fa1de51a80fd9, 0x92f9e8cc2d5eb88e),
+            ),
+        ];
+        for (e, pair) in cases {
+            assert_eq!(e.to_u64_pair(), pair);
+            assert_eq!(Scru128Id::from_u64_pair(pair.0, pair.1), e);
+        }
+
+        let mut g = crate::Scru128Generator::with_rng(crate::generator::DefaultRng::default());
+        let ts = 0x0123_4567_89abu64;
+        let mut prev = Scru128Id::MIN;
+        for i in 0..10_000 {
+            let curr = g.generate_or_abort_core(ts + i / 100, 10_000).unwrap();
+            let (hi, lo) = curr.to_u64_pair();
+            assert_eq!(Scru128Id::from_u64_pair(hi, lo), curr);
+            assert_eq!(hi, (curr.to_u128() >> 64) as u64);
+            assert!(prev.to_u64_pair() < (hi, lo));
+            prev = curr;
+        }
+
+        // order across low half boundary
+        let x = Scru128Id::from_u64_pair(1, u64::MAX);
+        let y = Scru128Id::from_u64_pair(2, 0);
+        assert!(x < y);
+        assert!(x.to_u64_pair() < y.to_u64_pair());
+    }
+
+    /// Sorts descending keys in reverse order of IDs
+    #[test]
+    fn sorts_descending_keys_in_reverse_order_of_ids() {
+        assert_eq!(Scru128Id::MIN.to_descending_key(), [0xff; 16]);
+        assert_eq!(Scru128Id::MAX.to_descending_key(), [0x00; 16]);
+
+        let mut g = crate::Scru128Generator::with_rng(crate::generator::DefaultRng::default());
+        let ts = 0x0123_4567_89abu64;
+        let mut prev = Scru128Id::MIN;
+        for i in 0..10_000 {
+            let curr = g.generate_or_abort_core(ts + i / 100, 10_000).unwrap();
+            assert!(prev < curr);
+            assert!(prev.to_descending_key() > curr.to_descending_key());
+            assert_eq!(
+                Scru128Id::from_descending_key(curr.to_descending_key()),
+                curr
+            );
+            prev = curr;
+        }
+        assert!(prev.to_descending_key() > Scru128Id::MAX.to_descending_key());
+
+        #[cfg(feature = "std")]
+        {
+            let mut g = Scru128Generator::new();
+            let mut ids: Vec<Scru128Id> = (0..1000).map(|_| g.generate()).collect();
+            ids.push(Scru128Id::MIN);
+            ids.push(Scru128Id::MAX);
+            let mut keys: Vec<[u8; 16]> = ids.iter().map(|e| e.to_descending_key()).collect();
+            keys.sort();
+            ids.sort_by(|a, b| b.cmp(a));
+            for (key, e) in keys.iter().zip(&ids) {
+                assert_eq!(Scru128Id::from_descending_key(*key), *e);
+            }
+        }
+    }
+
+    /// Provides smallest and largest ID values as constants
+    #[test]
+    fn provides_smallest_and_largest_id_values_as_constants() {
+        const RANGE: (Scru128Id, Scru128Id) = (Scru128Id::MIN, Scru128Id::MAX);
+        assert!(RANGE.0 < RANGE.1);
+        assert_eq!(Scru128Id::MIN, Scru128Id::from_fields(0, 0, 0, 0));
+        assert_eq!(
+            Scru128Id::MAX,
+            Scru128Id::from_fields(MAX_UINT48, MAX_UINT24, MAX_UINT24, MAX_UINT32)
+        );
+        assert_eq!(Scru128Id::MIN.encode(), "0000000000000000000000000");
+        assert_eq!(Scru128Id::MAX.encode(), "f5lxx1zz5pnorynqglhzmsp33");
+        #[cfg(feature = "std")]
+        assert_eq!(Scru128Id::MIN.to_string(), "0000000000000000000000000");
+        #[cfg(feature = "std")]
+        assert_eq!(Scru128Id::MAX.to_string(), "f5lxx1zz5pnorynqglhzmsp33");
+    }
+
+    /// Provides lengths of string and byte array representations as constants
+    #[test]
+    fn provides_lengths_of_string_and_byte_array_representations_as_constants() {
+        let x = Scru128Id::from_fields(MAX_UINT48, MAX_UINT24, MAX_UINT24, MAX_UINT32);
+        assert_eq!(x.encode().len(), Scru128Id::STR_LEN);
+        assert_eq!(x.to_bytes().len(), Scru128Id::BYTE_LEN);
+
+        let buffer = [b'0'; Scru128Id::STR_LEN];
+        assert_eq!(
+            Scru128Id::try_from_str(core::str::from_utf8(&buffer).unwrap()),
+            Ok(Scru128Id::from_bytes([0; Scru128Id::BYTE_LEN]))
+        );
+    }
+
+    /// Formats canonical and URN forms with Display
+    #[cfg(feature = "std")]
+    #[test]
+    fn formats_canonical_and_urn_forms_with_display() {
+        let x = Scru128Id::from_fields(MAX_UINT48, MAX_UINT24, MAX_UINT24, MAX_UINT32);
+        assert_eq!(format!("{}", x), "f5lxx1zz5pnorynqglhzmsp33");
+        assert_eq!(format!("{:#}", x), "urn:scru128:f5lxx1zz5pnorynqglhzmsp33");
+        assert_eq!(
+            format!("{:>#40}", x),
+            "   urn:scru128:f5lxx1zz5pnorynqglhzmsp33"
+        );
+        assert_eq!(format!("{:#.15}", x), "urn:scru128:f5l");
+
+        let y = Scru128Id::from_fields(0, 0, 0, 0);
+        assert_eq!(format!("{}", y), "0000000000000000000000000");
+        assert_eq!(format!("{:#}", y), "urn:scru128:0000000000000000000000000");
+    }
+
+    /// Converts to and from little-endian byte array
+    #[test]
+    fn converts_to_and_from_little_endian_byte_array() {
+        let cases = [
+            Scru128Id::MIN,
+            Scru128Id::MAX,
+            Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e),
+            Scru128Id::from_fields(MAX_UINT48, 0, MAX_UINT24, 0),
+        ];
+
+        for e in cases {
+            let (le, be) = (e.to_bytes_le(), e.to_bytes());
+            assert_eq!(Scru128Id::from_bytes_le(le), e);
+            let mut reversed = be;
+            reversed.reverse();
+            assert_eq!(le, reversed);
+            assert_eq!(
+                Scru128Id::from_bytes_le(be),
+                Scru128Id::from_bytes(reversed)
+            );
+        }
+
+        let x = Scru128Id::from_u128(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10);
+        assert_eq!(
+            x.to_bytes_le(),
+            [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]
+        );
+
+        #[cfg(feature = "std")]
+        {
+            let mut g = Scru128Generator::new();
+            for _ in 0..1000 {
+                let e = g.generate();
+                assert_eq!(Scru128Id::from_bytes_le(e.to_bytes_le()), e);
+            }
+        }
+    }
+
+    /// Counts leading bytes shared by two IDs
+    #[test]
+    fn counts_leading_bytes_shared_by_two_ids() {
+        let x = Scru128Id::from_fields(0x0123_4567_89ab, 0xabcdef, 0x123456, 0xdead_beef);
+        assert_eq!(x.common_prefix_len(&x), 16);
+        assert_eq!(Scru128Id::MIN.common_prefix_len(&Scru128Id::MIN), 16);
+
+        let cases = [
+            (
+                Scru128Id::from_fields(0x0123_4567_89ab, 0xabcdef, 0x123456, 0xdead_beee),
+                15,
+            ),
+            (
+                Scru128Id::from_fields(0x0123_4567_89ab, 0xabcdef, 0x123456, 0xdeaf_beef),
+                13,
+            ),
+            (
+                Scru128Id::from_fields(0x0123_4567_89ab, 0xabcdef, 0x123456, 0x0ead_beef),
+                12,
+            ),
+            (
+                Scru128Id::from_fields(0x0123_4567_89ab, 0xabcdef, 0x123457, 0xdead_beef),
+                11,
+            ),
+            (
+                Scru128Id::from_fields(0x0123_4567_89ab, 0xabcdee, 0x123456, 0xdead_beef),
+                8,
+            ),
+            (
+                Scru128Id::from_fields(0x0123_4567_89ac, 0xabcdef, 0x123456, 0xdead_beef),
+                5,
+            ),
+            (
+                Scru128Id::from_fields(0x0223_4567_89ab, 0xabcdef, 0x123456, 0xdead_beef),
+                0,
+            ),
+            (Scru128Id::MAX, 0),
+        ];
+        for (y, n) in cases {
+            assert_eq!(x.common_prefix_len(&y), n);
+            assert_eq!(y.common_prefix_len(&x), n);
+            assert_eq!(x.as_bytes()[..n], y.as_bytes()[..n]);
+            if n < 16 {
+                assert_ne!(x.as_bytes()[n], y.as_bytes()[n]);
+            }
+        }
+
+        #[cfg(feature = "default_rng")]
+        {
+            let mut g = crate::Scru128Generator::new();
+            let ts = 0x0123_4567_89ab;
+            let mut prev = g.generate_or_abort_core(ts, 10_000).unwrap();
+            for _ in 0..1_000 {
+                let curr = g.generate_or_abort_core(ts, 10_000).unwrap();
+                assert!(prev.common_prefix_len(&curr) >= 6);
+                prev = curr;
+            }
+        }
+    }
+
+    /// Shifts timestamp forward preserving random fields
+    #[test]
+    fn shifts_timestamp_forward_preserving_random_fields() {
+        let x = Scru128Id::from_fields(0x0123_4567_89ab, 0x123456, 0xabcdef, 0xdead_beef);
+        for ms in [0, 1, 1_000, 86_400_000, MAX_UINT48 - 0x0123_4567_89ab] {
+            let y = x.checked_add_millis(ms).unwrap();
+            assert_eq!(y.timestamp(), x.timestamp() + ms);
+            assert_eq!(y.counter_hi(), x.counter_hi());
+            assert_eq!(y.counter_lo(), x.counter_lo());
+            assert_eq!(y.entropy(), x.entropy());
+            assert_eq!(y.as_bytes()[6..], x.as_bytes()[6..]);
+        }
+
+        for ms in [MAX_UINT48 - 0x0123_4567_89ab + 1, MAX_UINT48, u64::MAX] {
+            assert_eq!(x.checked_add_millis(ms), None);
+        }
+        assert_eq!(
+            Scru128Id::MIN.checked_add_millis(MAX_UINT48),
+            Some(Scru128Id::from_fields(MAX_UINT48, 0, 0, 0))
+        );
+        assert_eq!(Scru128Id::MAX.checked_add_millis(0), Some(Scru128Id::MAX));
+        assert_eq!(Scru128Id::MAX.checked_add_millis(1), None);
+
+        let mut g = crate::Scru128Generator::with_rng(crate::generator::DefaultRng::default());
+        let ts = 0x0123_4567_89abu64;
+        let mut prev = g.generate_or_abort_core(ts, 10_000).unwrap();
+        for _ in 0..1_000 {
+            let curr = g.generate_or_abort_core(ts, 10_000).unwrap();
+            let (a, b) = (
+                prev.checked_add_millis(500).unwrap(),
+                curr.checked_add_millis(500).unwrap(),
+            );
+            assert!(a < b);
+            assert!(curr < a);
+            assert_eq!(b.bits() & ((1 << 80) - 1), curr.bits() & ((1 << 80) - 1));
+            prev = curr;
+        }
+    }
+
+    /// Returns timestamp as non-negative i64
+    #[test]
+    fn returns_timestamp_as_non_negative_i64() {
+        let cases = [
+            (Scru128Id::MIN, 0),
+            (Scru128Id::MAX, MAX_UINT48 as i64),
+            (Scru128Id::from_fields(1, 0, 0, 0), 1),
+            (
+                Scru128Id::from_fields(MAX_UINT48, 0, 0, 0),
+                0xffff_ffff_ffff,
+            ),
+            (
+                Scru128Id::from_fields(0x0123_4567_89ab, MAX_UINT24, MAX_UINT24, MAX_UINT32),
+                0x0123_4567_89ab,
+            ),
+        ];
+        for (e, expected) in cases {
+            assert_eq!(e.timestamp_millis_i64(), expected);
+            assert_eq!(e.timestamp_millis_i64(), e.timestamp() as i64);
+        }
+
+        let mut g = crate::Scru128Generator::with_rng(crate::generator::DefaultRng::default());
+        let ts = 0x0123_4567_89abu64;
+        for i in 0..1_000 {
+            let e = g.generate_or_abort_core(ts + i, 10_000).unwrap();
+            assert_eq!(e.timestamp_millis_i64(), e.timestamp() as i64);
+            assert!(e.timestamp_millis_i64() > 0);
+        }
+    }
+
+    /// Compares IDs by timestamp only
+    #[test]
+    fn compares_ids_by_timestamp_only() {
+        let x = Scru128Id::from_fields(0x0123_4567_89ab, 0x123456, 0x123456, 0xdead_beef);
+        let same = [
+            Scru128Id::from_fields(0x0123_4567_89ab, 0, 0, 0),
+            Scru128Id::from_fields(0x0123_4567_89ab, MAX_UINT24, MAX_UINT24, MAX_UINT32),
+            Scru128Id::from_fields(0x0123_4567_89ab, 0x123457, 0x123456, 0xdead_beef),
+            Scru128Id::from_fields(0x0123_4567_89ab, 0x123456, 0x123455, 0xdead_beef),
+            x,
+        ];
+        for y in same {
+            assert!(x.same_millisecond(&y));
+            assert!(y.same_millisecond(&x));
+            assert_eq!(x.timestamp_cmp(&y), cmp::Ordering::Equal);
+            assert_eq!(y.timestamp_cmp(&x), cmp::Ordering::Equal);
+        }
+
+        let later = [
+            Scru128Id::from_fields(0x0123_4567_89ac, 0, 0, 0),
+            Scru128Id::from_fields(0x0223_4567_89ab, 0, 0, 0),
+            Scru128Id::MAX,
+        ];
+        for y in later {
+            assert!(!x.same_millisecond(&y));
+            assert!(!y.same_millisecond(&x));
+            assert_eq!(x.timestamp_cmp(&y), cmp::Ordering::Less);
+            assert_eq!(y.timestamp_cmp(&x), cmp::Ordering::Greater);
+        }
+
+        let earlier = Scru128Id::from_fields(0x0123_4567_89aa, MAX_UINT24, MAX_UINT24, MAX_UINT32);
+        assert!(earlier < x);
+        assert!(!x.same_millisecond(&earlier));
+        assert_eq!(x.timestamp_cmp(&earlier), cmp::Ordering::Greater);
+        assert_eq!(Scru128Id::MIN.timestamp_cmp(&earlier), cmp::Ordering::Less);
+    }
 
-impl str::FromStr for Scru128Id {
-    type Err = ParseError;
+    /// Packs entropy into low 32 bits without bleeding into counter_lo
+    #[test]
+    fn packs_entropy_into_low_32_bits_without_bleeding_into_counter_lo() {
+        let x = Scru128Id::from_fields(0, 0, 0, MAX_UINT32);
+        assert_eq!(x.to_u128(), 0xffff_ffff);
+        assert_eq!(x.counter_lo(), 0);
+        assert_eq!(x.entropy(), MAX_UINT32);
 
-    /// Creates an object from a 25-digit string representation.
-    fn from_str(str_value: &str) -> Result<Self, Self::Err> {
-        Self::try_from_str(str_value)
-    }
-}
+        let y = Scru128Id::from_fields(0, 0, MAX_UINT24, 0);
+        assert_eq!(y.to_u128(), 0xff_ffff << 32);
+        assert_eq!(y.counter_lo(), MAX_UINT24);
+        assert_eq!(y.entropy(), 0);
 
-impl fmt::Display for Scru128Id {
-    /// Returns the 25-digit canonical string representation.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// use scru128::Scru128Id;
-    ///
-    /// let x = "03997ft3ckz99o1i3f82zat1t".parse::<Scru128Id>()?;
-    /// assert_eq!(format!("{}", x), "03997ft3ckz99o1i3f82zat1t");
-    /// assert_eq!(format!("{:32}", x), "03997ft3ckz99o1i3f82zat1t       ");
-    /// assert_eq!(format!("{:->32}", x), "-------03997ft3ckz99o1i3f82zat1t");
-    /// assert_eq!(format!("{:.^7.5}", x), ".03997.");
-    /// # Ok::<(), scru128::ParseError>(())
-    /// ```
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(self.encode().as_str(), f)
+        let z = Scru128Id::from_fields(MAX_UINT48, 0, 1, MAX_UINT32);
+        assert_eq!(z.to_u128(), (0xffff_ffff_ffff << 80) | 0x1_ffff_ffff);
+        assert_eq!(
+            (z.timestamp(), z.counter_hi(), z.counter_lo(), z.entropy()),
+            (MAX_UINT48, 0, 1, MAX_UINT32)
+        );
+        assert_eq!(z.increment().counter_lo(), 2);
+        assert_eq!(z.increment().entropy(), 0);
     }
-}
 
-/// An error parsing an invalid string representation of SCRU128 ID.
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ParseError {
-    kind: ParseErrorKind,
-}
+    /// Looks up maps by borrowed byte representation
+    #[cfg(feature = "std")]
+    #[test]
+    fn looks_up_maps_by_borrowed_byte_representation() {
+        use std::collections::{BTreeMap, HashMap};
+        use std::hash::{BuildHasher, RandomState};
 
-#[derive(Clone, Debug, Eq, PartialEq)]
-enum ParseErrorKind {
-    InvalidLength {
-        n_bytes: usize,
-    },
-    InvalidDigit {
-        /// Holds the invalid character as a UTF-8 byte array to work in the const context.
-        utf8_char: [u8; 4],
-        position: usize,
-    },
-    OutOfU128Range,
-}
+        let ids = [
+            Scru128Id::MIN,
+            Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e),
+            Scru128Id::from_fields(MAX_UINT48, 0, MAX_UINT24, 0),
+            Scru128Id::MAX,
+        ];
 
-impl ParseError {
-    /// Creates an `InvalidLength` variant from the actual length.
-    const fn invalid_length(n_bytes: usize) -> Self {
-        Self {
-            kind: ParseErrorKind::InvalidLength { n_bytes },
-        }
-    }
+        let btree: BTreeMap<_, _> = ids.iter().enumerate().map(|(i, e)| (*e, i)).collect();
+        let hash: HashMap<_, _> = ids.iter().enumerate().map(|(i, e)| (*e, i)).collect();
+        let hasher = RandomState::new();
+        for (i, e) in ids.iter().enumerate() {
+            let bytes: [u8; 16] = e.to_bytes();
+            assert_eq!(btree.get(&bytes), Some(&i));
+            assert_eq!(btree.get(&bytes[..]), Some(&i));
+            assert_eq!(hash.get(&bytes), Some(&i));
+            assert_eq!(hash.get(&bytes[..]), Some(&i));
 
-    /// Creates an `InvalidDigit` variant from the entire string and the position of invalid digit.
-    const fn invalid_digit(src: &str, position: usize) -> Self {
-        const fn is_char_boundary(utf8_bytes: &[u8], index: usize) -> bool {
-            match index {
-                0 => true,
-                i if i < utf8_bytes.len() => (utf8_bytes[i] as i8) >= -64,
-                _ => index == utf8_bytes.len(),
+            assert_eq!(hasher.hash_one(e), hasher.hash_one(bytes));
+            assert_eq!(hasher.hash_one(e), hasher.hash_one(&bytes[..]));
+            for f in &ids {
+                assert_eq!(e.cmp(f), bytes.cmp(f.as_bytes()));
+                assert_eq!(e.cmp(f), bytes[..].cmp(&f.as_bytes()[..]));
             }
         }
 
-        let bs = src.as_bytes();
-        assert!(is_char_boundary(bs, position));
-        let mut utf8_char = [bs[position], 0, 0, 0];
+        let absent = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88f);
+        assert_eq!(btree.get(absent.as_bytes()), None);
+        assert_eq!(hash.get(absent.as_bytes()), None);
+    }
 
-        let mut i = 1;
-        while !is_char_boundary(bs, position + i) {
-            utf8_char[i] = bs[position + i];
-            i += 1;
+    /// Increments ID to next one in integer order
+    #[test]
+    fn increments_id_to_next_one_in_integer_order() {
+        let cases = [
+            Scru128Id::MIN,
+            Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e),
+            Scru128Id::from_fields(0x0123_4567_89ab, MAX_UINT24, MAX_UINT24, MAX_UINT32),
+            Scru128Id::from_u128(u128::MAX - 1),
+        ];
+        for e in cases {
+            let next = e.increment();
+            assert!(e < next);
+            assert_eq!(next.to_u128(), e.to_u128() + 1);
+            assert_eq!(e.checked_increment(), Some(next));
         }
 
-        Self {
-            kind: ParseErrorKind::InvalidDigit {
-                utf8_char,
-                position,
-            },
-        }
-    }
+        let carried = cases[2].increment();
+        assert_eq!(carried.timestamp(), 0x0123_4567_89ac);
+        assert_eq!(
+            carried,
+            Scru128Id::from_timestamp_lower_bound(0x0123_4567_89ac)
+        );
 
-    /// Creates an `OutOfU128Range` variant.
-    const fn out_of_u128_range() -> Self {
-        Self {
-            kind: ParseErrorKind::OutOfU128Range,
-        }
+        assert_eq!(Scru128Id::MAX.increment(), Scru128Id::MIN);
+        assert_eq!(Scru128Id::MAX.checked_increment(), None);
     }
-}
 
-impl fmt::Display for ParseError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "could not parse string as SCRU128 ID: ")?;
-        match self.kind {
-            ParseErrorKind::InvalidLength { n_bytes } => {
-                write!(f, "invalid length: {} bytes (expected 25)", n_bytes)
-            }
-            ParseErrorKind::InvalidDigit {
-                utf8_char,
-                position,
-            } => {
-                let chr = str::from_utf8(&utf8_char).unwrap().chars().next().unwrap();
-                write!(f, "invalid digit '{}' at {}", chr.escape_debug(), position)
+    /// Splits and reassembles text parts preserving order
+    #[test]
+    fn splits_and_reassembles_text_parts_preserving_order() {
+        let mut ids = [
+            Scru128Id::MIN,
+            Scru128Id::MAX,
+            Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e),
+            Scru128Id::from_fields(0x0123_4567_89ab, 0, 0, 0),
+            Scru128Id::from_fields(0x0123_4567_89ab, 0, 0, 1),
+            Scru128Id::from_fields(0x0123_4567_89ab, MAX_UINT24, MAX_UINT24, MAX_UINT32),
+            Scru128Id::from_fields(0x0123_4567_89ac, 0, 0, 0),
+            Scru128Id::from_fields(MAX_UINT48, 0, 0, 0),
+        ];
+        ids.sort();
+
+        let mut prev: Option<(FStr<13>, FStr<12>)> = None;
+        for e in ids {
+            let (hi, lo) = e.split_text();
+            let canonical = e.encode();
+            assert_eq!(hi, &canonical[..13]);
+            assert_eq!(lo, &canonical[13..]);
+            assert_eq!(Scru128Id::from_text_parts(&hi, &lo), Ok(e));
+            if let Some(prev) = prev {
+                assert!(prev.0 <= hi);
+                assert!(prev < (hi, lo));
             }
-            ParseErrorKind::OutOfU128Range => write!(f, "out of 128-bit value range"),
+            prev = Some((hi, lo));
         }
+
+        assert_eq!(
+            Scru128Id::from_text_parts("037D0XYE6OP48", "CMCE8EY4XLCF"),
+            "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()
+        );
+        assert!(Scru128Id::from_text_parts("037d0xye6op4", "8cmce8ey4xlcf").is_err());
+        assert!(Scru128Id::from_text_parts("037d0xye6op48", "cmce8ey4xlc").is_err());
+        assert_eq!(
+            Scru128Id::from_text_parts("037d0xye6op48", "cmce8ey4xl-f").unwrap_err(),
+            "037d0xye6op48cmce8ey4xl-f"
+                .parse::<Scru128Id>()
+                .unwrap_err()
+        );
     }
-}
 
-#[cfg(feature = "std")]
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-mod with_std {
-    use super::{ParseError, Scru128Id};
+    /// Encodes and decodes Crockford Base32 form
+    #[test]
+    fn encodes_and_decodes_crockford_base32_form() {
+        let cases = [
+            (Scru128Id::MIN, "00000000000000000000000000"),
+            (Scru128Id::MAX, "7ZZZZZZZZZZZZZZZZZZZZZZZZZ"),
+            (
+                Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e),
+                "01FYGXWMD81ZCS5YF8SGPNXE4E",
+            ),
+            (Scru128Id::from_u128(1), "00000000000000000000000001"),
+            (Scru128Id::from_u128(1 << 127), "40000000000000000000000000"),
+        ];
+        let mut prev: Option<FStr<26>> = None;
+        for (e, text) in cases {
+            assert_eq!(e.to_base32_crockford(), text);
+            assert_eq!(Scru128Id::from_base32_crockford(text), Ok(e));
+            if let Some(prev) = prev {
+                assert_eq!(
+                    prev.as_str() < text,
+                    Scru128Id::from_base32_crockford(&prev).unwrap() < e
+                );
+            }
+            prev = Some(e.to_base32_crockford());
+        }
 
-    impl TryFrom<String> for Scru128Id {
-        type Error = ParseError;
+        // normalize lowercase and ambiguous characters
+        let x = Scru128Id::from_base32_crockford("0123456789ABCDEFGHJKMNPQRS").unwrap();
+        for text in [
+            "0123456789abcdefghjkmnpqrs",
+            "O123456789ABCDEFGHJKMNPQRS",
+            "oI23456789ABCDEFGHJKMNPQRS",
+            "0l23456789ABCDEFGHJKMNPQRS",
+            "0L23456789ABCDEFGHJKMNPQRS",
+            "0i23456789abcdefghjkmnpqrs",
+        ] {
+            assert_eq!(Scru128Id::from_base32_crockford(text), Ok(x));
+        }
 
-        fn try_from(value: String) -> Result<Self, Self::Error> {
-            Self::try_from_str(&value)
+        // reject invalid strings
+        use super::ParseErrorRepr::*;
+        let cases = [
+            ("", invalid_length(0)),
+            ("0000000000000000000000000", invalid_length(25)),
+            ("000000000000000000000000000", invalid_length(27)),
+            ("0000000000000000000000000U", invalid_digit('U', 25)),
+            ("00000000000000-00000000000", invalid_digit('-', 14)),
+            ("000000000000000000000000é", invalid_digit('é', 24)),
+            ("80000000000000000000000000", OutOfU128Range),
+            ("ZZZZZZZZZZZZZZZZZZZZZZZZZZ", OutOfU128Range),
+        ];
+        for (text, kind) in cases {
+            let err = Scru128Id::from_base32_crockford(text).unwrap_err();
+            match (err.repr, kind) {
+                (InvalidLength { n_bytes: a, .. }, InvalidLength { n_bytes: b, .. }) => {
+                    assert_eq!(a, b)
+                }
+                (a, b) => assert_eq!(a, b),
+            }
         }
     }
 
-    impl From<Scru128Id> for String {
-        fn from(object: Scru128Id) -> Self {
-            object.encode().into()
+    /// Reports default value as nil and generated IDs as non-nil
+    #[test]
+    fn reports_default_value_as_nil_and_generated_ids_as_non_nil() {
+        assert!(Scru128Id::default().is_nil());
+        assert!(Scru128Id::MIN.is_nil());
+        assert!(!Scru128Id::MAX.is_nil());
+        assert!(!Scru128Id::from_u128(1).is_nil());
+        assert!(!Scru128Id::from_fields(0, 0, 0, 1).is_nil());
+
+        #[cfg(feature = "std")]
+        {
+            let mut g = Scru128Generator::new();
+            for _ in 0..1000 {
+                let e = g.generate();
+                assert!(e.timestamp() > 0);
+                assert!(!e.is_nil());
+            }
         }
     }
 
-    impl std::error::Error for ParseError {}
-}
+    /// Converts byte slices of binary or textual representation by length
+    #[test]
+    fn converts_byte_slices_of_binary_or_textual_representation_by_length() {
+        use super::ParseErrorRepr::*;
 
-#[cfg(test)]
-mod tests {
-    use super::Scru128Id;
+        let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>().unwrap();
+        let bytes = x.to_bytes();
+        assert_eq!(Scru128Id::try_from(&bytes[..]), Ok(x));
+        assert_eq!(
+            Scru128Id::try_from(&b"037d0xye6op48cmce8ey4xlcf"[..]),
+            Ok(x)
+        );
+        assert_eq!(
+            Scru128Id::try_from(&b"037D0XYE6OP48CMCE8EY4XLCF"[..]),
+            Ok(x)
+        );
 
-    #[cfg(feature = "std")]
-    use crate::Scru128Generator;
+        // 16 ASCII bytes are binary representation
+        let ascii = b"0123456789abcdef";
+        assert_eq!(
+            Scru128Id::try_from(&ascii[..]),
+            Ok(Scru128Id::from_bytes(*ascii))
+        );
 
-    const MAX_UINT48: u64 = (1 << 48) - 1;
-    const MAX_UINT24: u32 = (1 << 24) - 1;
-    const MAX_UINT32: u32 = u32::MAX;
+        for n in [0, 15, 17, 20, 24, 26] {
+            assert_eq!(
+                Scru128Id::try_from(&[0x30u8; 32][..n]).unwrap_err().repr,
+                InvalidLength {
+                    n_bytes: n,
+                    expected: "16 or 25"
+                }
+            );
+        }
+        assert_eq!(
+            Scru128Id::try_from(&b"037d0xye6op48cmce8ey4xlc-"[..])
+                .unwrap_err()
+                .repr,
+            invalid_digit('-', 24)
+        );
 
-    /// Encodes and decodes prepared cases correctly
+        #[cfg(feature = "std")]
+        {
+            let mut g = Scru128Generator::new();
+            for _ in 0..1000 {
+                let e = g.generate();
+                assert_eq!(Scru128Id::try_from(e.as_bytes().as_slice()), Ok(e));
+                assert_eq!(Scru128Id::try_from(e.encode().as_bytes().as_slice()), Ok(e));
+            }
+        }
+    }
+
+    /// Writes canonical string into caller-provided buffer
     #[test]
-    fn encodes_and_decodes_prepared_cases_correctly() {
-        #[allow(clippy::type_complexity)]
-        let cases: &[((u64, u32, u32, u32), &str)] = &[
-            ((0, 0, 0, 0), "0000000000000000000000000"),
-            ((MAX_UINT48, 0, 0, 0), "F5LXX1ZZ5K6TP71GEEH2DB7K0"),
-            ((MAX_UINT48, 0, 0, 0), "f5lxx1zz5k6tp71geeh2db7k0"),
-            ((0, MAX_UINT24, 0, 0), "0000000005GV2R2KJWR7N8XS0"),
-            ((0, MAX_UINT24, 0, 0), "0000000005gv2r2kjwr7n8xs0"),
-            ((0, 0, MAX_UINT24, 0), "00000000000000JPIA7QL4HS0"),
-            ((0, 0, MAX_UINT24, 0), "00000000000000jpia7ql4hs0"),
-            ((0, 0, 0, MAX_UINT32), "0000000000000000001Z141Z3"),
-            ((0, 0, 0, MAX_UINT32), "0000000000000000001z141z3"),
-            (
-                (MAX_UINT48, MAX_UINT24, MAX_UINT24, MAX_UINT32),
-                "F5LXX1ZZ5PNORYNQGLHZMSP33",
-            ),
+    fn writes_canonical_string_into_caller_provided_buffer() {
+        let cases = [
+            (Scru128Id::MIN, "0000000000000000000000000"),
+            (Scru128Id::MAX, "f5lxx1zz5pnorynqglhzmsp33"),
             (
-                (MAX_UINT48, MAX_UINT24, MAX_UINT24, MAX_UINT32),
-                "f5lxx1zz5pnorynqglhzmsp33",
+                Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e),
+                "036z968fuj8fp95tsldrnqjke",
             ),
         ];
 
+        // reuse a dirty buffer, as under no_std environments
+        let mut buf = [0xffu8; 25];
         for e in cases {
-            let from_fields = Scru128Id::from_fields(e.0 .0, e.0 .1, e.0 .2, e.0 .3);
-            let from_string = e.1.parse::<Scru128Id>().unwrap();
+            let written = e.0.encode_to_slice(&mut buf);
+            assert_eq!(written, e.1);
+            assert_eq!(written, e.0.encode());
+            assert_eq!(&buf, e.1.as_bytes());
+            assert_eq!(str::from_utf8(&buf), Ok(e.1));
+        }
 
-            assert_eq!(from_fields, from_string);
-            assert_eq!(
-                from_fields.to_u128(),
-                u128::from_str_radix(e.1, 36).unwrap()
-            );
-            assert_eq!(
-                from_string.to_u128(),
-                u128::from_str_radix(e.1, 36).unwrap()
-            );
-            assert_eq!(
-                from_fields.to_bytes(),
-                u128::from_str_radix(e.1, 36).unwrap().to_be_bytes()
-            );
-            assert_eq!(
-                from_string.to_bytes(),
-                u128::from_str_radix(e.1, 36).unwrap().to_be_bytes()
-            );
-            assert_eq!(
-                (
-                    (
-                        from_fields.timestamp(),
-                        from_fields.counter_hi(),
-                        from_fields.counter_lo(),
-                        from_fields.entropy(),
-                    ),
-                    &from_fields.encode() as &str
-                ),
-                (e.0, e.1.to_lowercase().as_str())
-            );
-            assert_eq!(
-                (
-                    (
-                        from_string.timestamp(),
-                        from_string.counter_hi(),
-                        from_string.counter_lo(),
-                        from_string.entropy(),
-                    ),
-                    &from_string.encode() as &str
-                ),
-                (e.0, e.1.to_lowercase().as_str())
-            );
-            #[cfg(feature = "std")]
-            assert_eq!(from_fields.to_string(), e.1.to_lowercase());
-            #[cfg(feature = "std")]
-            assert_eq!(from_string.to_string(), e.1.to_lowercase());
+        let mut g = crate::Scru128Generator::with_rng(crate::generator::DefaultRng::default());
+        for _ in 0..1_000 {
+            let e = g.generate_or_abort_core(0x0123_4567_89ab, 10_000).unwrap();
+            assert_eq!(e.encode_to_slice(&mut buf).parse::<Scru128Id>(), Ok(e));
+            assert_eq!(&buf, e.encode().as_bytes());
         }
     }
 
-    /// Returns error if an invalid string representation is supplied
+    /// Formats lazily as canonical string
+    #[cfg(feature = "std")]
     #[test]
-    fn returns_error_if_an_invalid_string_representation_is_supplied() {
-        use super::ParseErrorKind::{self, *};
-        fn invalid_digit(c: char, position: usize) -> ParseErrorKind {
-            let mut utf8_char = [0u8; 4];
-            c.encode_utf8(&mut utf8_char);
-            InvalidDigit {
-                utf8_char,
-                position,
-            }
-        }
-
+    fn formats_lazily_as_canonical_string() {
         let cases = [
-            ("", InvalidLength { n_bytes: 0 }),
-            (" 036z8puq4tsxsigk6o19y164q", InvalidLength { n_bytes: 26 }),
-            ("036z8puq54qny1vq3hcbrkweb ", InvalidLength { n_bytes: 26 }),
-            (" 036z8puq54qny1vq3helivwax ", InvalidLength { n_bytes: 27 }),
-            ("+036z8puq54qny1vq3hfcv3ss0", InvalidLength { n_bytes: 26 }),
-            ("-036z8puq54qny1vq3hhy8u1ch", InvalidLength { n_bytes: 26 }),
-            ("+36z8puq54qny1vq3hjq48d9p", invalid_digit('+', 0)),
-            ("-36z8puq5a7j0ti08oz6zdrdy", invalid_digit('-', 0)),
-            ("036z8puq5a7j0t_08p2cdz28v", invalid_digit('_', 14)),
-            ("036z8pu-5a7j0ti08p3ol8ool", invalid_digit('-', 7)),
-            ("036z8puq5a7j0ti08p4j 6cya", invalid_digit(' ', 20)),
-            ("f5lxx1zz5pnorynqglhzmsp34", OutOfU128Range),
-            ("zzzzzzzzzzzzzzzzzzzzzzzzz", OutOfU128Range),
-            ("039o\tvvklfmqlqe7fzllz7c7t", invalid_digit('\t', 4)),
-            ("039onvvklfmqlq漢字fgvd1", invalid_digit('漢', 14)),
-            ("039onvvkl🤣qe7fzr2hdoqu", invalid_digit('🤣', 9)),
-            ("頭onvvklfmqlqe7fzrhtgcfz", invalid_digit('頭', 0)),
-            ("039onvvklfmqlqe7fztft5尾", invalid_digit('尾', 22)),
-            ("039漢字a52xp4bvf4sn94e09cja", InvalidLength { n_bytes: 29 }),
-            ("039ooa52xp4bv😘sn97642mwl", InvalidLength { n_bytes: 27 }),
+            Scru128Id::MIN,
+            Scru128Id::MAX,
+            Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e),
         ];
+        let mut g = Scru128Generator::new();
+        for e in cases.into_iter().chain((0..1_000).map(|_| g.generate())) {
+            let lazy = e.lazy_display();
+            let copied = lazy;
+            assert_eq!(lazy.to_string(), e.encode());
+            assert_eq!(copied.to_string(), e.encode());
+            assert_eq!(format!("{:#}", lazy), format!("urn:scru128:{}", e.encode()));
+            assert_eq!(format!("{:>30}", lazy), format!("{:>30}", e));
+        }
+    }
 
-        for e in cases {
-            let result = e.0.parse::<Scru128Id>();
-            assert!(result.is_err());
-            assert_eq!(result.unwrap_err().kind, e.1);
+    /// Returns tail of canonical string as short tag
+    #[test]
+    fn returns_tail_of_canonical_string_as_short_tag() {
+        let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>().unwrap();
+        assert_eq!(x.short_tag::<0>(), "");
+        assert_eq!(x.short_tag::<1>(), "f");
+        assert_eq!(x.short_tag::<6>(), "y4xlcf");
+        assert_eq!(x.short_tag::<8>(), "8ey4xlcf");
+        assert_eq!(x.short_tag::<25>(), x.encode());
+
+        const TAG: FStr<4> = Scru128Id::MAX.short_tag::<4>();
+        assert_eq!(TAG, "sp33");
+
+        let mut g = crate::Scru128Generator::with_rng(crate::generator::DefaultRng::default());
+        let ts = 0x0123_4567_89ab;
+        let mut prev = g.generate_or_abort_core(ts, 10_000).unwrap();
+        for _ in 0..1_000 {
+            let curr = g.generate_or_abort_core(ts, 10_000).unwrap();
+            assert_eq!(curr.timestamp(), prev.timestamp());
+            assert_eq!(curr.short_tag::<8>(), &curr.encode()[17..]);
+            assert_ne!(curr.short_tag::<8>(), prev.short_tag::<8>());
+            prev = curr;
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let mut g = Scru128Generator::new();
+            let mut tags = std::collections::HashSet::new();
+            for _ in 0..1_000 {
+                let e = g.generate();
+                assert_eq!(e.short_tag::<10>(), &e.encode()[15..]);
+                tags.insert(e.short_tag::<8>());
+            }
+            assert_eq!(tags.len(), 1_000);
+
+            assert!(std::panic::catch_unwind(|| x.short_tag::<26>()).is_err());
         }
     }
 
-    /// Has symmetric converters from/to various values
+    /// Rejects nil value in from_u128_non_nil
     #[test]
-    fn has_symmetric_converters_from_to_various_values() {
-        let cases = [
-            Scru128Id::from_fields(0, 0, 0, 0),
-            Scru128Id::from_fields(MAX_UINT48, 0, 0, 0),
-            Scru128Id::from_fields(0, MAX_UINT24, 0, 0),
-            Scru128Id::from_fields(0, 0, MAX_UINT24, 0),
-            Scru128Id::from_fields(0, 0, 0, MAX_UINT32),
-            Scru128Id::from_fields(MAX_UINT48, MAX_UINT24, MAX_UINT24, MAX_UINT32),
-        ];
+    fn rejects_nil_value_in_from_u128_non_nil() {
+        const NIL: Option<Scru128Id> = Scru128Id::from_u128_non_nil(0);
+        assert_eq!(NIL, None);
+
+        let cases = [1, 1 << 80, u128::MAX, 0x017fa1de51a80fd992f9e8cc2d5eb88e];
+        for e in cases {
+            let x = Scru128Id::from_u128_non_nil(e).unwrap();
+            assert_eq!(x, Scru128Id::from_u128(e));
+            assert!(!x.is_nil());
+        }
 
         #[cfg(feature = "std")]
-        let cases = {
-            let mut v = cases.to_vec();
+        {
             let mut g = Scru128Generator::new();
             for _ in 0..1000 {
-                v.push(g.generate());
+                let e = g.generate();
+                assert_eq!(Scru128Id::from_u128_non_nil(e.to_u128()), Some(e));
             }
-            v
-        };
+        }
+    }
 
+    /// Encodes grouped form that strips back to canonical form
+    #[test]
+    fn encodes_grouped_form_that_strips_back_to_canonical_form() {
+        let cases = [
+            Scru128Id::MIN,
+            Scru128Id::MAX,
+            Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e),
+            Scru128Id::from_fields(MAX_UINT48, 0, MAX_UINT24, 0),
+        ];
         for e in cases {
-            assert_eq!(Scru128Id::try_from_str(&e.encode()), Ok(e));
-            assert_eq!(e.encode().parse::<Scru128Id>(), Ok(e));
-            #[cfg(feature = "std")]
-            assert_eq!(e.to_string().parse::<Scru128Id>(), Ok(e));
-            #[cfg(feature = "std")]
-            assert_eq!(Scru128Id::try_from(String::from(e)), Ok(e));
-            assert_eq!(Scru128Id::from_u128(e.to_u128()), e);
-            assert_eq!(Scru128Id::from(u128::from(e)), e);
-            assert_eq!(Scru128Id::from_bytes(e.to_bytes()), e);
-            assert_eq!(Scru128Id::from(<[u8; 16]>::from(e)), e);
-            assert_eq!(Scru128Id::from_bytes(*e.as_bytes()), e);
-            assert_eq!(
-                Scru128Id::from_fields(e.timestamp(), e.counter_hi(), e.counter_lo(), e.entropy()),
-                e
-            );
+            let grouped = e.encode_grouped_5();
+            let canonical = e.encode();
+            assert_eq!(grouped.len(), 29);
+            for (i, c) in grouped.bytes().enumerate() {
+                assert_eq!(c == b'-', i % 6 == 5);
+            }
+            assert!(grouped.bytes().filter(|&c| c != b'-').eq(canonical.bytes()));
+        }
+
+        assert_eq!(
+            Scru128Id::MAX.encode_grouped_5(),
+            "f5lxx-1zz5p-noryn-qglhz-msp33"
+        );
+        assert_eq!(
+            Scru128Id::MIN.encode_grouped_5(),
+            "00000-00000-00000-00000-00000"
+        );
+    }
+
+    /// Formats zero-padded hexadecimal forms with LowerHex and UpperHex
+    #[cfg(feature = "std")]
+    #[test]
+    fn formats_zero_padded_hexadecimal_forms_with_lower_hex_and_upper_hex() {
+        let x = Scru128Id::MIN;
+        assert_eq!(format!("{:x}", x), "00000000000000000000000000000000");
+        assert_eq!(format!("{:X}", x), "00000000000000000000000000000000");
+        assert_eq!(format!("{:#x}", x), "0x00000000000000000000000000000000");
+        assert_eq!(format!("{:x}", x).len(), 32);
+
+        let y = Scru128Id::MAX;
+        assert_eq!(format!("{:x}", y), "ffffffffffffffffffffffffffffffff");
+        assert_eq!(format!("{:X}", y), "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
+        assert_eq!(format!("{:#X}", y), "0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
+
+        let z = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+        assert_eq!(format!("{:x}", z), "017fa1de51a80fd992f9e8cc2d5eb88e");
+        assert_eq!(format!("{:X}", z), "017FA1DE51A80FD992F9E8CC2D5EB88E");
+        assert_eq!(format!("{:#x}", z), "0x017fa1de51a80fd992f9e8cc2d5eb88e");
+        assert_eq!(
+            format!("{:>36x}", z),
+            "    017fa1de51a80fd992f9e8cc2d5eb88e"
+        );
+        assert_eq!(
+            format!("{:*<#36X}", z),
+            "0x017FA1DE51A80FD992F9E8CC2D5EB88E**"
+        );
+        assert_eq!(
+            format!("{:#036x}", z),
+            "0x00017fa1de51a80fd992f9e8cc2d5eb88e"
+        );
+        assert_eq!(format!("{:8x}", z), "017fa1de51a80fd992f9e8cc2d5eb88e");
+
+        #[cfg(feature = "default_rng")]
+        {
+            let mut g = crate::Scru128Generator::new();
+            let mut prev = format!("{:x}", g.generate());
+            for _ in 0..1_000 {
+                let e = g.generate();
+                let curr = format!("{:x}", e);
+                assert_eq!(curr, format!("{:032x}", e.to_u128()));
+                assert_eq!(format!("{:X}", e), curr.to_uppercase());
+                assert!(prev < curr);
+                prev = curr;
+            }
         }
     }
 
+    /// Exposes bits in const context
+    #[test]
+    fn exposes_bits_in_const_context() {
+        const X: Scru128Id = Scru128Id::from_fields(MAX_UINT48, 0, MAX_UINT24, 0);
+        const TIMESTAMP: u64 = (X.bits() >> 80) as u64;
+        const COUNTER_LO: u32 = (X.bits() >> 32) as u32 & MAX_UINT24;
+        assert_eq!(TIMESTAMP, MAX_UINT48);
+        assert_eq!(COUNTER_LO, MAX_UINT24);
+        assert_eq!(X.bits(), X.to_u128());
+    }
+
     /// Supports comparison operators
     #[test]
     fn supports_comparison_operators() {
         #[cfg(feature = "std")]
         let hash = {
-            use std::hash::{BuildHasher, Hash, Hasher};
+            use std::hash::BuildHasher;
             let s = std::collections::hash_map::RandomState::new();
-            move |value: &Scru128Id| {
-                let mut hasher = s.build_hasher();
-                value.hash(&mut hasher);
-                hasher.finish()
-            }
+            move |value: &Scru128Id| s.hash_one(value)
         };
 
         let ordered = [
@@ -592,6 +4099,49 @@ mod tests {
             prev = curr;
         }
     }
+
+    /// Sorts encoded FStr values in same order as IDs
+    #[test]
+    fn sorts_encoded_fstr_values_in_same_order_as_ids() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        const N: usize = 4096;
+        let mut rng = StdRng::seed_from_u64(0x0123_4567_89ab_cdef);
+        let mut ids = [Scru128Id::MIN; N];
+        for (i, e) in ids.iter_mut().enumerate() {
+            // mix uniformly random values with ones sharing long prefixes
+            *e = match i % 4 {
+                0 => Scru128Id::from_u128(rng.gen()),
+                1 => Scru128Id::from_u128(rng.gen::<u128>() >> rng.gen_range(0..128u32)),
+                2 => Scru128Id::from_fields(0x0123_4567_89ab, 0, rng.gen_range(0..16), rng.gen()),
+                _ => Scru128Id::from_u128(u128::MAX - rng.gen_range(0..1_000_000)),
+            };
+        }
+        ids[0] = Scru128Id::MIN;
+        ids[1] = Scru128Id::MAX;
+
+        let mut encoded = [ids[0].encode(); N];
+        for (e, id) in encoded.iter_mut().zip(&ids) {
+            *e = id.encode();
+        }
+
+        ids.sort_unstable();
+        encoded.sort_unstable();
+        for (id, e) in ids.iter().zip(&encoded) {
+            assert_eq!(&id.encode(), e);
+        }
+
+        for i in 1..N {
+            assert_eq!(
+                ids[i - 1].cmp(&ids[i]),
+                ids[i - 1].encode().cmp(&ids[i].encode())
+            );
+            assert_eq!(
+                ids[i - 1].cmp(&ids[i]),
+                ids[i - 1].encode().as_str().cmp(ids[i].encode().as_str())
+            );
+        }
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -610,6 +4160,16 @@ mod with_serde {
         }
     }
 
+    /// Deserializes an ID from any of the following representations, regardless of whether the
+    /// format is human-readable:
+    ///
+    /// - A string of exactly 25 characters, which is always parsed as the canonical Base36
+    ///   representation. A string of 25 decimal digits is therefore never read as a decimal
+    ///   integer, because it is also a valid Base36 representation of a different ID.
+    /// - A string of any other length consisting of decimal digits, which is parsed as the
+    ///   decimal representation of the 128-bit unsigned integer.
+    /// - A byte array of 16 bytes, or a byte array containing either of the strings above.
+    /// - An unsigned integer (`u64` or `u128`).
     impl<'de> serde::Deserialize<'de> for Scru128Id {
         fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
             if deserializer.is_human_readable() {
@@ -630,6 +4190,12 @@ mod with_serde {
         }
 
         fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            // parse 25-digit strings as Base36 only, as they may also consist of decimal digits
+            if value.len() != 25 {
+                if let Ok(int_value) = value.parse::<u128>() {
+                    return Ok(Self::Value::from_u128(int_value));
+                }
+            }
             Self::Value::try_from_str(value).map_err(de::Error::custom)
         }
 
@@ -643,6 +4209,10 @@ mod with_serde {
             }
         }
 
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            Ok(Self::Value::from_u128(value as u128))
+        }
+
         fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
             Ok(Self::Value::from_u128(value))
         }
@@ -651,7 +4221,7 @@ mod with_serde {
     #[cfg(test)]
     mod tests {
         use super::Scru128Id;
-        use serde_test::{Configure, Token};
+        use serde_test::{Configure, Readable, Token};
 
         /// Serializes and deserializes prepared cases correctly
         #[test]
@@ -721,5 +4291,660 @@ mod with_serde {
                 serde_test::assert_de_tokens(&e.compact(), &[Token::Bytes(text.as_bytes())]);
             }
         }
+
+        /// Deserializes integer and decimal string inputs
+        #[test]
+        fn deserializes_integer_and_decimal_string_inputs() {
+            use serde::de::{value, Deserialize, IntoDeserializer};
+
+            let x = Scru128Id::from_u128(0x0123_4567_89ab_cdef);
+            serde_test::assert_de_tokens(&x.readable(), &[Token::U64(0x0123_4567_89ab_cdef)]);
+            serde_test::assert_de_tokens(&x.compact(), &[Token::U64(0x0123_4567_89ab_cdef)]);
+            serde_test::assert_de_tokens(&Scru128Id::MIN.readable(), &[Token::U64(0)]);
+
+            let y = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+            let de: value::U128Deserializer<value::Error> = y.to_u128().into_deserializer();
+            assert_eq!(Scru128Id::deserialize(de), Ok(y));
+            let de: value::U128Deserializer<value::Error> = u128::MAX.into_deserializer();
+            assert_eq!(Scru128Id::deserialize(de), Ok(Scru128Id::MAX));
+
+            // accept decimal integer string if not 25 characters long
+            serde_test::assert_de_tokens(
+                &y.readable(),
+                &[Token::Str("1991932778688565249604048638384715918")],
+            );
+            serde_test::assert_de_tokens(&x.readable(), &[Token::Str("81985529216486895")]);
+
+            // parse 25-digit decimal string as Base36, not as decimal integer
+            let z = "1000000000000000000000000".parse::<Scru128Id>().unwrap();
+            assert_ne!(z, Scru128Id::from_u128(10u128.pow(24)));
+            serde_test::assert_de_tokens(&z.readable(), &[Token::Str("1000000000000000000000000")]);
+            serde_test::assert_de_tokens(
+                &z.compact(),
+                &[Token::Bytes(b"1000000000000000000000000")],
+            );
+            serde_test::assert_de_tokens_error::<Readable<Scru128Id>>(
+                &[Token::Str("not an id")],
+                "could not parse string as SCRU128 ID: invalid length: 9 bytes (expected 25)",
+            );
+        }
+    }
+}
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+mod with_bytes {
+    use super::{ParseError, Scru128Id};
+    use bytes::{BufMut, Bytes};
+
+    impl Scru128Id {
+        /// Writes the 25-digit canonical string representation to `buf` as ASCII bytes without
+        /// allocating a temporary [`String`].
+        ///
+        /// # Panics
+        ///
+        /// Panics if `buf` does not have enough capacity to write 25 bytes, as
+        /// [`BufMut::put_slice()`] does.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use bytes::BytesMut;
+        /// use scru128::Scru128Id;
+        ///
+        /// let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
+        /// let mut buf = BytesMut::new();
+        /// x.put_encoded(&mut buf);
+        /// assert_eq!(&buf[..], b"037d0xye6op48cmce8ey4xlcf");
+        /// # Ok::<(), scru128::ParseError>(())
+        /// ```
+        pub fn put_encoded<B: BufMut>(&self, buf: &mut B) {
+            buf.put_slice(self.encode().as_bytes());
+        }
+
+        /// Writes the 16-byte big-endian binary representation to `buf`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `buf` does not have enough capacity to write 16 bytes, as
+        /// [`BufMut::put_slice()`] does.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use bytes::BytesMut;
+        /// use scru128::Scru128Id;
+        ///
+        /// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+        /// let mut buf = BytesMut::new();
+        /// x.put_bytes(&mut buf);
+        /// assert_eq!(&buf[..], x.as_bytes());
+        /// ```
+        pub fn put_bytes<B: BufMut>(&self, buf: &mut B) {
+            buf.put_slice(self.as_bytes());
+        }
+
+        /// Returns the 16-byte big-endian binary representation as [`Bytes`], which is intended
+        /// for protobuf `bytes` fields generated by `prost`.
+        ///
+        /// `prost-build` maps `bytes` fields to `Vec<u8>` by default and to [`Bytes`] if
+        /// configured through `Config::bytes()`. This method suits the latter; use
+        /// `to_bytes().to_vec()` for the former. Read the field back with
+        /// [`from_prost_bytes()`](Self::from_prost_bytes), which accepts both types.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use scru128::Scru128Id;
+        ///
+        /// // generated by `prost-build` with `config.bytes(["."])` from:
+        /// // message Record { bytes id = 1; }
+        /// struct Record {
+        ///     // #[prost(bytes = "bytes", tag = "1")]
+        ///     id: bytes::Bytes,
+        /// }
+        ///
+        /// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+        /// let record = Record { id: x.to_prost_bytes() };
+        /// assert_eq!(Scru128Id::from_prost_bytes(&record.id)?, x);
+        /// # Ok::<(), scru128::ParseError>(())
+        /// ```
+        pub fn to_prost_bytes(&self) -> Bytes {
+            Bytes::copy_from_slice(self.as_bytes())
+        }
+
+        /// Creates an object from the content of a protobuf `bytes` field, or returns an error if
+        /// the content is not exactly 16 bytes long.
+        ///
+        /// This method accepts the 16-byte big-endian binary representation written by
+        /// [`to_prost_bytes()`](Self::to_prost_bytes) and does not accept the 25-digit string
+        /// representation, so that a field holding an unexpected value is rejected rather than
+        /// interpreted.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if `b` is not 16 bytes long.
+        pub fn from_prost_bytes(b: &[u8]) -> Result<Self, ParseError> {
+            match <[u8; 16]>::try_from(b) {
+                Ok(array_value) => Ok(Self::from_bytes(array_value)),
+                Err(_) => Err(ParseError::invalid_length(b.len(), "16")),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::Scru128Id;
+        use bytes::{BufMut, BytesMut};
+
+        /// Writes textual and binary representations into BytesMut
+        #[test]
+        fn writes_textual_and_binary_representations_into_bytes_mut() {
+            let x = "037arkzbgn93kdu9h3pw2ow2l".parse::<Scru128Id>().unwrap();
+            let y = Scru128Id::from_fields(0, 0, 0, 0);
+
+            let mut buf = BytesMut::with_capacity(64);
+            buf.put_u8(b'[');
+            x.put_encoded(&mut buf);
+            buf.put_u8(b',');
+            y.put_encoded(&mut buf);
+            buf.put_u8(b']');
+            assert_eq!(
+                &buf[..],
+                b"[037arkzbgn93kdu9h3pw2ow2l,0000000000000000000000000]"
+            );
+
+            let mut buf = BytesMut::new();
+            x.put_bytes(&mut buf);
+            y.put_bytes(&mut buf);
+            assert_eq!(buf.len(), 32);
+            assert_eq!(&buf[..16], x.as_bytes());
+            assert_eq!(&buf[16..], &[0u8; 16]);
+            assert_eq!(Scru128Id::from_bytes(buf[..16].try_into().unwrap()), x);
+
+            let mut fixed = [0u8; 25];
+            x.put_encoded(&mut &mut fixed[..]);
+            assert_eq!(&fixed, b"037arkzbgn93kdu9h3pw2ow2l");
+        }
+
+        /// Converts to and from protobuf bytes field content
+        #[test]
+        fn converts_to_and_from_protobuf_bytes_field_content() {
+            use crate::ParseErrorKind;
+
+            let cases = [
+                Scru128Id::MIN,
+                Scru128Id::MAX,
+                Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e),
+            ];
+            for e in cases {
+                let b = e.to_prost_bytes();
+                assert_eq!(&b[..], e.as_bytes());
+                assert_eq!(Scru128Id::from_prost_bytes(&b), Ok(e));
+                assert_eq!(Scru128Id::from_prost_bytes(&e.to_bytes()), Ok(e));
+            }
+
+            for n in [0, 1, 15, 17, 25, 32] {
+                let err = Scru128Id::from_prost_bytes(&[0u8; 32][..n]).unwrap_err();
+                assert_eq!(err.kind(), ParseErrorKind::InvalidLength);
+                assert_eq!(
+                    err.repr,
+                    crate::id::ParseErrorRepr::InvalidLength {
+                        n_bytes: n,
+                        expected: "16"
+                    }
+                );
+            }
+
+            // string representation is rejected
+            let x = "037arkzbgn93kdu9h3pw2ow2l".parse::<Scru128Id>().unwrap();
+            assert!(Scru128Id::from_prost_bytes(x.encode().as_bytes()).is_err());
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+mod with_chrono {
+    use super::Scru128Id;
+    use chrono::{DateTime, Utc};
+
+    impl Scru128Id {
+        /// Returns the `timestamp` field value as a [`DateTime<Utc>`] with millisecond precision.
+        ///
+        /// This conversion never fails, as every 48-bit `timestamp` is a non-negative number of
+        /// milliseconds well within the range supported by `chrono` (up to the year 10889).
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use scru128::Scru128Id;
+        ///
+        /// let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
+        /// assert_eq!(x.to_datetime_utc().timestamp_millis(), x.timestamp_millis_i64());
+        /// # Ok::<(), scru128::ParseError>(())
+        /// ```
+        pub fn to_datetime_utc(&self) -> DateTime<Utc> {
+            DateTime::from_timestamp_millis(self.timestamp_millis_i64())
+                .expect("48-bit timestamp must be within range of chrono")
+        }
+
+        /// Creates an object from field values, taking the `timestamp` field value from a
+        /// [`DateTime<Utc>`].
+        ///
+        /// The `datetime` is truncated to milliseconds. This is the inverse of
+        /// [`to_datetime_utc()`](Self::to_datetime_utc).
+        ///
+        /// # Panics
+        ///
+        /// Panics if `datetime` is earlier than the Unix epoch or later than the maximum 48-bit
+        /// `timestamp`, or if any other argument is out of the value range of the field.
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// use chrono::{TimeZone, Utc};
+        /// use scru128::Scru128Id;
+        ///
+        /// let datetime = Utc.with_ymd_and_hms(2022, 3, 20, 8, 34, 1).unwrap();
+        /// let x = Scru128Id::from_datetime_utc_and_fields(datetime, 0, 0, 0);
+        /// assert_eq!(x.to_datetime_utc(), datetime);
+        /// ```
+        pub fn from_datetime_utc_and_fields(
+            datetime: DateTime<Utc>,
+            counter_hi: u32,
+            counter_lo: u32,
+            entropy: u32,
+        ) -> Self {
+            let timestamp =
+                u64::try_from(datetime.timestamp_millis()).expect("invalid field value");
+            Self::from_fields(timestamp, counter_hi, counter_lo, entropy)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::Scru128Id;
+        use chrono::{DateTime, Duration, TimeZone, Utc};
+
+        /// Converts timestamp to and from DateTime with millisecond precision
+        #[test]
+        fn converts_timestamp_to_and_from_date_time_with_millisecond_precision() {
+            let datetime =
+                Utc.with_ymd_and_hms(2022, 3, 20, 8, 34, 1).unwrap() + Duration::milliseconds(493);
+            let x = Scru128Id::from_datetime_utc_and_fields(datetime, 0xabcdef, 0x123456, 42);
+            assert_eq!(x.timestamp(), 1_647_765_241_493);
+            assert_eq!(x.to_datetime_utc(), datetime);
+            assert_eq!(
+                (x.counter_hi(), x.counter_lo(), x.entropy()),
+                (0xabcdef, 0x123456, 42)
+            );
+
+            // truncate sub-millisecond part
+            let y = Scru128Id::from_datetime_utc_and_fields(
+                datetime + Duration::microseconds(999),
+                0xabcdef,
+                0x123456,
+                42,
+            );
+            assert_eq!(y, x);
+
+            let cases = [
+                (Scru128Id::MIN, DateTime::UNIX_EPOCH),
+                (
+                    Scru128Id::MAX,
+                    DateTime::from_timestamp_millis(0xffff_ffff_ffff).unwrap(),
+                ),
+            ];
+            for (e, datetime) in cases {
+                assert_eq!(e.to_datetime_utc(), datetime);
+                let (hi, lo, entropy) = (e.counter_hi(), e.counter_lo(), e.entropy());
+                assert_eq!(
+                    Scru128Id::from_datetime_utc_and_fields(datetime, hi, lo, entropy),
+                    e
+                );
+            }
+
+            let mut g = crate::Scru128Generator::with_rng(crate::generator::DefaultRng::default());
+            for i in 0..1_000 {
+                let e = g
+                    .generate_or_abort_core(1_647_765_241_493 + i, 10_000)
+                    .unwrap();
+                let datetime = e.to_datetime_utc();
+                assert_eq!(datetime.timestamp_millis(), e.timestamp_millis_i64());
+                assert_eq!(datetime.timestamp_subsec_nanos() % 1_000_000, 0);
+                let (hi, lo, entropy) = (e.counter_hi(), e.counter_lo(), e.entropy());
+                assert_eq!(
+                    Scru128Id::from_datetime_utc_and_fields(datetime, hi, lo, entropy),
+                    e
+                );
+            }
+
+            #[cfg(feature = "std")]
+            for datetime in [
+                DateTime::UNIX_EPOCH - Duration::milliseconds(1),
+                DateTime::from_timestamp_millis(1 << 48).unwrap(),
+            ] {
+                let result = std::panic::catch_unwind(|| {
+                    Scru128Id::from_datetime_utc_and_fields(datetime, 0, 0, 0)
+                });
+                assert!(result.is_err());
+            }
+        }
+    }
+}
+
+#[cfg(feature = "diesel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "diesel")))]
+mod with_diesel {
+    use super::Scru128Id;
+    use diesel::{backend, deserialize, serialize, sql_types::Binary};
+
+    /// Serializes an ID into a `Binary` column (e.g., `bytea` in PostgreSQL and `BLOB` in SQLite)
+    /// as the 16-byte big-endian binary representation.
+    impl<DB> serialize::ToSql<Binary, DB> for Scru128Id
+    where
+        DB: backend::Backend,
+        [u8]: serialize::ToSql<Binary, DB>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut serialize::Output<'b, '_, DB>) -> serialize::Result {
+            self.as_bytes()[..].to_sql(out)
+        }
+    }
+
+    /// Deserializes an ID from a `Binary` column, returning an error if the value is not exactly
+    /// 16 bytes long.
+    impl<DB> deserialize::FromSql<Binary, DB> for Scru128Id
+    where
+        DB: backend::Backend,
+        Vec<u8>: deserialize::FromSql<Binary, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+            let bytes = Vec::<u8>::from_sql(bytes)?;
+            match <[u8; 16]>::try_from(bytes.as_slice()) {
+                Ok(bytes) => Ok(Self::from_bytes(bytes)),
+                Err(_) => Err(format!(
+                    "invalid length for SCRU128 ID: expected 16 bytes, found {}",
+                    bytes.len()
+                )
+                .into()),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::Scru128Id;
+        use diesel::{dsl, prelude::*, sql_types::Binary};
+
+        /// Round-trips IDs through SQLite BLOB values
+        #[test]
+        fn round_trips_ids_through_sqlite_blob_values() {
+            let mut conn = SqliteConnection::establish(":memory:").unwrap();
+
+            let cases = [
+                Scru128Id::MIN,
+                Scru128Id::MAX,
+                Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e),
+            ];
+            for e in cases {
+                let bytes: Vec<u8> = dsl::select(e.into_sql::<Binary>())
+                    .get_result(&mut conn)
+                    .unwrap();
+                assert_eq!(bytes, e.as_bytes());
+
+                let decoded: Scru128Id = dsl::select(bytes.into_sql::<Binary>())
+                    .get_result(&mut conn)
+                    .unwrap();
+                assert_eq!(decoded, e);
+            }
+
+            for n in [0, 15, 17] {
+                let result = dsl::select(vec![0u8; n].into_sql::<Binary>())
+                    .get_result::<Scru128Id>(&mut conn);
+                assert!(result.is_err());
+            }
+        }
+
+        /// Round-trips IDs through PostgreSQL bytea values
+        ///
+        /// Requires `DATABASE_URL` pointing to a PostgreSQL server.
+        #[test]
+        #[ignore]
+        fn round_trips_ids_through_postgresql_bytea_values() {
+            let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+            let mut conn = PgConnection::establish(&url).unwrap();
+
+            let e = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+            let decoded: Scru128Id = dsl::select(e.into_sql::<Binary>())
+                .get_result(&mut conn)
+                .unwrap();
+            assert_eq!(decoded, e);
+
+            let result =
+                dsl::select(vec![0u8; 15].into_sql::<Binary>()).get_result::<Scru128Id>(&mut conn);
+            assert!(result.is_err());
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+mod with_uuid {
+    use super::Scru128Id;
+
+    /// Converts an ID into a [`uuid::Uuid`] by reinterpreting the 16-byte big-endian binary
+    /// representation as is.
+    ///
+    /// This conversion does not set the version and variant bits, so the resulting value is
+    /// generally not a valid RFC 9562 (formerly RFC 4122) UUID and should be regarded as an opaque
+    /// 128-bit value stored in a UUID-typed container. Converting it back into [`Scru128Id`]
+    /// restores the original ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use scru128::Scru128Id;
+    /// use uuid::Uuid;
+    ///
+    /// let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+    /// let y = Uuid::from(x);
+    /// assert_eq!(y.to_string(), "017fa1de-51a8-0fd9-92f9-e8cc2d5eb88e");
+    /// assert_eq!(Scru128Id::from(y), x);
+    /// ```
+    impl From<Scru128Id> for uuid::Uuid {
+        fn from(value: Scru128Id) -> Self {
+            Self::from_bytes(value.to_bytes())
+        }
+    }
+
+    /// Converts a [`uuid::Uuid`] into an ID by reinterpreting the 16-byte big-endian binary
+    /// representation as is, regardless of the version and variant of the UUID.
+    impl From<uuid::Uuid> for Scru128Id {
+        fn from(value: uuid::Uuid) -> Self {
+            Self::from_bytes(value.into_bytes())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::Scru128Id;
+        use uuid::Uuid;
+
+        /// Round-trips IDs through Uuid by byte reinterpretation
+        #[test]
+        fn round_trips_ids_through_uuid_by_byte_reinterpretation() {
+            assert_eq!(Uuid::from(Scru128Id::MIN), Uuid::nil());
+            assert_eq!(Uuid::from(Scru128Id::MAX), Uuid::max());
+            assert_eq!(Scru128Id::from(Uuid::nil()), Scru128Id::MIN);
+            assert_eq!(Scru128Id::from(Uuid::max()), Scru128Id::MAX);
+
+            let x = Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e);
+            assert_eq!(Uuid::from(x).as_u128(), x.to_u128());
+            assert_eq!(Uuid::from(x).as_bytes(), x.as_bytes());
+
+            #[cfg(feature = "default_rng")]
+            {
+                let mut g = crate::Scru128Generator::new();
+                let mut prev = Uuid::from(g.generate());
+                for _ in 0..1_000 {
+                    let e = g.generate();
+                    let curr = Uuid::from(e);
+                    assert_eq!(curr.as_bytes(), e.as_bytes());
+                    assert_eq!(Scru128Id::from(curr), e);
+                    assert!(prev < curr);
+                    prev = curr;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+mod with_arbitrary {
+    use super::Scru128Id;
+    use arbitrary::{Arbitrary, Result, Unstructured};
+
+    /// Creates an ID from the next 16 bytes of the unstructured input as the big-endian binary
+    /// representation, so that fuzz targets can take [`Scru128Id`] arguments directly.
+    ///
+    /// As with `[u8; 16]`, the missing bytes are filled with zeros if the input has fewer than 16
+    /// bytes left.
+    impl<'a> Arbitrary<'a> for Scru128Id {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self::from_bytes(u.arbitrary()?))
+        }
+
+        fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+            (16, Some(16))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::Scru128Id;
+        use arbitrary::{Arbitrary, Unstructured};
+
+        /// Builds IDs from 16 bytes of unstructured input
+        #[test]
+        fn builds_ids_from_16_bytes_of_unstructured_input() {
+            let bytes = [
+                0x01, 0x7f, 0xa1, 0xde, 0x51, 0xa8, 0x0f, 0xd9, 0x92, 0xf9, 0xe8, 0xcc, 0x2d, 0x5e,
+                0xb8, 0x8e, 0xff, 0xff,
+            ];
+            let mut u = Unstructured::new(&bytes);
+            let x = Scru128Id::arbitrary(&mut u).unwrap();
+            assert_eq!(x, Scru128Id::from_bytes(bytes[..16].try_into().unwrap()));
+            assert_eq!(x.to_u128(), 0x017fa1de51a80fd992f9e8cc2d5eb88e);
+            assert_eq!(u.len(), 2);
+
+            assert_eq!(Scru128Id::size_hint(0), (16, Some(16)));
+
+            let mut u = Unstructured::new(&bytes[..16]);
+            let (y, z) = <(Scru128Id, Scru128Id)>::arbitrary(&mut u).unwrap();
+            assert_eq!(y, x);
+            assert_eq!(z, Scru128Id::MIN);
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+mod with_rand {
+    use super::Scru128Id;
+    use rand::{distributions::Distribution, distributions::Standard, Rng};
+
+    /// Samples an ID whose 128 bits are all uniformly random.
+    ///
+    /// The sampled value is an arbitrary 128-bit integer reinterpreted as an ID, not one
+    /// monotonically generated from the current time, so it is suitable for property-based tests
+    /// and fixtures but not for generating production IDs. Use
+    /// [`Scru128Generator`](crate::Scru128Generator) for the latter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rand::{rngs::StdRng, Rng, SeedableRng};
+    /// use scru128::Scru128Id;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let x: Scru128Id = rng.gen();
+    /// assert_eq!(x.encode().parse::<Scru128Id>(), Ok(x));
+    /// ```
+    impl Distribution<Scru128Id> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Scru128Id {
+            Scru128Id::from_u128(rng.gen())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::Scru128Id;
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        /// Samples uniformly random IDs that survive round trips
+        #[test]
+        fn samples_uniformly_random_ids_that_survive_round_trips() {
+            let mut rng = StdRng::seed_from_u64(0x0123_4567_89ab_cdef);
+            let mut prev: Scru128Id = rng.gen();
+            let (mut n_ascending, mut ones) = (0, 0);
+            for _ in 0..10_000 {
+                let e: Scru128Id = rng.gen();
+                assert_eq!(e.encode().parse::<Scru128Id>(), Ok(e));
+                assert_eq!(Scru128Id::from_bytes(e.to_bytes()), e);
+                assert_ne!(e, prev);
+                if prev < e {
+                    n_ascending += 1;
+                }
+                ones += e.to_u128().count_ones();
+                prev = e;
+            }
+
+            // not monotonic unlike generated IDs
+            assert!((4_500..5_500).contains(&n_ascending));
+
+            // roughly half of the bits are set, including those of `timestamp`
+            assert!((630_000..650_000).contains(&ones));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "bitcode"))]
+mod tests_bitcode {
+    use super::Scru128Id;
+
+    /// Encodes IDs with bitcode in at most 16 bytes each plus header
+    #[test]
+    fn encodes_ids_with_bitcode_in_at_most_16_bytes_each_plus_header() {
+        const MAX_HEADER_LEN: usize = 8;
+
+        let cases = [
+            Scru128Id::MIN,
+            Scru128Id::MAX,
+            Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e),
+        ];
+        for e in cases {
+            let encoded = bitcode::encode(&e);
+            assert!(encoded.len() <= 16 + MAX_HEADER_LEN);
+            assert_eq!(bitcode::decode::<Scru128Id>(&encoded).unwrap(), e);
+        }
+
+        let encoded = bitcode::encode(&cases);
+        assert!(encoded.len() <= 16 * cases.len() + MAX_HEADER_LEN);
+        assert_eq!(bitcode::decode::<[Scru128Id; 3]>(&encoded).unwrap(), cases);
+
+        assert!(bitcode::decode::<Scru128Id>(&[]).is_err());
+
+        #[cfg(feature = "default_rng")]
+        {
+            let mut g = crate::Scru128Generator::new();
+            let ids: Vec<Scru128Id> = (0..1_000).map(|_| g.generate()).collect();
+            let encoded = bitcode::encode(&ids);
+            // random fields leave little room for packing
+            assert!(encoded.len() > 15 * ids.len());
+            assert!(encoded.len() <= 16 * ids.len() + MAX_HEADER_LEN);
+            assert_eq!(bitcode::decode::<Vec<Scru128Id>>(&encoded).unwrap(), ids);
+        }
     }
 }
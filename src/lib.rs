@@ -38,31 +38,96 @@
 //! - `std` configures [`Scru128Generator`] with the system clock. Without `std`, this
 //!   crate provides basic SCRU128 primitives available under `no_std` environments.
 //! - `rand` enables an adapter for [`rand::RngCore`] to use `rand` and any other
-//!   conforming random number generators with [`Scru128Generator`].
+//!   conforming random number generators with [`Scru128Generator`]. It also lets
+//!   [`rand::Rng::gen()`] sample uniformly random [`Scru128Id`] values for tests and
+//!   provides [`Scru128Generator::from_seed()`] to create a generator with a seeded random
+//!   number generator for reproducible tests.
 //! - `default_rng` (implies `std`) provides the default random number generator for
 //!   [`Scru128Generator`] and enables the [`Scru128Generator::new()`] constructor.
 //! - `global_gen` (implies `default_rng`) provides the process-wide default SCRU128
-//!   generator and enables the [`new()`] and [`new_string()`] functions.
+//!   generator and enables the [`new()`], [`try_new()`], [`new_fair()`], and [`new_string()`]
+//!   functions.
 //!
 //! Optional features:
 //!
-//! - `serde` enables serialization/deserialization of [`Scru128Id`] via serde.
+//! - `serde` enables serialization/deserialization of [`Scru128Id`] via serde, as well as the
+//!   [`serde_array`] module to serialize IDs as fixed-size arrays of exactly 16 bytes, the
+//!   [`as_struct`] module to serialize IDs as verbose structs for human inspection, and the
+//!   [`serde_u128`] module to serialize IDs as 128-bit unsigned integers.
+//! - `arrow` (implies `std`) provides the [`arrow`] module to convert [`Scru128Id`] values to and
+//!   from Apache Arrow's `FixedSizeBinary(16)` arrays.
+//! - `test-util` (implies `default_rng`) provides [`Scru128Id::random()`] and the [`test_seed`]
+//!   module to produce reproducible random IDs for test fixtures. It also provides
+//!   [`generator::ReplayTimeSource`] and [`generator::ReplayRandSource`] to reproduce the IDs of
+//!   a generator from recorded clock and random number traces.
+//! - `critical-section` provides the [`global`] module, a process-wide generator for `no_std`
+//!   environments that is guarded by the `critical-section` crate and driven by user-registered
+//!   time and random sources.
+//! - `bytes` enables [`Scru128Id::put_encoded()`] and [`Scru128Id::put_bytes()`] to write
+//!   [`Scru128Id`] values directly into `bytes::BufMut` buffers, as well as
+//!   [`Scru128Id::to_prost_bytes()`] and [`Scru128Id::from_prost_bytes()`] to convert IDs to and
+//!   from the content of protobuf `bytes` fields generated by `prost`.
+//! - `diesel` (implies `std`) implements Diesel's `ToSql`, `FromSql`, `AsExpression`, and
+//!   `FromSqlRow` for [`Scru128Id`] to store IDs in `Binary` columns as the 16-byte
+//!   representation. PostgreSQL (`bytea`) and SQLite (`BLOB`) backends are supported.
+//! - `uuid` implements conversions between [`Scru128Id`] and `uuid::Uuid` that reinterpret the
+//!   16-byte representation as is.
+//! - `heapless` enables [`Scru128Generator::generate_into_heapless()`] to fill a fixed-capacity
+//!   `heapless::Vec` with new IDs under `no_std` environments.
+//! - `bench-util` provides [`generator::XorShiftRng`], a fast but insecure random number
+//!   generator intended only for benchmarks that isolate the cost of the generator logic, and
+//!   [`Scru128Id::encode_fixed_iteration()`], a reference encoder to compare with
+//!   [`Scru128Id::encode()`].
+//! - `bitcode` implements `bitcode::Encode` and `bitcode::Decode` for [`Scru128Id`] to encode
+//!   IDs in at most 16 bytes each plus a message header of a few bytes.
+//! - `arbitrary` implements `arbitrary::Arbitrary` for [`Scru128Id`] to take IDs as structured
+//!   inputs of fuzz targets.
+//! - `governor` (implies `std`) provides [`generator::GovernedGenerator`] that gates ID generation
+//!   through a rate limiter of the `governor` crate in asynchronous code.
+//! - `chrono` enables [`Scru128Id::to_datetime_utc()`] and
+//!   [`Scru128Id::from_datetime_utc_and_fields()`] to convert the `timestamp` field to and from
+//!   `chrono::DateTime<Utc>`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 mod global_gen;
 #[cfg(feature = "global_gen")]
-pub use global_gen::{new, new_string};
+pub use global_gen::{new, new_fair, new_string, try_new};
 
 mod id;
-pub use id::{ParseError, Scru128Id};
+#[cfg(feature = "std")]
+pub use id::parse_all;
+pub use id::{FieldError, ParseError, ParseErrorKind, Scru128Id};
+
+mod timestamp;
+pub use timestamp::Scru128Timestamp;
 
 pub mod generator;
 #[doc(hidden)]
 pub use generator as r#gen;
 pub use generator::Scru128Generator;
 
+mod newtype;
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+}
+
+pub mod arrow;
+
+pub mod serde_array;
+
+pub mod as_struct;
+
+pub mod serde_u128;
+
+pub mod test_seed;
+
+pub mod global;
+
 /// The maximum value of 48-bit `timestamp` field.
 const MAX_TIMESTAMP: u64 = 0xffff_ffff_ffff;
 
@@ -0,0 +1,129 @@
+/// Implements common traits for a newtype wrapping [`Scru128Id`](crate::Scru128Id) by delegating
+/// them to the inner type.
+///
+/// This macro takes the name of a tuple struct that has exactly one field of type `Scru128Id`,
+/// such as `struct UserId(Scru128Id);`, and implements the following traits for it:
+///
+/// - [`Display`](core::fmt::Display)
+/// - [`FromStr`](core::str::FromStr) with [`ParseError`](crate::ParseError) as the error type
+/// - [`From<u128>`]
+/// - `serde::Serialize` and `serde::Deserialize` if the `serde` feature of this crate is enabled
+///
+/// Other traits such as `Copy`, `Eq`, `Ord`, `Hash`, and `Debug` can be derived as usual.
+///
+/// # Examples
+///
+/// ```rust
+/// use scru128::{scru128_newtype, Scru128Id};
+///
+/// #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+/// struct UserId(Scru128Id);
+///
+/// scru128_newtype!(UserId);
+///
+/// let x = "036z968fu2tugy7svkfznewkk".parse::<UserId>()?;
+/// assert_eq!(x.to_string(), "036z968fu2tugy7svkfznewkk");
+/// assert_eq!(UserId::from(x.0.to_u128()), x);
+/// # Ok::<(), scru128::ParseError>(())
+/// ```
+#[macro_export]
+macro_rules! scru128_newtype {
+    ($name:ident) => {
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::ParseError;
+
+            fn from_str(str_value: &str) -> ::core::result::Result<Self, Self::Err> {
+                $crate::Scru128Id::try_from_str(str_value).map(Self)
+            }
+        }
+
+        impl ::core::convert::From<u128> for $name {
+            fn from(value: u128) -> Self {
+                Self($crate::Scru128Id::from_u128(value))
+            }
+        }
+
+        $crate::__scru128_newtype_serde!($name);
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __scru128_newtype_serde {
+    ($name:ident) => {
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S: $crate::__private::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                $crate::__private::serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D: $crate::__private::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> ::core::result::Result<Self, D::Error> {
+                <$crate::Scru128Id as $crate::__private::serde::Deserialize<'de>>::deserialize(
+                    deserializer,
+                )
+                .map(Self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __scru128_newtype_serde {
+    ($name:ident) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Scru128Id;
+
+    #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+    struct UserId(Scru128Id);
+
+    scru128_newtype!(UserId);
+
+    /// Delegates traits of newtype to inner ID
+    #[test]
+    fn delegates_traits_of_newtype_to_inner_id() {
+        let text = "037arkzbgn93kdu9h3pw2ow2l";
+        let inner = text.parse::<Scru128Id>().unwrap();
+
+        let x = text.parse::<UserId>().unwrap();
+        assert_eq!(x, UserId(inner));
+        assert_eq!(UserId::from(inner.to_u128()), x);
+        assert_eq!(
+            "037arkzbgn93kdu9h3pw2ow2".parse::<UserId>(),
+            Err(Scru128Id::try_from_str("037arkzbgn93kdu9h3pw2ow2").unwrap_err())
+        );
+
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(x.to_string(), text);
+            assert_eq!(format!("{:#}", x), format!("{:#}", inner));
+        }
+
+        #[cfg(feature = "serde")]
+        {
+            use serde_test::{Configure, Token};
+            serde_test::assert_tokens(&x.readable(), &[Token::Str(text)]);
+            let bytes = &[
+                1, 128, 178, 254, 34, 56, 72, 100, 6, 87, 159, 252, 102, 145, 202, 93,
+            ];
+            serde_test::assert_tokens(&x.compact(), &[Token::Bytes(bytes)]);
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Serde helper that serializes [`Scru128Id`] as a fixed-size array of 16 bytes in binary formats.
+//!
+//! The default [`Serialize`](serde::Serialize) implementation of [`Scru128Id`] writes the 16-byte
+//! representation through `serialize_bytes()` in non-human-readable formats, which some formats
+//! encode as a variable-length byte string with a length prefix. For example, `postcard` emits
+//! a one-byte length prefix, taking 17 bytes per ID. This module instead serializes IDs as a
+//! 16-element tuple of `u8`, which such formats encode without a length prefix, so that an ID
+//! occupies exactly 16 bytes on the wire in `postcard`. Human-readable formats use the 25-digit
+//! canonical string representation as the default implementation does.
+//!
+//! Use this module with the `#[serde(with = "...")]` field attribute. Note that the wire format is
+//! not compatible with that of the default implementation in formats that distinguish byte
+//! strings from tuples.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use scru128::Scru128Id;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "scru128::serde_array")]
+//!     id: Scru128Id,
+//! }
+//! ```
+
+#![cfg(feature = "serde")]
+#![cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+
+use crate::Scru128Id;
+use core::fmt;
+use serde::{de, ser::SerializeTuple, Deserializer, Serializer};
+
+/// Serializes an ID as a 16-byte tuple, or as the canonical string in human-readable formats.
+pub fn serialize<S: Serializer>(id: &Scru128Id, serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&id.encode())
+    } else {
+        let mut tuple = serializer.serialize_tuple(16)?;
+        for e in id.as_bytes() {
+            tuple.serialize_element(e)?;
+        }
+        tuple.end()
+    }
+}
+
+/// Deserializes an ID from a 16-byte tuple, or from the canonical string in human-readable
+/// formats.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Scru128Id, D::Error> {
+    if deserializer.is_human_readable() {
+        serde::Deserialize::deserialize(deserializer)
+    } else {
+        deserializer.deserialize_tuple(16, VisitorImpl)
+    }
+}
+
+struct VisitorImpl;
+
+impl<'de> de::Visitor<'de> for VisitorImpl {
+    type Value = Scru128Id;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "an array of 16 bytes")
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = [0u8; 16];
+        for (i, e) in bytes.iter_mut().enumerate() {
+            *e = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        Ok(Self::Value::from_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Scru128Id;
+    use serde::{Deserialize, Serialize};
+    use serde_test::{Configure, Token};
+
+    #[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Debug)]
+    struct Record {
+        #[serde(with = "super")]
+        id: Scru128Id,
+    }
+
+    /// Serializes to exactly 16 bytes with postcard
+    #[test]
+    fn serializes_to_exactly_16_bytes_with_postcard() {
+        let cases = [
+            Scru128Id::MIN,
+            Scru128Id::MAX,
+            Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e),
+        ];
+
+        for e in cases {
+            let record = Record { id: e };
+            let mut buffer = [0u8; 64];
+            let encoded = postcard::to_slice(&record, &mut buffer).unwrap();
+            assert_eq!(encoded, e.as_bytes());
+            assert_eq!(postcard::from_bytes::<Record>(encoded).unwrap(), record);
+
+            // default implementation emits length prefix
+            let mut buffer = [0u8; 64];
+            let encoded = postcard::to_slice(&e, &mut buffer).unwrap();
+            assert_eq!(encoded.len(), 17);
+            assert_eq!(encoded[0], 16);
+            assert_eq!(&encoded[1..], e.as_bytes());
+            assert_eq!(postcard::from_bytes::<Scru128Id>(encoded).unwrap(), e);
+        }
+
+        assert!(postcard::from_bytes::<Record>(&[0u8; 15]).is_err());
+    }
+
+    /// Serializes as tuple in binary formats and as string in human-readable formats
+    #[test]
+    fn serializes_as_tuple_in_binary_formats_and_as_string_in_human_readable_formats() {
+        let record = Record {
+            id: "037arkzbgn93kdu9h3pw2ow2l".parse().unwrap(),
+        };
+        let bytes = [
+            1, 128, 178, 254, 34, 56, 72, 100, 6, 87, 159, 252, 102, 145, 202, 93,
+        ];
+
+        let mut tokens = [Token::TupleEnd; 21];
+        tokens[0] = Token::Struct {
+            name: "Record",
+            len: 1,
+        };
+        tokens[1] = Token::Str("id");
+        tokens[2] = Token::Tuple { len: 16 };
+        for (i, e) in bytes.into_iter().enumerate() {
+            tokens[3 + i] = Token::U8(e);
+        }
+        tokens[20] = Token::StructEnd;
+        serde_test::assert_tokens(&record.compact(), &tokens);
+
+        serde_test::assert_tokens(
+            &record.readable(),
+            &[
+                Token::Struct {
+                    name: "Record",
+                    len: 1,
+                },
+                Token::Str("id"),
+                Token::Str("037arkzbgn93kdu9h3pw2ow2l"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
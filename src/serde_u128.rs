@@ -0,0 +1,116 @@
+//! Serde helper that serializes [`Scru128Id`] as a 128-bit unsigned integer.
+//!
+//! The default [`Serialize`](serde::Serialize) implementation of [`Scru128Id`] writes the 25-digit
+//! canonical string in human-readable formats and the 16-byte representation in
+//! non-human-readable formats. This module instead serializes IDs through `serialize_u128()` in
+//! all formats, which suits protocols that carry IDs as plain integers. Each format encodes the
+//! integer in its own way: for example, `postcard` uses a variable-length integer, and JSON
+//! writes a decimal number, which many JSON parsers outside Rust cannot read without loss of
+//! precision. Formats that do not support 128-bit integers return an error.
+//!
+//! Use this module with the `#[serde(with = "...")]` field attribute. Note that the wire format is
+//! not compatible with that of the default implementation.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use scru128::Scru128Id;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "scru128::serde_u128")]
+//!     id: Scru128Id,
+//! }
+//! ```
+
+#![cfg(feature = "serde")]
+#![cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+
+use crate::Scru128Id;
+use core::fmt;
+use serde::{de, Deserializer, Serializer};
+
+/// Serializes an ID as a 128-bit unsigned integer.
+pub fn serialize<S: Serializer>(id: &Scru128Id, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(id.to_u128())
+}
+
+/// Deserializes an ID from a 128-bit unsigned integer.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Scru128Id, D::Error> {
+    deserializer.deserialize_u128(VisitorImpl)
+}
+
+struct VisitorImpl;
+
+impl de::Visitor<'_> for VisitorImpl {
+    type Value = Scru128Id;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a 128-bit unsigned integer")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Self::Value::from_u128(value as u128))
+    }
+
+    fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+        Ok(Self::Value::from_u128(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Scru128Id;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Debug)]
+    struct Record {
+        #[serde(with = "super")]
+        id: Scru128Id,
+    }
+
+    const CASES: [Scru128Id; 4] = [
+        Scru128Id::MIN,
+        Scru128Id::MAX,
+        Scru128Id::from_u128(42),
+        Scru128Id::from_u128(0x017fa1de51a80fd992f9e8cc2d5eb88e),
+    ];
+
+    /// Serializes as u128 integer with postcard
+    #[test]
+    fn serializes_as_u128_integer_with_postcard() {
+        for e in CASES {
+            let record = Record { id: e };
+            let mut buffer = [0u8; 64];
+            let encoded = postcard::to_slice(&record, &mut buffer).unwrap().to_vec();
+
+            let mut expected = [0u8; 64];
+            let expected = postcard::to_slice(&e.to_u128(), &mut expected).unwrap();
+            assert_eq!(encoded, expected);
+            assert_eq!(postcard::from_bytes::<Record>(&encoded).unwrap(), record);
+        }
+
+        // variable-length integer is shorter than byte string for small values
+        let mut buffer = [0u8; 64];
+        let encoded = postcard::to_slice(&Record { id: CASES[2] }, &mut buffer).unwrap();
+        assert_eq!(encoded, [42]);
+    }
+
+    /// Serializes as decimal number in JSON
+    #[test]
+    fn serializes_as_decimal_number_in_json() {
+        for e in CASES {
+            let record = Record { id: e };
+            let encoded = serde_json::to_string(&record).unwrap();
+            let number = encoded
+                .strip_prefix(r#"{"id":"#)
+                .and_then(|rest| rest.strip_suffix('}'));
+            assert_eq!(number.map(str::parse::<u128>), Some(Ok(e.to_u128())));
+            assert_eq!(serde_json::from_str::<Record>(&encoded).unwrap(), record);
+        }
+
+        assert!(serde_json::from_str::<Record>(r#"{"id":"036z968fuj8fp95tsldrnqjke"}"#).is_err());
+        assert!(serde_json::from_str::<Record>(r#"{"id":-1}"#).is_err());
+    }
+}
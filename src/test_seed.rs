@@ -0,0 +1,92 @@
+//! Thread-local seed control for reproducible test fixtures.
+//!
+//! By default, [`Scru128Id::random()`] draws random IDs from the operating system's entropy
+//! source. Calling [`set()`] makes it draw from a random number generator seeded with the given
+//! value on the current thread, so that test suites can produce the same IDs across runs.
+//! [`clear()`] restores the default behavior.
+//!
+//! This module is intended for tests only. The seeded random number generator is predictable by
+//! design and must not be used to produce IDs in production.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use scru128::{test_seed, Scru128Id};
+//!
+//! test_seed::set(42);
+//! let x = Scru128Id::random();
+//!
+//! test_seed::set(42);
+//! assert_eq!(Scru128Id::random(), x);
+//!
+//! test_seed::clear();
+//! ```
+
+#![cfg(feature = "test-util")]
+#![cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+
+use crate::Scru128Id;
+use rand::{rngs::OsRng, RngCore, SeedableRng as _};
+use rand_chacha::ChaCha12Rng;
+use std::cell::RefCell;
+
+thread_local! {
+    static SEEDED_RNG: RefCell<Option<ChaCha12Rng>> = const { RefCell::new(None) };
+}
+
+/// Makes the random ID helpers on the current thread draw from a random number generator seeded
+/// with `seed`.
+pub fn set(seed: u64) {
+    SEEDED_RNG.with(|e| *e.borrow_mut() = Some(ChaCha12Rng::seed_from_u64(seed)));
+}
+
+/// Makes the random ID helpers on the current thread draw from the operating system's entropy
+/// source again.
+pub fn clear() {
+    SEEDED_RNG.with(|e| *e.borrow_mut() = None);
+}
+
+/// Fills `dest` with random bytes from the seeded random number generator if set or from the
+/// operating system otherwise.
+fn fill_bytes(dest: &mut [u8]) {
+    SEEDED_RNG.with(|e| match e.borrow_mut().as_mut() {
+        Some(rng) => rng.fill_bytes(dest),
+        None => OsRng.fill_bytes(dest),
+    });
+}
+
+impl Scru128Id {
+    /// Returns a uniformly random 128-bit value as an ID for test fixtures.
+    ///
+    /// The returned ID is not generated by [`Scru128Generator`](crate::Scru128Generator) and thus
+    /// has no relation to the current time. See the [`test_seed`](crate::test_seed) module to
+    /// make the returned values reproducible.
+    pub fn random() -> Self {
+        let mut bytes = [0u8; 16];
+        fill_bytes(&mut bytes);
+        Self::from_bytes(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{clear, set, Scru128Id};
+
+    /// Reproduces same random IDs with same seed
+    #[test]
+    fn reproduces_same_random_ids_with_same_seed() {
+        set(0x0123_4567_89ab_cdef);
+        let (x, y) = (Scru128Id::random(), Scru128Id::random());
+        assert_ne!(x, y);
+
+        set(0x0123_4567_89ab_cdef);
+        assert_eq!(Scru128Id::random(), x);
+        assert_eq!(Scru128Id::random(), y);
+
+        set(0xfedc_ba98_7654_3210);
+        assert_ne!(Scru128Id::random(), x);
+
+        clear();
+        assert_ne!(Scru128Id::random(), Scru128Id::random());
+    }
+}
@@ -0,0 +1,110 @@
+use core::fmt;
+
+/// Represents the creation time of a SCRU128 ID, i.e., the `timestamp` field value, as the number
+/// of milliseconds elapsed since the Unix epoch (1970-01-01 00:00:00 UTC).
+///
+/// This type is returned by [`Scru128Id::creation_time()`](crate::Scru128Id::creation_time) to
+/// distinguish the creation time from other bare integers. The resolution is one millisecond, and
+/// the [`Display`](fmt::Display) implementation renders the value as an ISO 8601 date and time in
+/// UTC with millisecond precision (e.g., `2022-03-20T08:34:01.493Z`). Convert it into a `u64` to
+/// obtain the raw millisecond value.
+///
+/// # Examples
+///
+/// ```rust
+/// use scru128::Scru128Id;
+///
+/// let x = "037d0xye6op48cmce8ey4xlcf".parse::<Scru128Id>()?;
+/// let t = x.creation_time();
+/// assert_eq!(u64::from(t), x.timestamp());
+/// assert_eq!(t.to_string(), "2022-05-21T21:54:29.973Z");
+/// # Ok::<(), scru128::ParseError>(())
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Scru128Timestamp(u64);
+
+impl Scru128Timestamp {
+    /// Creates an object from the number of milliseconds elapsed since the Unix epoch.
+    pub(crate) const fn from_millis(millis: u64) -> Self {
+        Self(millis)
+    }
+
+    /// Returns the number of milliseconds elapsed since the Unix epoch.
+    pub const fn as_millis(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<Scru128Timestamp> for u64 {
+    fn from(value: Scru128Timestamp) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Scru128Timestamp {
+    /// Returns the ISO 8601 representation in UTC with millisecond precision.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (secs, millis) = (self.0 / 1_000, self.0 % 1_000);
+        let (days, secs_of_day) = (secs / 86_400, secs % 86_400);
+
+        // convert days since epoch to civil date (Howard Hinnant's algorithm)
+        let z = days + 719_468;
+        let era = z / 146_097;
+        let doe = z % 146_097;
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + (month <= 2) as u64;
+
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+            year,
+            month,
+            day,
+            secs_of_day / 3_600,
+            secs_of_day / 60 % 60,
+            secs_of_day % 60,
+            millis
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    /// Formats creation time as ISO 8601 date and time
+    #[cfg(feature = "std")]
+    #[test]
+    fn formats_creation_time_as_iso_8601_date_and_time() {
+        use super::Scru128Timestamp;
+        use crate::Scru128Id;
+
+        let cases = [
+            (0, "1970-01-01T00:00:00.000Z"),
+            (1, "1970-01-01T00:00:00.001Z"),
+            (951_782_400_000, "2000-02-29T00:00:00.000Z"),
+            (1_647_765_241_493, "2022-03-20T08:34:01.493Z"),
+            (1_709_251_199_999, "2024-02-29T23:59:59.999Z"),
+            (4_102_444_800_000, "2100-01-01T00:00:00.000Z"),
+            (0xffff_ffff_ffff, "10889-08-02T05:31:50.655Z"),
+        ];
+        for (millis, text) in cases {
+            let t = Scru128Timestamp::from_millis(millis);
+            assert_eq!(t.to_string(), text);
+            assert_eq!(u64::from(t), millis);
+            assert_eq!(t.as_millis(), millis);
+        }
+
+        let x = "037arkzbgn93kdu9h3pw2ow2l".parse::<Scru128Id>().unwrap();
+        assert_eq!(x.creation_time().as_millis(), x.timestamp());
+        let text = x.creation_time().to_string();
+        let re = regex::Regex::new(
+            r"^[0-9]{4}-[0-9]{2}-[0-9]{2}T[0-9]{2}:[0-9]{2}:[0-9]{2}\.[0-9]{3}Z$",
+        )
+        .unwrap();
+        assert!(re.is_match(&text));
+        assert_eq!(text, "2022-05-11T12:00:41.016Z");
+    }
+}